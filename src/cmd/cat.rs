@@ -40,15 +40,56 @@ cat arguments:
                             decompressed automatically.
 
 cat options:
-                             COLUMNS OPTION:
+    --drop-empty             Skip input files with no data rows (header-only or
+                             completely empty) instead of concatenating them,
+                             logging each skipped file to stderr. Only applies
+                             when concatenating rows & rowskey.
+    --sample-rate <rate>     Keep each data row with the given probability (0..1)
+                             via Bernoulli sampling while concatenating, producing
+                             a random subsample of the concatenation in one pass.
+                             Only applies when concatenating rows & rowskey.
+    --seed <seed>            RNG seed for --sample-rate, making the
+                             subsample reproducible.
+    --dry-run                Don't concatenate anything. Instead, report the resolved,
+                             ordered input list to stderr - one line per input with its
+                             detected delimiter, column count and row count - then exit
+                             without writing any data. Useful for catching misdetected
+                             files before running a big concatenation.
+
+                             COLUMNS OPTIONS:
     -p, --pad                When concatenating columns, this flag will cause
                              all records to appear. It will pad each row if
                              other CSV data isn't long enough.
+    --fill <text>            The value to use when --pad fills in cells for
+                             exhausted inputs, so padded-missing cells can be
+                             told apart from genuinely empty ones.
+                             (default: <empty string>)
+    --dedup-headers          When concatenating columns from inputs that share
+                             header names, disambiguate the collisions in the
+                             output header row by renaming them to `name_2`,
+                             `name_3`, etc. based on source order.
+    --on <key>               When concatenating columns, align rows across inputs
+                             by matching the named key column's value instead of
+                             pairing rows positionally - a full outer join on <key>.
+                             Rows are output in the order keys first appear across
+                             the inputs, and cells for inputs missing a key are
+                             filled with --fill (empty by default). Each input's
+                             first row is read as its header and must contain <key> -
+                             it is an error otherwise. Duplicate keys within an
+                             input keep the first occurrence.
 
-                             ROWS OPTION:
+                             ROWS OPTIONS:
     --flexible               When concatenating rows, this flag turns off validation
                              that the input and output CSVs have the same number of columns.
                              This is faster, but may result in invalid CSV data.
+    --with-source <srckind>  When concatenating rows, prepend a column containing each
+                             row's originating filename. Valid values are the same
+                             kinds supported by --group: 'fullpath', 'parentdirfname',
+                             'parentdirfstem', 'fname', 'fstem' and 'none'.
+                             If 'none' is specified, no source column is added.
+                             [default: none]
+    --source-name <arg>      The name of the column added by --with-source.
+                             [default: file]
 
                              ROWSKEY OPTIONS:
     -g, --group <grpkind>    When concatenating with rowskey, you can specify a grouping value
@@ -60,7 +101,35 @@ cat options:
                              [default: none]
     -N, --group-name <arg>   When concatenating with rowskey, this flag provides the name
                              for the new grouping column. [default: file]
-                             
+    --source-coverage        When concatenating with rowskey, append a "source_coverage"
+                             column counting how many of the union columns are actually
+                             present in each row's source file. This surfaces sparse rows
+                             coming from files that are missing union columns.
+    --rename <mapfile>       A two-column CSV of from,to alias pairs (read without
+                             headers - every row is a pair) applied to each input's
+                             headers before the union/alignment step, so columns
+                             spelled differently across files merge into one.
+                             Renaming happens per file and is case-sensitive
+                             unless --rename-ci is set.
+    --rename-ci              Match the "from" side of the --rename aliases
+                             case-insensitively. Only valid with --rename.
+    --columns <file>         Supply the rowskey target column union up front - a
+                             plain text file with one column name per line - instead
+                             of discovering it with a header-scanning first pass.
+                             Each input is streamed directly into this fixed output
+                             schema in a single bounded-memory pass, writing empty
+                             strings for columns absent from an input and dropping
+                             input columns not in the list.
+                             Cannot be used with --no-headers.
+    --strict                 With --columns, error if a supplied column is not
+                             found in any input.
+    --align-report <file>    When concatenating with rowskey, write a CSV presence
+                             matrix to <file> - one row per union column, one column
+                             per input file, with 1/0 marking whether the file has
+                             that column. Useful for diagnosing why certain union
+                             columns are sparse. Cannot be used with --columns,
+                             as it is built during the header-discovery pass.
+
 Common options:
     -h, --help             Display this message
     -o, --output <file>    Write output to <file> instead of stdout.
@@ -69,6 +138,14 @@ Common options:
                            concatenating columns.
     -d, --delimiter <arg>  The field delimiter for reading CSV data.
                            Must be a single character. (default: ,)
+    --quote-style <arg>    The quote style to use when writing CSV output:
+                             necessary   - quote fields only when necessary (default)
+                             always      - quote all fields
+                             non-numeric - quote all non-numeric fields
+                             never       - never quote fields
+                           Overrides the QSV_DEFAULT_QUOTE_STYLE envvar.
+    --crlf                 Use '\r\n' line endings in the output.
+                           Overrides the QSV_CRLF envvar.
 "#;
 
 use std::{
@@ -76,29 +153,48 @@ use std::{
     str::FromStr,
 };
 
+use foldhash::{HashMap, HashMapExt};
 use indexmap::{IndexMap, IndexSet};
+use rand::{Rng, SeedableRng, rngs::StdRng};
 use serde::Deserialize;
 use strum_macros::EnumString;
 
 use crate::{
     CliResult,
-    config::{Config, DEFAULT_WTR_BUFFER_CAPACITY, Delimiter},
+    config::{Config, DEFAULT_WTR_BUFFER_CAPACITY, Delimiter, parse_quote_style},
     util,
 };
 
 #[derive(Deserialize)]
 struct Args {
-    cmd_rows:        bool,
-    cmd_rowskey:     bool,
-    cmd_columns:     bool,
-    flag_group:      String,
-    flag_group_name: String,
-    arg_input:       Vec<PathBuf>,
-    flag_pad:        bool,
-    flag_flexible:   bool,
-    flag_output:     Option<String>,
-    flag_no_headers: bool,
-    flag_delimiter:  Option<Delimiter>,
+    cmd_rows:             bool,
+    cmd_rowskey:          bool,
+    cmd_columns:          bool,
+    flag_group:           String,
+    flag_group_name:      String,
+    flag_source_coverage: bool,
+    flag_rename:          Option<String>,
+    flag_rename_ci:       bool,
+    flag_columns:         Option<String>,
+    flag_strict:          bool,
+    flag_align_report:    Option<String>,
+    arg_input:            Vec<PathBuf>,
+    flag_pad:             bool,
+    flag_fill:            Option<String>,
+    flag_dedup_headers:   bool,
+    flag_on:              Option<String>,
+    flag_flexible:        bool,
+    flag_with_source:     String,
+    flag_source_name:     String,
+    flag_drop_empty:      bool,
+    flag_sample_rate:     Option<f64>,
+    flag_seed:            Option<u64>,
+    flag_dry_run:         bool,
+    flag_output:          Option<String>,
+    flag_no_headers:      bool,
+    flag_delimiter:       Option<Delimiter>,
+    flag_quote_style:     Option<String>,
+    flag_crlf:            bool,
 }
 
 #[derive(Debug, EnumString, PartialEq)]
@@ -129,8 +225,83 @@ fn get_parentdir_and_file(path: &Path, stem_only: bool) -> String {
 pub fn run(argv: &[&str]) -> CliResult<()> {
     let mut args: Args = util::get_args(USAGE, argv)?;
 
+    if args.flag_with_source != "none" && !args.cmd_rows {
+        return fail_incorrectusage_clierror!(
+            "--with-source is only valid when concatenating rows."
+        );
+    }
+
+    if args.flag_rename.is_some() && !args.cmd_rowskey {
+        return fail_incorrectusage_clierror!(
+            "--rename is only valid when concatenating with rowskey."
+        );
+    }
+
+    if args.flag_rename_ci && args.flag_rename.is_none() {
+        return fail_incorrectusage_clierror!("--rename-ci requires --rename.");
+    }
+
+    // validate --quote-style upfront; wconfig() reparses it infallibly
+    if let Some(ref style) = args.flag_quote_style {
+        parse_quote_style(style)?;
+    }
+
+    if args.flag_columns.is_some() {
+        if !args.cmd_rowskey {
+            return fail_incorrectusage_clierror!(
+                "--columns is only valid when concatenating with rowskey."
+            );
+        }
+        if args.flag_no_headers {
+            return fail_incorrectusage_clierror!("--columns cannot be used with --no-headers.");
+        }
+    }
+
+    if args.flag_strict && args.flag_columns.is_none() {
+        return fail_incorrectusage_clierror!("--strict requires --columns.");
+    }
+
+    if args.flag_align_report.is_some() {
+        if !args.cmd_rowskey {
+            return fail_incorrectusage_clierror!(
+                "--align-report is only valid when concatenating with rowskey."
+            );
+        }
+        if args.flag_columns.is_some() {
+            return fail_incorrectusage_clierror!(
+                "--align-report cannot be used with --columns."
+            );
+        }
+    }
+
+    if args.flag_dedup_headers && !args.cmd_columns {
+        return fail_incorrectusage_clierror!(
+            "--dedup-headers is only valid when concatenating columns."
+        );
+    }
+
+    if args.flag_on.is_some() && !args.cmd_columns {
+        return fail_incorrectusage_clierror!("--on is only valid when concatenating columns.");
+    }
+
+    if let Some(rate) = args.flag_sample_rate {
+        if args.cmd_columns {
+            return fail_incorrectusage_clierror!(
+                "--sample-rate is only valid when concatenating rows & rowskey."
+            );
+        }
+        if !(rate > 0.0 && rate <= 1.0) {
+            return fail_incorrectusage_clierror!(
+                "--sample-rate must be greater than 0 and at most 1."
+            );
+        }
+    }
+
     let tmpdir = tempfile::tempdir()?;
     args.arg_input = util::process_input(args.arg_input, &tmpdir, "")?;
+    if args.flag_dry_run {
+        return args.dry_run();
+    }
     if args.cmd_rows {
         args.cat_rows()
     } else if args.cmd_rowskey {
@@ -143,7 +314,114 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
 }
 
 impl Args {
+    /// the output writer config, applying --quote-style and --crlf when set
+    fn wconfig(&self) -> Config {
+        let mut wconfig = Config::new(self.flag_output.as_ref());
+        if let Some(quote_style) = self
+            .flag_quote_style
+            .as_deref()
+            .and_then(|style| parse_quote_style(style).ok())
+        {
+            wconfig = wconfig.quote_style(quote_style);
+        }
+        if self.flag_crlf {
+            wconfig = wconfig.crlf(true);
+        }
+        wconfig
+    }
+
+    // --sample-rate: a seeded RNG paired with the keep probability,
+    // None when not sampling
+    fn sampler(&self) -> Option<(StdRng, f64)> {
+        self.flag_sample_rate.map(|rate| {
+            let rng = if let Some(seed) = self.flag_seed {
+                StdRng::seed_from_u64(seed) // DevSkim: ignore DS148264
+            } else {
+                StdRng::from_os_rng()
+            };
+            (rng, rate)
+        })
+    }
+
+    #[inline]
+    fn keep_row(sampler: &mut Option<(StdRng, f64)>) -> bool {
+        match sampler {
+            Some((rng, rate)) => rng.random::<f64>() < *rate,
+            None => true,
+        }
+    }
+
     #[inline]
+    /// --dry-run: report the resolved, ordered input list to stderr with each
+    /// file's delimiter, column count and row count, without concatenating
+    fn dry_run(&self) -> CliResult<()> {
+        for conf in self.configs()? {
+            let input_name = conf.path.as_ref().map_or_else(
+                || "<stdin>".to_string(),
+                |path| path.display().to_string(),
+            );
+            let mut rdr = conf.reader()?;
+            let column_count = rdr.byte_headers()?.len();
+            let row_count = util::count_rows(&conf)?;
+            eprintln!(
+                "\"{input_name}\": delimiter: \"{}\" columns: {column_count} rows: {row_count}",
+                conf.get_delimiter() as char
+            );
+        }
+        Ok(())
+    }
+
+    /// --rename: load the two-column from,to alias pairs applied to each
+    /// input's headers before the union/alignment step. The "from" keys are
+    /// lowercased when --rename-ci is set
+    fn rename_map(&self) -> CliResult<Option<HashMap<Vec<u8>, Vec<u8>>>> {
+        let Some(ref mapfile) = self.flag_rename else {
+            return Ok(None);
+        };
+        let mut rdr = Config::new(Some(mapfile))
+            .delimiter(self.flag_delimiter)
+            .no_headers(true)
+            .reader()?;
+        let mut map: HashMap<Vec<u8>, Vec<u8>> = HashMap::new();
+        let mut record = csv::ByteRecord::new();
+        while rdr.read_byte_record(&mut record)? {
+            let (Some(from), Some(to)) = (record.get(0), record.get(1)) else {
+                return fail_incorrectusage_clierror!(
+                    "--rename mapfile rows must have two columns: from,to."
+                );
+            };
+            let key = if self.flag_rename_ci {
+                from.to_ascii_lowercase()
+            } else {
+                from.to_vec()
+            };
+            map.insert(key, to.to_vec());
+        }
+        Ok(Some(map))
+    }
+
+    /// apply the --rename aliases to one input's header row, leaving
+    /// unmapped columns untouched
+    fn apply_rename(
+        &self,
+        header: &csv::ByteRecord,
+        rename_map: &HashMap<Vec<u8>, Vec<u8>>,
+    ) -> csv::ByteRecord {
+        let mut renamed = csv::ByteRecord::with_capacity(header.as_slice().len(), header.len());
+        for field in header {
+            let key = if self.flag_rename_ci {
+                field.to_ascii_lowercase()
+            } else {
+                field.to_vec()
+            };
+            match rename_map.get(&key) {
+                Some(to) => renamed.push_field(to),
+                None => renamed.push_field(field),
+            }
+        }
+        renamed
+    }
+
     fn configs(&self) -> CliResult<Vec<Config>> {
         util::many_configs(
             &self.arg_input,
@@ -158,37 +436,116 @@ impl Args {
     }
 
     fn cat_rows(&self) -> CliResult<()> {
+        // --with-source: prepend a column with each row's originating filename
+        let Ok(source_kind) = GroupKind::from_str(&self.flag_with_source) else {
+            return fail_incorrectusage_clierror!(
+                "Invalid source kind `{}`. Valid values are 'fullpath', 'parentdirfname', \
+                 'parentdirfstem', 'fname', 'fstem' and 'none'.",
+                self.flag_with_source
+            );
+        };
+        let with_source = source_kind != GroupKind::None;
+
         let mut row = csv::ByteRecord::new();
-        let mut wtr = Config::new(self.flag_output.as_ref())
-            .flexible(self.flag_flexible)
-            .writer()?;
+        let mut out_row = csv::ByteRecord::new();
+        let mut wtr = self.wconfig().flexible(self.flag_flexible).writer()?;
         let mut rdr;
+        let mut sampler = self.sampler();
 
-        let mut configs = self.configs()?.into_iter();
+        // the first file we keep is special, as it has the headers.
+        // with --drop-empty, that may not be the first file given,
+        // as inputs with no data rows are skipped entirely
+        let mut wrote_headers = false;
 
-        // the first file is special, as it has the headers
-        // if --no-headers is set, we just write the first file
-        if let Some(conf) = configs.next() {
+        for conf in self.configs()? {
             rdr = conf.reader()?;
-            conf.write_headers(&mut rdr, &mut wtr)?;
-            while rdr.read_byte_record(&mut row)? {
-                wtr.write_byte_record(&row)?;
+            if self.flag_drop_empty {
+                // probe for a data row. With headers enabled, the first read
+                // transparently consumes the header row first, so an empty
+                // probe means the file is header-only or completely empty
+                if !rdr.read_byte_record(&mut row)? {
+                    winfo!("Skipping file with no data rows: {:?}", conf.path);
+                    continue;
+                }
+            }
+            let source_value = if with_source {
+                conf.path.as_deref().map_or_else(
+                    || "stdin".to_owned(),
+                    |path| Self::source_value(&source_kind, path),
+                )
+            } else {
+                String::new()
+            };
+            if !wrote_headers {
+                if with_source && !self.flag_no_headers {
+                    out_row.clear();
+                    out_row.push_field(self.flag_source_name.as_bytes());
+                    out_row.extend(rdr.byte_headers()?);
+                    wtr.write_byte_record(&out_row)?;
+                } else {
+                    conf.write_headers(&mut rdr, &mut wtr)?;
+                }
+                wrote_headers = true;
+            }
+            if self.flag_drop_empty && Self::keep_row(&mut sampler) {
+                // the emptiness probe consumed the first data row
+                Self::write_row_with_source(
+                    &mut wtr,
+                    &mut out_row,
+                    &row,
+                    with_source,
+                    &source_value,
+                )?;
             }
-        }
-
-        // the rest of the files are just written
-        // as fast as possible, as we don't need to
-        // worry about headers
-        for conf in configs {
-            rdr = conf.reader()?;
             while rdr.read_byte_record(&mut row)? {
-                wtr.write_byte_record(&row)?;
+                if Self::keep_row(&mut sampler) {
+                    Self::write_row_with_source(
+                        &mut wtr,
+                        &mut out_row,
+                        &row,
+                        with_source,
+                        &source_value,
+                    )?;
+                }
             }
         }
 
         Ok(wtr.flush()?)
     }
 
+    /// the per-file value of the --with-source column for the given kind
+    fn source_value(kind: &GroupKind, path: &Path) -> String {
+        // safety: we know that this is a valid file path and if the file path
+        // is not utf8, we convert it to lossy utf8
+        match kind {
+            GroupKind::FullPath => path.canonicalize().unwrap().to_string_lossy().into_owned(),
+            GroupKind::ParentDirFName => get_parentdir_and_file(path, false),
+            GroupKind::ParentDirFStem => get_parentdir_and_file(path, true),
+            GroupKind::FName => path.file_name().unwrap().to_string_lossy().into_owned(),
+            GroupKind::FStem => path.file_stem().unwrap().to_string_lossy().into_owned(),
+            GroupKind::None => String::new(),
+        }
+    }
+
+    /// write one concatenated row, prepending the --with-source value when set
+    fn write_row_with_source<W: std::io::Write>(
+        wtr: &mut csv::Writer<W>,
+        out_row: &mut csv::ByteRecord,
+        row: &csv::ByteRecord,
+        with_source: bool,
+        source_value: &str,
+    ) -> CliResult<()> {
+        if with_source {
+            out_row.clear();
+            out_row.push_field(source_value.as_bytes());
+            out_row.extend(row);
+            wtr.write_byte_record(out_row)?;
+        } else {
+            wtr.write_byte_record(row)?;
+        }
+        Ok(())
+    }
+
     // this algorithm is largely inspired by https://github.com/vi/csvcatrow by @vi
     // https://github.com/dathere/qsv/issues/527
     fn cat_rowskey(&self) -> CliResult<()> {
@@ -204,6 +561,8 @@ impl Args {
             );
         };
 
+        let rename_map = self.rename_map()?;
+
         let mut columns_global: FhashIndexSet<Box<[u8]>> = FhashIndexSet::default();
 
         if group_kind != GroupKind::None {
@@ -219,45 +578,125 @@ impl Args {
         // we need to create a temporary header in case --no-headers is set
         let mut temp_header = csv::ByteRecord::new();
 
-        // First pass, add all column headers to an IndexSet
-        for conf in &self.configs()? {
-            if conf.is_stdin() {
-                stdin_tempfilename = temp_dir.path().join("stdin");
-                let tmp_file = std::fs::File::create(&stdin_tempfilename)?;
-                let mut tmp_file =
-                    std::io::BufWriter::with_capacity(DEFAULT_WTR_BUFFER_CAPACITY, tmp_file);
-                std::io::copy(&mut std::io::stdin(), &mut tmp_file)?;
+        // with --drop-empty, inputs with no data rows are skipped entirely -
+        // their columns don't join the union and they're not read a second time
+        let configs = self.configs()?;
+        let mut skip_input = vec![false; configs.len()];
+
+        // --align-report: each input's label and column set, gathered during
+        // the header-discovery pass for the presence matrix
+        let mut file_columns: Vec<(String, FhashIndexSet<Box<[u8]>>)> = Vec::new();
+
+        // --columns: the output schema is supplied up front, so the
+        // header-discovery pass over the inputs is skipped entirely and
+        // each input is streamed once into the fixed schema
+        if let Some(ref columns_file) = self.flag_columns {
+            for line in std::fs::read_to_string(columns_file)?.lines() {
+                let column = line.trim();
+                if !column.is_empty() {
+                    columns_global.insert(column.as_bytes().to_vec().into_boxed_slice());
+                }
             }
-            let mut rdr = conf.reader()?;
+        } else {
+            // First pass, add all column headers to an IndexSet
+            for (conf_idx, conf) in configs.iter().enumerate() {
+                if conf.is_stdin() {
+                    stdin_tempfilename = temp_dir.path().join("stdin");
+                    let tmp_file = std::fs::File::create(&stdin_tempfilename)?;
+                    let mut tmp_file =
+                        std::io::BufWriter::with_capacity(DEFAULT_WTR_BUFFER_CAPACITY, tmp_file);
+                    std::io::copy(&mut std::io::stdin(), &mut tmp_file)?;
+                }
+                let mut rdr = conf.reader()?;
 
-            // if self.flag_no_headers is set, we create temporary headers
-            // to use as keys, using the convention "_c_1", "_c_2", "_c_3", etc.
-            let header = if self.flag_no_headers {
-                let mut header = csv::ByteRecord::new();
-                rdr.read_byte_record(&mut header)?;
-                temp_header.clear();
-                for (n, _) in header.iter().enumerate() {
-                    temp_header.push_field(format!("_c_{}", n + 1).as_bytes());
+                // if self.flag_no_headers is set, we create temporary headers
+                // to use as keys, using the convention "_c_1", "_c_2", "_c_3", etc.
+                let header = if self.flag_no_headers {
+                    let mut header = csv::ByteRecord::new();
+                    let has_rows = rdr.read_byte_record(&mut header)?;
+                    if self.flag_drop_empty && !has_rows {
+                        skip_input[conf_idx] = true;
+                        winfo!("Skipping file with no data rows: {:?}", conf.path);
+                        continue;
+                    }
+                    temp_header.clear();
+                    for (n, _) in header.iter().enumerate() {
+                        temp_header.push_field(format!("_c_{}", n + 1).as_bytes());
+                    }
+                    &temp_header
+                } else {
+                    if self.flag_drop_empty {
+                        // probe for a data row after the header
+                        let mut probe = csv::ByteRecord::new();
+                        if !rdr.read_byte_record(&mut probe)? {
+                            skip_input[conf_idx] = true;
+                            winfo!("Skipping file with no data rows: {:?}", conf.path);
+                            continue;
+                        }
+                    }
+                    rdr.byte_headers()?
+                };
+
+                // --rename: apply the aliases to this file's headers before
+                // they join the union
+                let renamed_header;
+                let header = if let Some(ref rename_map) = rename_map {
+                    renamed_header = self.apply_rename(header, rename_map);
+                    &renamed_header
+                } else {
+                    header
+                };
+
+                for field in header {
+                    let fi = field.to_vec().into_boxed_slice();
+                    columns_global.insert(fi);
                 }
-                &temp_header
-            } else {
-                rdr.byte_headers()?
-            };
 
-            for field in header {
-                let fi = field.to_vec().into_boxed_slice();
-                columns_global.insert(fi);
+                if self.flag_align_report.is_some() {
+                    let label = conf.path.as_ref().map_or_else(
+                        || "stdin".to_string(),
+                        |p| p.to_string_lossy().into_owned(),
+                    );
+                    file_columns.push((
+                        label,
+                        header
+                            .iter()
+                            .map(|f| f.to_vec().into_boxed_slice())
+                            .collect(),
+                    ));
+                }
             }
         }
+
         let num_columns_global = columns_global.len();
 
+        // --align-report: write the presence matrix - one row per union
+        // column, one column per input file, with 1/0 marking whether the
+        // file has that column
+        if let Some(ref report_file) = self.flag_align_report {
+            let mut report_wtr = Config::new(Some(report_file)).writer()?;
+            let mut report_row = csv::ByteRecord::with_capacity(500, file_columns.len() + 1);
+            report_row.push_field(b"column");
+            for (label, _) in &file_columns {
+                report_row.push_field(label.as_bytes());
+            }
+            report_wtr.write_byte_record(&report_row)?;
+            for c in &columns_global {
+                report_row.clear();
+                report_row.push_field(c);
+                for (_, columns) in &file_columns {
+                    report_row.push_field(if columns.contains(c) { b"1" } else { b"0" });
+                }
+                report_wtr.write_byte_record(&report_row)?;
+            }
+            report_wtr.flush()?;
+        }
+
         // Second pass, write all columns to a new file
         // set flexible to true for faster writes
         // as we know that all columns are already in columns_global and we don't need to
         // validate that the number of columns are the same every time we write a row
-        let mut wtr = Config::new(self.flag_output.as_ref())
-            .flexible(true)
-            .writer()?;
+        let mut wtr = self.wconfig().flexible(true).writer()?;
         let mut new_row = csv::ByteRecord::with_capacity(500, num_columns_global);
 
         // write the header
@@ -265,9 +704,14 @@ impl Args {
             for c in &columns_global {
                 new_row.push_field(c);
             }
+            if self.flag_source_coverage {
+                new_row.push_field(b"source_coverage");
+            }
             wtr.write_byte_record(&new_row)?;
         }
 
+        let mut sampler = self.sampler();
+
         // amortize allocations
         let mut grouping_value = String::new();
         let mut conf_path;
@@ -277,8 +721,28 @@ impl Args {
         columns_of_this_file.reserve(num_columns_global);
         let mut row: csv::ByteRecord = csv::ByteRecord::with_capacity(500, num_columns_global);
 
-        for conf in self.configs()? {
+        // --strict: track which of the supplied --columns were matched in
+        // at least one input. The --group column never matches an input
+        // header, so it's marked matched upfront
+        let mut matched_columns = vec![false; num_columns_global];
+        if group_kind != GroupKind::None {
+            matched_columns[0] = true;
+        }
+
+        for (conf_idx, conf) in self.configs()?.into_iter().enumerate() {
+            if skip_input[conf_idx] {
+                continue;
+            }
             if conf.is_stdin() {
+                if self.flag_columns.is_some() {
+                    // --columns skips the header-discovery pass, so stdin
+                    // hasn't been saved to the temp file yet
+                    stdin_tempfilename = temp_dir.path().join("stdin");
+                    let tmp_file = std::fs::File::create(&stdin_tempfilename)?;
+                    let mut tmp_file =
+                        std::io::BufWriter::with_capacity(DEFAULT_WTR_BUFFER_CAPACITY, tmp_file);
+                    std::io::copy(&mut std::io::stdin(), &mut tmp_file)?;
+                }
                 rdr = Config::new(Some(stdin_tempfilename.to_string_lossy().to_string()).as_ref())
                     .reader()?;
                 conf_path = Some(stdin_tempfilename.clone());
@@ -293,6 +757,13 @@ impl Args {
                 rdr.byte_headers()?
             };
 
+            // --rename: align this file's rows under the aliased column names
+            let renamed_header;
+            if let Some(ref rename_map) = rename_map {
+                renamed_header = self.apply_rename(header, rename_map);
+                header = &renamed_header;
+            }
+
             columns_of_this_file.clear();
 
             for (n, field) in header.iter().enumerate() {
@@ -307,6 +778,14 @@ impl Args {
                 columns_of_this_file.insert(fi, n);
             }
 
+            if self.flag_strict {
+                for (n, c) in columns_global.iter().enumerate() {
+                    if !matched_columns[n] && columns_of_this_file.contains_key(c) {
+                        matched_columns[n] = true;
+                    }
+                }
+            }
+
             // safety: we know that this is a valid file path
             let conf_pathbuf = conf_path.unwrap();
 
@@ -339,7 +818,22 @@ impl Args {
             let group_flag = group_kind != GroupKind::None;
             let grouping_value_bytes = grouping_value.as_bytes();
 
+            // the union columns present in this file is a per-file constant,
+            // so compute the coverage count once, before scanning its rows
+            let source_coverage = if self.flag_source_coverage {
+                columns_global
+                    .iter()
+                    .filter(|c| columns_of_this_file.contains_key(*c))
+                    .count()
+                    .to_string()
+            } else {
+                String::new()
+            };
+
             while rdr.read_byte_record(&mut row)? {
+                if !Self::keep_row(&mut sampler) {
+                    continue;
+                }
                 new_row.clear();
                 for (col_idx, c) in columns_global.iter().enumerate() {
                     match columns_of_this_file.get(c) {
@@ -361,15 +855,38 @@ impl Args {
                         },
                     }
                 }
+                if self.flag_source_coverage {
+                    new_row.push_field(source_coverage.as_bytes());
+                }
                 wtr.write_byte_record(&new_row)?;
             }
         }
 
+        if self.flag_strict {
+            let unmatched: Vec<String> = columns_global
+                .iter()
+                .zip(&matched_columns)
+                .filter(|(_, matched)| !**matched)
+                .map(|(c, _)| String::from_utf8_lossy(c).into_owned())
+                .collect();
+            if !unmatched.is_empty() {
+                return fail_clierror!(
+                    "--strict: {} --columns column/s not found in any input: {}",
+                    unmatched.len(),
+                    unmatched.join(", ")
+                );
+            }
+        }
+
         Ok(wtr.flush()?)
     }
 
     fn cat_columns(&self) -> CliResult<()> {
-        let mut wtr = Config::new(self.flag_output.as_ref()).writer()?;
+        if let Some(ref key) = self.flag_on {
+            return self.cat_columns_on(key.as_bytes());
+        }
+
+        let mut wtr = self.wconfig().writer()?;
         let mut rdrs = self
             .configs()?
             .into_iter()
@@ -391,6 +908,12 @@ impl Args {
         // safety: there's always a first element
         let mut record = csv::ByteRecord::with_capacity(1024, *lengths.first().unwrap());
 
+        let fill = self.flag_fill.as_deref().unwrap_or_default().as_bytes();
+
+        // --dedup-headers: the first record written is the concatenated
+        // header row, which is where name collisions are disambiguated
+        let mut dedup_headers_pending = self.flag_dedup_headers;
+
         'OUTER: loop {
             record.clear();
             let mut num_done = 0;
@@ -400,7 +923,7 @@ impl Args {
                         num_done += 1;
                         if self.flag_pad {
                             for _ in 0..len {
-                                record.push_field(b"");
+                                record.push_field(fill);
                             }
                         } else {
                             break 'OUTER;
@@ -416,8 +939,98 @@ impl Args {
             if num_done >= iters.len() {
                 break 'OUTER;
             }
+            if dedup_headers_pending {
+                dedup_headers_pending = false;
+                wtr.write_byte_record(&Self::dedup_headers(&record))?;
+            } else {
+                wtr.write_byte_record(&record)?;
+            }
+        }
+        Ok(wtr.flush()?)
+    }
+
+    /// --on: concatenate columns by aligning each input's rows on the key
+    /// column's value - a full outer join on a single key - instead of
+    /// pairing rows positionally
+    fn cat_columns_on(&self, key: &[u8]) -> CliResult<()> {
+        // foldhash is a faster hasher than the default one used by IndexSet and IndexMap
+        type FhashIndexSet<T> = IndexSet<T, foldhash::fast::RandomState>;
+        type FhashIndexMap<T, T2> = IndexMap<T, T2, foldhash::fast::RandomState>;
+
+        let mut wtr = self.wconfig().writer()?;
+        let fill = self.flag_fill.as_deref().unwrap_or_default().as_bytes();
+
+        // keys in the order they first appear across the inputs
+        let mut all_keys: FhashIndexSet<Vec<u8>> = FhashIndexSet::default();
+        // per input: its column count and its rows indexed by key value
+        let mut inputs: Vec<(usize, FhashIndexMap<Vec<u8>, csv::ByteRecord>)> = Vec::new();
+        let mut out_headers = csv::ByteRecord::new();
+
+        for conf in self.configs()? {
+            let mut rdr = conf.reader()?;
+            let headers = rdr.byte_headers()?.clone();
+            let Some(key_idx) = headers.iter().position(|h| h == key) else {
+                return fail_incorrectusage_clierror!(
+                    "--on column '{}' not found in {:?}",
+                    String::from_utf8_lossy(key),
+                    conf.path
+                );
+            };
+            out_headers.extend(&headers);
+
+            let mut rows: FhashIndexMap<Vec<u8>, csv::ByteRecord> = FhashIndexMap::default();
+            for row in rdr.byte_records() {
+                let row = row?;
+                let key_val = row.get(key_idx).unwrap_or_default().to_vec();
+                if !rows.contains_key(&key_val) {
+                    all_keys.insert(key_val.clone());
+                    rows.insert(key_val, row);
+                }
+            }
+            inputs.push((headers.len(), rows));
+        }
+
+        if self.flag_dedup_headers {
+            wtr.write_byte_record(&Self::dedup_headers(&out_headers))?;
+        } else {
+            wtr.write_byte_record(&out_headers)?;
+        }
+
+        let mut record = csv::ByteRecord::with_capacity(1024, out_headers.len());
+        for key_val in &all_keys {
+            record.clear();
+            for (len, rows) in &inputs {
+                if let Some(row) = rows.get(key_val) {
+                    record.extend(row);
+                } else {
+                    for _ in 0..*len {
+                        record.push_field(fill);
+                    }
+                }
+            }
             wtr.write_byte_record(&record)?;
         }
         Ok(wtr.flush()?)
     }
+
+    /// --dedup-headers: rename duplicate column names to `name_2`, `name_3`,
+    /// etc. based on source order, leaving the first occurrence untouched
+    fn dedup_headers(headers: &csv::ByteRecord) -> csv::ByteRecord {
+        let mut seen: HashMap<Vec<u8>, usize> = HashMap::with_capacity(headers.len());
+        let mut new_headers =
+            csv::ByteRecord::with_capacity(headers.as_slice().len(), headers.len());
+        for field in headers {
+            let count = seen
+                .entry(field.to_vec())
+                .and_modify(|c| *c += 1)
+                .or_insert(1);
+            if *count == 1 {
+                new_headers.push_field(field);
+            } else {
+                new_headers
+                    .push_field(format!("{}_{count}", String::from_utf8_lossy(field)).as_bytes());
+            }
+        }
+        new_headers
+    }
 }
@@ -7,7 +7,8 @@ columns - field,value,count,percentage.
 
 In JSON output mode, the table is formatted as nested JSON data. In addition to
 the columns above, the JSON output also includes the row count, field count, each
-field's data type, cardinality, nullcount, sparsity, uniqueness_ratio and its stats.
+field's data type, cardinality, nullcount, sparsity, uniqueness_ratio, entropy,
+normalized_entropy and its stats.
 
 Since this command computes an exact frequency distribution table, memory proportional
 to the cardinality of each column would be normally required.
@@ -69,6 +70,13 @@ frequency options:
                             details. This is provided here because piping 'qsv
                             select' into 'qsv frequency' will disable the use
                             of indexing.
+    --combine               When multiple columns are selected, compute a single
+                            joint frequency table of the selected columns' value
+                            combinations instead of per-column tables. The field
+                            column lists the tab-joined header names and each value
+                            is the tab-joined combination of the selected columns'
+                            values. Works in both CSV and JSON output modes.
+                            Cannot be used with --bins or --bounded.
     -l, --limit <arg>       Limit the frequency table to the N most common
                             items. Set to '0' to disable a limit.
                             If negative, only return values with an occurrence
@@ -85,6 +93,28 @@ frequency options:
                             in a column >= threshold, the limits will be applied.
                             Set to '0' to disable the threshold and always apply limits.
                             [default: 0]
+    --bins <N>              For columns the stats cache types as Integer or Float,
+                            group values into N equal-width bins spanning the
+                            column's min..max (pulled from the stats cache, like
+                            cardinalities) instead of a per-value frequency table,
+                            with the value column showing the bin range "[lo, hi)".
+                            Empty values are reported as a trailing NULL bin.
+                            Columns without numeric stats fall back to the normal
+                            per-value behavior, as does the whole table when the
+                            stats cache does not exist. In JSON mode, numeric
+                            fields report a "bins" array instead of "frequencies".
+                            Cannot be used with --bounded.
+                            Set to '0' to disable. [default: 0]
+    --weight <column>       Sum this weight column instead of counting rows - for
+                            pre-aggregated inputs that already carry a count column.
+                            Each selected field's frequency is increased by the row's
+                            weight (a non-negative integer) instead of 1, and
+                            percentages are computed against the weighted total.
+                            Rows with an empty or non-numeric weight are an error.
+                            With --no-headers, a 1-based column index can be used.
+                            Disables the all-unique stats cache short-circuit, as
+                            row count no longer equals weighted count.
+                            Cannot be used with --bounded.
     --pct-dec-places <arg>  The number of decimal places to round the percentage to.
                             If negative, the number of decimal places will be set
                             automatically to the minimum number of decimal places needed
@@ -100,8 +130,35 @@ frequency options:
                             [default: Other]
     -a, --asc               Sort the frequency tables in ascending order by count.
                             The default is descending order.
+    --first-seen-order      Order each frequency table by the order in which each
+                            value first appeared in the data, rather than by count.
+                            Useful when the input order is meaningful (e.g.
+                            chronological categories). --limit still keeps the
+                            most frequent values; only the display order changes.
+                            Forces sequential processing even when an index is
+                            present. Cannot be used with --asc, --bins, --combine
+                            or --bounded.
+    --cumulative            Append a "cumulative_percentage" column to the CSV output -
+                            a running total of the percentage column, computed in the
+                            sorted output order (respecting --asc) and reset per field.
+                            The "Other" category is included in the running total.
+                            In JSON mode, a matching cumulative_percentage is added
+                            to each frequency entry.
+    --normalize-to-max      Append a "normalized" column to the CSV output - each
+                            value's count expressed as a percentage of the most
+                            frequent value's count in that field, so the top value
+                            reports 100 and the others scale proportionally.
+                            Handy for bar-chart-style visualization.
     --no-trim               Don't trim whitespace from values when computing frequencies.
                             The default is to trim leading and trailing whitespaces.
+    --collapse-whitespace   Replace each run of internal whitespace in a value with
+                            a single space when computing frequencies, so internal
+                            whitespace variants (e.g. "New  York" & "New York")
+                            count as the same value.
+    --strip-bom-values      Strip a leading UTF-8 Byte Order Mark from field values
+                            before counting, so BOM-contaminated values (typically
+                            the first data field of a Windows export) group with
+                            their clean counterparts.
     --no-nulls              Don't include NULLs in the frequency table.
     -i, --ignore-case       Ignore case when computing frequencies.
    --all-unique-text <arg>  The text to use for the "<ALL_UNIQUE>" category.
@@ -112,13 +169,88 @@ frequency options:
     -j, --jobs <arg>        The number of jobs to run in parallel when the given CSV data has
                             an index. Note that a file handle is opened for each job.
                             When not set, defaults to the number of CPUs detected.
+    --bounded <k>           Compute the frequency table in a single streaming pass,
+                            keeping at most K distinct values per column. When a
+                            column's counter is full, the least frequent value is
+                            evicted and its count folded into the "Other" category,
+                            bounding memory to O(K) per column. Counts are exact for
+                            the top values of skewed columns; the evicted tail is
+                            approximate. Useful for unindexed, larger-than-memory
+                            files without a stats cache. Cannot be used with --json.
+                            Set to '0' to disable. [default: 0]
+    --two-pass              Make a first streaming pass to compute exact per-column
+                            cardinalities, then build exactly-sized frequency hashmaps
+                            on the second pass, trading an extra read for reduced
+                            allocation churn and memory. A performance alternative to
+                            a stats cache when none is available. The frequency tables
+                            themselves are identical to the single-pass mode's.
+                            Requires a file input, not stdin.
+    --sample <n>            Estimate the frequency table from a reservoir sample of
+                            up to <n> rows instead of tallying every row. The input
+                            is still streamed once to draw the sample, but only the
+                            sampled rows are tallied, and their counts are scaled by
+                            (rowcount / <n>) to estimate the full population, with
+                            percentages remaining the sample's proportions. A note
+                            marking the output as estimated is printed to stderr.
+                            Trades exactness for speed on massive files. Cannot be
+                            used with --bins, --bounded, --weight, --two-pass or
+                            --first-seen-order. Set to '0' to disable. [default: 0]
+    --seed <arg>            Random Number Generator (RNG) seed for --sample, making
+                            the drawn sample deterministic.
+    --coverage              Report a per-column concentration summary to stderr - the
+                            number of distinct top values needed to cover 50%, 80% and
+                            95% of rows. This summarizes value concentration without
+                            dumping the full frequency table.
+    --null-report <file>    Write a per-column null report CSV to <file> with the
+                            columns - field,nullcount,nullpercentage - for each
+                            selected column, independent of whether NULLs appear
+                            in the frequency table. Uses the stats cache nullcount
+                            when available, otherwise counts empty fields with an
+                            additional streaming pass over the input.
+    --outliers <max_count>  Instead of outputting the top-values frequency table,
+                            report only values appearing <max_count> times or fewer,
+                            per column - a focused report of rare/suspicious
+                            categories (e.g. data-entry errors), rarest first.
+                            Columns short-circuited as all-unique using the stats
+                            cache are skipped as every value is a singleton.
+    --find-duplicate-columns  Instead of outputting a frequency table, compare the value
+                            distributions of the selected columns and report pairs of
+                            columns with identical distributions (candidate duplicate or
+                            derived columns) as CSV with the columns - column_1,column_2.
+                            Columns short-circuited as all-unique using the stats cache
+                            are skipped as their frequencies are not compiled.
+    --explain               Print the optimization decisions made while compiling the
+                            frequency table to stderr - whether the stats cache was used,
+                            which columns were short-circuited as all-unique, whether
+                            frequencies were compiled in parallel, and the per-column
+                            hashmap capacities chosen.
+    --bom                   Write a UTF-8 Byte Order Mark (BOM) at the start of the
+                            CSV output so Excel reads it as UTF-8. Applies to both
+                            stdout and --output.
+    --md                    Output the frequency table as GitHub-flavored Markdown
+                            tables instead of CSV - one table per field with
+                            field/value/count/percentage columns. When multiple
+                            fields are selected, each table is preceded by a
+                            level 3 <field> heading and separated by a blank line.
+                            Cannot be used with --json or --jsonl.
+    --split-output <dir>    Instead of one combined output, write a separate file
+                            per selected field into <dir> (creating it if needed) -
+                            "<field>.csv" with value, count & percentage columns,
+                            or "<field>.json" when --json is also set. Field names
+                            are sanitized for filesystem safety and disambiguated
+                            with a _N suffix when they collide.
 
                             JSON OUTPUT OPTIONS:
     --json                  Output frequency table as nested JSON instead of CSV.
                             The JSON output includes row count, field count & each field's
-                            data type, cardinality, null count, sparsity, uniqueness_ratio
-                            and its stats.
-    --no-stats              When using the JSON output mode, do not include stats.
+                            data type, cardinality, null count, sparsity, uniqueness_ratio,
+                            entropy, normalized_entropy and its stats.
+    --jsonl                 Output the frequency table as JSON Lines - one JSON
+                            object per field (the same per-field structure as the
+                            --json "fields" array, in field order) so results can
+                            be consumed incrementally as they stream in.
+                            Cannot be used with --json.
+    --no-stats              When using the JSON output modes, do not include stats.
 
 Common options:
     -h, --help             Display this message
@@ -133,11 +265,12 @@ Common options:
                            CSV into memory using CONSERVATIVE heuristics.
 "#;
 
-use std::{fs, io, sync::OnceLock};
+use std::{fs, hash::BuildHasher, io, sync::OnceLock};
 
 use crossbeam_channel;
-use foldhash::{HashMap, HashMapExt};
+use foldhash::{HashMap, HashMapExt, HashSet, HashSetExt};
 use indicatif::HumanCount;
+use rand::{Rng, SeedableRng, rngs::StdRng};
 use rust_decimal::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_json::{self, Value as JsonValue};
@@ -158,24 +291,45 @@ use crate::{
 pub struct Args {
     pub arg_input:            Option<String>,
     pub flag_select:          SelectColumns,
+    pub flag_combine:         bool,
     pub flag_limit:           isize,
     pub flag_unq_limit:       usize,
     pub flag_lmt_threshold:   usize,
+    pub flag_bins:            usize,
+    pub flag_weight:          Option<String>,
     pub flag_pct_dec_places:  isize,
     pub flag_other_sorted:    bool,
     pub flag_other_text:      String,
     pub flag_asc:             bool,
+    pub flag_first_seen_order: bool,
+    pub flag_cumulative:      bool,
+    pub flag_normalize_to_max: bool,
     pub flag_no_trim:         bool,
+    pub flag_collapse_whitespace: bool,
+    pub flag_strip_bom_values: bool,
     pub flag_no_nulls:        bool,
     pub flag_ignore_case:     bool,
     pub flag_all_unique_text: String,
     pub flag_jobs:            Option<usize>,
+    pub flag_bounded:         usize,
+    pub flag_two_pass:        bool,
+    pub flag_sample:          usize,
+    pub flag_seed:            Option<u64>,
+    pub flag_coverage:        bool,
+    pub flag_outliers:        Option<u64>,
+    pub flag_null_report:     Option<String>,
+    pub flag_find_duplicate_columns: bool,
+    pub flag_explain:         bool,
+    pub flag_bom:             bool,
+    pub flag_md:              bool,
+    pub flag_split_output:    Option<String>,
     pub flag_output:          Option<String>,
     pub flag_no_headers:      bool,
     pub flag_delimiter:       Option<Delimiter>,
     pub flag_memcheck:        bool,
     pub flag_vis_whitespace:  bool,
     pub flag_json:            bool,
+    pub flag_jsonl:           bool,
     pub flag_no_stats:        bool,
 }
 
@@ -191,18 +345,26 @@ struct FrequencyEntry {
     value:      String,
     count:      u64,
     percentage: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cumulative_percentage: Option<f64>,
 }
 
 #[derive(Serialize)]
 struct FrequencyField {
-    field:            String,
-    r#type:           String,
-    cardinality:      u64,
-    nullcount:        u64,
-    sparsity:         f64,
-    uniqueness_ratio: f64,
-    stats:            Vec<FieldStats>,
-    frequencies:      Vec<FrequencyEntry>,
+    field:              String,
+    r#type:             String,
+    cardinality:        u64,
+    nullcount:          u64,
+    sparsity:           f64,
+    uniqueness_ratio:   f64,
+    // null for all-unique ID columns, as the stats cache short-circuits
+    // their frequency compilation
+    entropy:            Option<f64>,
+    normalized_entropy: Option<f64>,
+    stats:              Vec<FieldStats>,
+    frequencies:        Vec<FrequencyEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    bins:               Vec<FrequencyEntry>,
 }
 
 #[derive(Serialize, Clone)]
@@ -232,7 +394,23 @@ struct ProcessedFrequency {
 
 static UNIQUE_COLUMNS_VEC: OnceLock<Vec<usize>> = OnceLock::new();
 static COL_CARDINALITY_VEC: OnceLock<Vec<(String, u64)>> = OnceLock::new();
+// per-column (min, max) from the stats cache for columns typed Integer/Float,
+// only populated when --bins is set. None for non-numeric columns
+static COL_NUMERIC_RANGE_VEC: OnceLock<Vec<Option<(f64, f64)>>> = OnceLock::new();
+// the --weight column's position in the full record, resolved by sel_headers
+static WEIGHT_COL_IDX: OnceLock<Option<usize>> = OnceLock::new();
+// the first bad --weight value encountered while compiling frequencies.
+// The hot loop (and its parallel chunks) cannot return errors, so it is
+// recorded here and checked after compilation
+static WEIGHT_ERROR: OnceLock<String> = OnceLock::new();
 static FREQ_ROW_COUNT: OnceLock<u64> = OnceLock::new();
+// for --sample: the factor (rows read / rows sampled) by which sampled
+// counts are scaled up to estimate the full population
+static SAMPLE_SCALE: OnceLock<f64> = OnceLock::new();
+
+// for --first-seen-order: per selected column, the rank at which each
+// distinct value first appeared, recorded while accumulating frequencies
+static FIRST_SEEN_VEC: OnceLock<Vec<HashMap<Vec<u8>, usize>>> = OnceLock::new();
 
 pub fn run(argv: &[&str]) -> CliResult<()> {
     let mut args: Args = util::get_args(USAGE, argv)?;
@@ -240,10 +418,68 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
 
     let is_stdin = rconfig.is_stdin();
 
-    // if stdin and args.flag_json is true, save stdin to tempfile
+    if args.flag_json && args.flag_jsonl {
+        return fail_incorrectusage_clierror!("--json cannot be used with --jsonl.");
+    }
+    if args.flag_md && (args.flag_json || args.flag_jsonl) {
+        return fail_incorrectusage_clierror!("--md cannot be used with --json or --jsonl.");
+    }
+    if args.flag_split_output.is_some() && (args.flag_md || args.flag_jsonl) {
+        return fail_incorrectusage_clierror!("--split-output cannot be used with --md or --jsonl.");
+    }
+    if args.flag_outliers.is_some() && (args.flag_json || args.flag_jsonl || args.flag_md) {
+        return fail_incorrectusage_clierror!(
+            "--outliers cannot be used with --json, --jsonl or --md."
+        );
+    }
+    if args.flag_outliers.is_some() && args.flag_bins > 0 {
+        return fail_incorrectusage_clierror!("--outliers cannot be used with --bins.");
+    }
+    if args.flag_combine && args.flag_bins > 0 {
+        return fail_incorrectusage_clierror!("--combine cannot be used with --bins.");
+    }
+    if args.flag_first_seen_order {
+        if args.flag_asc {
+            return fail_incorrectusage_clierror!("--first-seen-order cannot be used with --asc.");
+        }
+        if args.flag_bins > 0 {
+            return fail_incorrectusage_clierror!("--first-seen-order cannot be used with --bins.");
+        }
+        if args.flag_combine {
+            return fail_incorrectusage_clierror!(
+                "--first-seen-order cannot be used with --combine."
+            );
+        }
+        if args.flag_bounded > 0 {
+            return fail_incorrectusage_clierror!(
+                "--first-seen-order cannot be used with --bounded."
+            );
+        }
+    }
+    if args.flag_sample > 0 {
+        if args.flag_bins > 0 {
+            return fail_incorrectusage_clierror!("--sample cannot be used with --bins.");
+        }
+        if args.flag_bounded > 0 {
+            return fail_incorrectusage_clierror!("--sample cannot be used with --bounded.");
+        }
+        if args.flag_weight.is_some() {
+            return fail_incorrectusage_clierror!("--sample cannot be used with --weight.");
+        }
+        if args.flag_two_pass {
+            return fail_incorrectusage_clierror!("--sample cannot be used with --two-pass.");
+        }
+        if args.flag_first_seen_order {
+            return fail_incorrectusage_clierror!(
+                "--sample cannot be used with --first-seen-order."
+            );
+        }
+    }
+
+    // if stdin and a JSON output mode is set, save stdin to tempfile
     // so we can derive stats
     let mut stdin_temp_file;
-    if is_stdin && args.flag_json {
+    if is_stdin && (args.flag_json || args.flag_jsonl) {
         let temp_dir = std::env::temp_dir();
         stdin_temp_file = tempfile::Builder::new()
             .suffix(".csv")
@@ -253,20 +489,93 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
         rconfig = args.rconfig();
     }
 
+    if args.flag_bounded > 0 {
+        if args.flag_json || args.flag_jsonl {
+            return fail_incorrectusage_clierror!("--bounded cannot be used with --json or --jsonl.");
+        }
+        if args.flag_bins > 0 {
+            return fail_incorrectusage_clierror!("--bounded cannot be used with --bins.");
+        }
+        if args.flag_weight.is_some() {
+            return fail_incorrectusage_clierror!("--bounded cannot be used with --weight.");
+        }
+        if args.flag_combine {
+            return fail_incorrectusage_clierror!("--bounded cannot be used with --combine.");
+        }
+        if args.flag_split_output.is_some() {
+            return fail_incorrectusage_clierror!("--bounded cannot be used with --split-output.");
+        }
+        // --bounded streams the input with O(K) memory per column,
+        // so no memory check is needed
+        return args.bounded_topk();
+    }
+
     // we're loading the entire file into memory, we need to check avail mem
     if let Some(path) = rconfig.path.clone() {
         util::mem_file_check(&path, false, args.flag_memcheck)?;
     }
 
+    let mut parallel = false;
+    // --two-pass: compute exact per-column cardinalities upfront so the
+    // frequency hashmaps are sized exactly on the second pass
+    if args.flag_two_pass {
+        if args.rconfig().is_stdin() {
+            return fail_incorrectusage_clierror!("--two-pass requires a file input, not stdin.");
+        }
+        args.compute_cardinalities()?;
+    }
+
+    // --first-seen-order tracks first-appearance ranks during accumulation,
+    // which is only meaningful when the input is scanned in one pass.
+    // --sample likewise draws its reservoir in one sequential pass
     let (headers, tables) = match args.rconfig().indexed()? {
-        Some(ref mut idx) if util::njobs(args.flag_jobs) > 1 => args.parallel_ftables(idx),
+        Some(ref mut idx)
+            if util::njobs(args.flag_jobs) > 1
+                && !args.flag_first_seen_order
+                && args.flag_sample == 0 =>
+        {
+            parallel = true;
+            args.parallel_ftables(idx)
+        },
         _ => args.sequential_ftables(),
     }?;
 
-    if args.flag_json {
+    if let Some(weight_error) = WEIGHT_ERROR.get() {
+        return fail_clierror!("{weight_error}");
+    }
+
+    if args.flag_explain {
+        args.explain(&headers, parallel);
+    }
+
+    if args.flag_coverage {
+        args.coverage_report(&headers, &tables);
+    }
+
+    if let Some(ref null_report_path) = args.flag_null_report {
+        args.null_report(&headers, null_report_path)?;
+    }
+
+    if args.flag_find_duplicate_columns {
+        return args.find_duplicate_columns(&headers, &tables);
+    }
+
+    if let Some(max_count) = args.flag_outliers {
+        return args.output_outliers(&headers, tables, &rconfig, max_count);
+    }
+
+    if args.flag_json || args.flag_jsonl {
         return args.output_json(&headers, tables, &rconfig, argv, is_stdin);
     }
 
+    if args.flag_md {
+        return args.output_markdown(&headers, tables, &rconfig);
+    }
+
+    if let Some(ref split_dir) = args.flag_split_output {
+        return args.output_split_csv(&headers, tables, &rconfig, split_dir);
+    }
+
     // amortize allocations
     #[allow(unused_assignments)]
     let mut header_vec: Vec<u8> = Vec::with_capacity(tables.len());
@@ -281,13 +590,29 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
     let mut processed_frequencies: Vec<ProcessedFrequency> = Vec::with_capacity(head_ftables.len());
     #[allow(unused_assignments)]
     let mut value_str = String::with_capacity(100);
+    #[allow(unused_assignments)]
+    let mut cumulative_pct_str = String::with_capacity(20);
+    #[allow(unused_assignments)]
+    let mut normalized_str = String::with_capacity(20);
 
     // safety: we know that UNIQUE_COLUMNS has been previously set
     // when compiling frequencies by sel_headers fn
     let unique_headers_vec = UNIQUE_COLUMNS_VEC.get().unwrap();
 
-    let mut wtr = Config::new(args.flag_output.as_ref()).writer()?;
-    wtr.write_record(vec!["field", "value", "count", "percentage"])?;
+    // explicitly quote only when needed, so values containing the output
+    // delimiter (e.g. tabs in TSV output) are quoted & round-trip correctly
+    let mut wtr = Config::new(args.flag_output.as_ref())
+        .quote_style(csv::QuoteStyle::Necessary)
+        .bom(args.flag_bom)
+        .writer()?;
+    let mut header_row = vec!["field", "value", "count", "percentage"];
+    if args.flag_cumulative {
+        header_row.push("cumulative_percentage");
+    }
+    if args.flag_normalize_to_max {
+        header_row.push("normalized");
+    }
+    wtr.write_record(header_row)?;
 
     for (i, (header, ftab)) in head_ftables.enumerate() {
         header_vec = if rconfig.no_headers {
@@ -297,14 +622,27 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
         };
 
         let all_unique_header = unique_headers_vec.contains(&i);
+        let bin_range = COL_NUMERIC_RANGE_VEC
+            .get()
+            .and_then(|ranges| ranges.get(i).copied().flatten());
         args.process_frequencies(
             all_unique_header,
+            bin_range,
             abs_dec_places,
             row_count,
+            i,
             &ftab,
             &mut processed_frequencies,
         );
 
+        // the running total resets per field
+        let mut cumulative_pct = 0.0_f64;
+        // the most frequent value's count, for --normalize-to-max
+        let max_count = processed_frequencies
+            .iter()
+            .map(|pf| pf.count)
+            .max()
+            .unwrap_or(0);
         for processed_freq in &processed_frequencies {
             row = vec![
                 &*header_vec,
@@ -318,6 +656,23 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
                 itoa_buffer.format(processed_freq.count).as_bytes(),
                 processed_freq.formatted_percentage.as_bytes(),
             ];
+            if args.flag_cumulative {
+                cumulative_pct += processed_freq.percentage;
+                // guard against f64 accumulation drift past 100%
+                cumulative_pct_str =
+                    args.format_percentage(cumulative_pct.min(100.0), abs_dec_places);
+                row.push(cumulative_pct_str.as_bytes());
+            }
+            if args.flag_normalize_to_max {
+                #[allow(clippy::cast_precision_loss)]
+                let normalized = if max_count > 0 {
+                    processed_freq.count as f64 * 100.0 / max_count as f64
+                } else {
+                    0.0
+                };
+                normalized_str = args.format_percentage(normalized, abs_dec_places);
+                row.push(normalized_str.as_bytes());
+            }
             wtr.write_record(row)?;
         }
         // Clear the vector for the next iteration
@@ -342,12 +697,26 @@ impl Args {
     fn process_frequencies(
         &self,
         all_unique_header: bool,
+        bin_range: Option<(f64, f64)>,
         abs_dec_places: u32,
         row_count: u64,
+        col_idx: usize,
         ftab: &FTable,
         processed_frequencies: &mut Vec<ProcessedFrequency>,
     ) {
-        if all_unique_header {
+        if let Some((min, max)) = bin_range {
+            // --bins: bucket the column into equal-width bins instead of
+            // a per-value frequency table
+            for (value, count, percentage) in self.binned_counts(ftab, min, max) {
+                let formatted_pct = self.format_percentage(percentage, abs_dec_places);
+                processed_frequencies.push(ProcessedFrequency {
+                    value,
+                    count,
+                    percentage,
+                    formatted_percentage: formatted_pct,
+                });
+            }
+        } else if all_unique_header {
             // For all-unique headers, create a single entry
             let all_unique_text = self.flag_all_unique_text.as_bytes().to_vec();
             let formatted_pct = self.format_percentage(100.0, abs_dec_places);
@@ -359,7 +728,7 @@ impl Args {
             });
         } else {
             // Process regular frequencies
-            let mut counts_to_process = self.counts(ftab);
+            let mut counts_to_process = self.counts(ftab, col_idx);
             if !self.flag_other_sorted
                 && counts_to_process.first().is_some_and(|(value, _, _)| {
                     value.starts_with(format!("{} (", self.flag_other_text).as_bytes())
@@ -411,7 +780,7 @@ impl Args {
     }
 
     #[inline]
-    fn counts(&self, ftab: &FTable) -> Vec<(ByteString, u64, f64)> {
+    fn counts(&self, ftab: &FTable, col_idx: usize) -> Vec<(ByteString, u64, f64)> {
         let (mut counts, total_count) = if self.flag_asc {
             // parallel sort in ascending order - least frequent values first
             ftab.par_frequent(true)
@@ -457,6 +826,14 @@ impl Args {
             }
         }
 
+        // --first-seen-order: re-order the (possibly limited) values by the
+        // rank each value first appeared at, tracked during accumulation
+        if self.flag_first_seen_order
+            && let Some(first_seen) = FIRST_SEEN_VEC.get().and_then(|v| v.get(col_idx))
+        {
+            counts.sort_by_key(|(value, _)| first_seen.get(*value).copied().unwrap_or(usize::MAX));
+        }
+
         let mut pct_sum = 0.0_f64;
         let mut pct: f64;
         let mut count_sum = 0_u64;
@@ -500,15 +877,341 @@ impl Args {
                 100.0_f64 - pct_sum,
             ));
         }
+
+        // --sample: scale the sampled counts up to estimate the full
+        // population. Percentages are left as the sample's proportions
+        if let Some(&scale) = SAMPLE_SCALE.get() {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            #[allow(clippy::cast_precision_loss)]
+            for (_, count, _) in &mut counts_final {
+                *count = (*count as f64 * scale).round() as u64;
+            }
+        }
         counts_final
     }
 
+    /// bucket a column's compiled frequencies into `--bins` equal-width bins
+    /// spanning min..max, returning (bin label, count, percentage) rows in bin
+    /// order. The last bin is closed ("[lo, hi]") so max itself is included.
+    /// Empty values are reported as a trailing NULL row.
+    fn binned_counts(&self, ftab: &FTable, min: f64, max: f64) -> Vec<(ByteString, u64, f64)> {
+        let nbins = self.flag_bins;
+        #[allow(clippy::cast_precision_loss)]
+        let bin_width = (max - min) / nbins as f64;
+        let (counts, total_count) = ftab.par_frequent(false);
+
+        let mut bin_counts: Vec<u64> = vec![0; nbins];
+        let mut null_count = 0_u64;
+        for (value, count) in counts {
+            // the stats cache typed this column numeric, so non-empty values
+            // parse. Defensively fold any that don't into the NULL row
+            let parsed = if value.is_empty() {
+                None
+            } else {
+                simdutf8::basic::from_utf8(value)
+                    .ok()
+                    .and_then(|s| s.parse::<f64>().ok())
+            };
+            if let Some(v) = parsed {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let bin = if bin_width > 0.0 {
+                    (((v - min) / bin_width) as usize).min(nbins - 1)
+                } else {
+                    0
+                };
+                bin_counts[bin] += count;
+            } else {
+                null_count += count;
+            }
+        }
+
+        let pct_factor = if total_count > 0 {
+            100.0_f64 / total_count.to_f64().unwrap_or(1.0_f64)
+        } else {
+            0.0_f64
+        };
+
+        let mut counts_final: Vec<(ByteString, u64, f64)> = Vec::with_capacity(nbins + 1);
+        #[allow(clippy::cast_precision_loss)]
+        for (i, count) in bin_counts.into_iter().enumerate() {
+            let lo = min + bin_width * i as f64;
+            let label = if i == nbins - 1 {
+                format!("[{lo}, {max}]")
+            } else {
+                format!("[{lo}, {})", min + bin_width * (i + 1) as f64)
+            };
+            #[allow(clippy::cast_precision_loss)]
+            counts_final.push((label.into_bytes(), count, count as f64 * pct_factor));
+        }
+        if null_count > 0 {
+            #[allow(clippy::cast_precision_loss)]
+            counts_final.push((NULL_VAL.to_vec(), null_count, null_count as f64 * pct_factor));
+        }
+        counts_final
+    }
+
+    /// compute the frequency table in a single streaming pass, keeping at most
+    /// `--bounded` K distinct values per column. When a column's counter is full,
+    /// the least frequent value is evicted and its count folded into the "Other"
+    /// aggregate, bounding memory to O(K) per column. Counts are exact for the
+    /// top values of skewed columns; the evicted tail is approximate.
+    fn bounded_topk(&self) -> CliResult<()> {
+        let k = self.flag_bounded;
+        let rconfig = self.rconfig();
+        let mut rdr = rconfig.reader()?;
+        let headers = rdr.byte_headers()?.clone();
+        let sel = rconfig.selection(&headers)?;
+        let sel_len = sel.len();
+
+        let mut counters: Vec<HashMap<Vec<u8>, u64>> =
+            (0..sel_len).map(|_| HashMap::with_capacity(k)).collect();
+        let mut other_counts: Vec<u64> = vec![0; sel_len];
+        let mut other_uniques: Vec<u64> = vec![0; sel_len];
+
+        let flag_no_nulls = self.flag_no_nulls;
+        let flag_ignore_case = self.flag_ignore_case;
+        let flag_no_trim = self.flag_no_trim;
+
+        let mut row = csv::ByteRecord::new();
+        let mut string_buf = String::with_capacity(512);
+        while rdr.read_byte_record(&mut row)? {
+            for (i, field) in sel.select(&row).enumerate() {
+                if field.is_empty() && flag_no_nulls {
+                    continue;
+                }
+                let key = if field.is_empty() {
+                    EMPTY_BYTE_VEC
+                } else if flag_ignore_case {
+                    let field = if flag_no_trim {
+                        field
+                    } else {
+                        trim_bs_whitespace(field)
+                    };
+                    if let Ok(s) = simdutf8::basic::from_utf8(field) {
+                        util::to_lowercase_into(s, &mut string_buf);
+                        string_buf.as_bytes().to_vec()
+                    } else {
+                        field.to_vec()
+                    }
+                } else if flag_no_trim {
+                    field.to_vec()
+                } else {
+                    trim_bs_whitespace(field).to_vec()
+                };
+
+                let counter = &mut counters[i];
+                if let Some(count) = counter.get_mut(&key) {
+                    *count += 1;
+                } else if counter.len() < k {
+                    counter.insert(key, 1);
+                } else {
+                    // the counter is full - evict the least frequent value,
+                    // folding its count into the "Other" aggregate. K is small,
+                    // so a linear scan for the minimum is cheaper than
+                    // maintaining a heap alongside the hashmap
+                    // safety: the counter is non-empty as k > 0
+                    let (min_key, min_count) = counter
+                        .iter()
+                        .min_by_key(|(_, count)| **count)
+                        .map(|(key, count)| (key.clone(), *count))
+                        .unwrap();
+                    counter.remove(&min_key);
+                    other_counts[i] += min_count;
+                    other_uniques[i] += 1;
+                    counter.insert(key, 1);
+                }
+            }
+        }
+
+        let abs_dec_places = self.flag_pct_dec_places.unsigned_abs() as u32;
+        let abs_limit = self.flag_limit.unsigned_abs();
+        let mut itoa_buffer = itoa::Buffer::new();
+
+        let mut wtr = Config::new(self.flag_output.as_ref())
+            .quote_style(csv::QuoteStyle::Necessary)
+            .bom(self.flag_bom)
+            .writer()?;
+        wtr.write_record(vec!["field", "value", "count", "percentage"])?;
+
+        for (i, counter) in counters.into_iter().enumerate() {
+            let header_vec = if self.flag_no_headers {
+                (i + 1).to_string().into_bytes()
+            } else {
+                sel.select(&headers).nth(i).unwrap().to_vec()
+            };
+
+            let mut counts: Vec<(Vec<u8>, u64)> = counter.into_iter().collect();
+            if self.flag_asc {
+                counts.sort_unstable_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+            } else {
+                counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            }
+
+            let mut other_count = other_counts[i];
+            let mut other_unique = other_uniques[i];
+            if self.flag_limit > 0 && counts.len() > abs_limit {
+                for (_, count) in counts.drain(abs_limit..) {
+                    other_count += count;
+                    other_unique += 1;
+                }
+            } else if self.flag_limit < 0 {
+                let count_limit = abs_limit as u64;
+                counts.retain(|(_, count)| {
+                    if *count >= count_limit {
+                        true
+                    } else {
+                        other_count += *count;
+                        other_unique += 1;
+                        false
+                    }
+                });
+            }
+
+            let total_count = counts.iter().map(|(_, count)| *count).sum::<u64>() + other_count;
+            let pct_factor = if total_count > 0 {
+                100.0_f64 / total_count.to_f64().unwrap_or(1.0_f64)
+            } else {
+                0.0_f64
+            };
+
+            let mut pct_sum = 0.0_f64;
+            #[allow(clippy::cast_precision_loss)]
+            for (value, count) in counts {
+                let pct = count as f64 * pct_factor;
+                pct_sum += pct;
+                wtr.write_record(vec![
+                    &*header_vec,
+                    if value.is_empty() {
+                        NULL_VAL
+                    } else {
+                        value.as_slice()
+                    },
+                    itoa_buffer.format(count).as_bytes(),
+                    self.format_percentage(pct, abs_dec_places).as_bytes(),
+                ])?;
+            }
+
+            if other_count > 0 && self.flag_other_text != "<NONE>" {
+                wtr.write_record(vec![
+                    &*header_vec,
+                    format!("{} ({})", self.flag_other_text, HumanCount(other_unique)).as_bytes(),
+                    itoa_buffer.format(other_count).as_bytes(),
+                    self.format_percentage(100.0_f64 - pct_sum, abs_dec_places)
+                        .as_bytes(),
+                ])?;
+            }
+        }
+        Ok(wtr.flush()?)
+    }
+
+    /// --two-pass: stream the input once, recording one 64-bit hash per
+    /// distinct raw value per selected column, and store the resulting exact
+    /// cardinalities in COL_CARDINALITY_VEC so `ftables` sizes its frequency
+    /// hashmaps exactly on the second pass. Raw values are counted (no
+    /// trimming or case folding), so the cardinality is an upper bound on the
+    /// processed one - i.e. always a sufficient capacity
+    fn compute_cardinalities(&self) -> CliResult<()> {
+        let rconfig = self.rconfig();
+        let mut rdr = rconfig.reader()?;
+        let headers = rdr.byte_headers()?.clone();
+        let sel = rconfig.selection(&headers)?;
+        let nsel = sel.normal();
+        let nsel_len = nsel.len();
+
+        let hasher = foldhash::fast::FixedState::default();
+        let mut distinct_hashes: Vec<HashSet<u64>> =
+            (0..nsel_len).map(|_| HashSet::new()).collect();
+
+        let mut row = csv::ByteRecord::new();
+        while rdr.read_byte_record(&mut row)? {
+            for (i, field) in nsel.select(row.iter()).enumerate() {
+                // safety: distinct_hashes has exactly nsel_len elements and
+                // i comes from enumerate() over the selected columns
+                unsafe {
+                    distinct_hashes
+                        .get_unchecked_mut(i)
+                        .insert(hasher.hash_one(field));
+                }
+            }
+        }
+
+        // the cardinalities are indexed in normalized selection order,
+        // matching how `ftables` looks them up
+        let mut sel_indices: Vec<usize> = sel.to_vec();
+        sel_indices.sort_unstable();
+        sel_indices.dedup();
+        let col_cardinality_vec: Vec<(String, u64)> = sel_indices
+            .iter()
+            .zip(distinct_hashes)
+            .map(|(&col, hashes)| {
+                (
+                    String::from_utf8_lossy(&headers[col]).to_string(),
+                    hashes.len() as u64,
+                )
+            })
+            .collect();
+        COL_CARDINALITY_VEC.get_or_init(|| col_cardinality_vec);
+
+        Ok(())
+    }
+
     pub fn sequential_ftables(&self) -> CliResult<(Headers, FTables)> {
         let mut rdr = self.rconfig().reader()?;
         let (headers, sel) = self.sel_headers(&mut rdr)?;
+        if self.flag_sample > 0 {
+            let sample = self.reservoir_sample(&mut rdr)?;
+            return Ok((headers, self.ftables(&sel, sample.into_iter().map(Ok), 1)));
+        }
         Ok((headers, self.ftables(&sel, rdr.byte_records(), 1)))
     }
 
+    /// --sample: draw an Algorithm R reservoir sample of up to `--sample` rows
+    /// in one streaming pass, recording the scale factor (rows read / rows
+    /// sampled) used to estimate full-population counts from the sample
+    fn reservoir_sample<R: io::Read>(
+        &self,
+        rdr: &mut csv::Reader<R>,
+    ) -> CliResult<Vec<csv::ByteRecord>> {
+        let sample_size = self.flag_sample;
+        let mut reservoir: Vec<csv::ByteRecord> = Vec::with_capacity(sample_size);
+        let mut rng: StdRng = match self.flag_seed {
+            Some(seed) => StdRng::seed_from_u64(seed), // DevSkim: ignore DS148264
+            None => StdRng::from_os_rng(),
+        };
+
+        let mut rows_read = 0_u64;
+        let mut record = csv::ByteRecord::new();
+        while rdr.read_byte_record(&mut record)? {
+            rows_read += 1;
+            if reservoir.len() < sample_size {
+                reservoir.push(record.clone());
+            } else {
+                // replace a random reservoir slot with probability n/rows_read,
+                // so every row read so far is equally likely to be in the sample
+                let slot = rng.random_range(0..rows_read) as usize;
+                if slot < sample_size {
+                    reservoir[slot] = record.clone();
+                }
+            }
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        SAMPLE_SCALE
+            .set(if reservoir.is_empty() {
+                1.0
+            } else {
+                rows_read as f64 / reservoir.len() as f64
+            })
+            .unwrap();
+        eprintln!(
+            "estimated: frequencies compiled from a reservoir sample of {} of {} rows",
+            HumanCount(reservoir.len() as u64),
+            HumanCount(rows_read)
+        );
+
+        Ok(reservoir)
+    }
+
     pub fn parallel_ftables(
         &self,
         idx: &Indexed<fs::File, fs::File>,
@@ -561,6 +1264,8 @@ impl Args {
         let flag_no_nulls = self.flag_no_nulls;
         let flag_ignore_case = self.flag_ignore_case;
         let flag_no_trim = self.flag_no_trim;
+        let flag_collapse_whitespace = self.flag_collapse_whitespace;
+        let flag_strip_bom_values = self.flag_strip_bom_values;
 
         // compile a vector of bool flags for all_unique_headers
         // so we can skip the contains check in the hot loop below
@@ -628,9 +1333,87 @@ impl Args {
             |field: &[u8], _buf: &mut String| trim_bs_whitespace(field).to_vec()
         };
 
+        // --first-seen-order: record the rank at which each distinct value
+        // first appears, per column, while accumulating frequencies
+        let mut first_seen_vec: Option<Vec<HashMap<Vec<u8>, usize>>> = if self.flag_first_seen_order
+        {
+            Some((0..nsel_len).map(|_| HashMap::new()).collect())
+        } else {
+            None
+        };
+
+        let weight_col_idx = *WEIGHT_COL_IDX.get().unwrap_or(&None);
+
+        // with --weight, each frequency is increased by the row's weight
+        // instead of 1. None flags a bad weight value, to be skipped
+        let row_weight = |record: &csv::ByteRecord| -> Option<u64> {
+            if let Some(weight_idx) = weight_col_idx {
+                match atoi_simd::parse::<u64>(trim_bs_whitespace(&record[weight_idx])) {
+                    Ok(weight) => Some(weight),
+                    Err(_) => {
+                        let _ = WEIGHT_ERROR.set(format!(
+                            "--weight column value \"{}\" is not a non-negative integer",
+                            String::from_utf8_lossy(&record[weight_idx])
+                        ));
+                        None
+                    },
+                }
+            } else {
+                Some(1)
+            }
+        };
+
+        if self.flag_combine && nsel_len > 1 {
+            // --combine: count the tab-joined combination of the selected
+            // columns' values in a single joint frequency table
+            let mut freq_table: FTable = Frequencies::with_capacity(1000);
+            let mut combined: Vec<u8> = Vec::with_capacity(1024);
+            for row in it {
+                // safety: we know the row is valid
+                row_buffer.clone_from(&unsafe { row.unwrap_unchecked() });
+                let Some(weight) = row_weight(&row_buffer) else {
+                    continue;
+                };
+
+                combined.clear();
+                let mut all_empty = true;
+                for (i, field) in nsel.select(row_buffer.into_iter()).enumerate() {
+                    if i > 0 {
+                        combined.push(b'\t');
+                    }
+                    let field = if flag_strip_bom_values {
+                        strip_bom(field)
+                    } else {
+                        field
+                    };
+                    if !field.is_empty() {
+                        all_empty = false;
+                        let mut processed = process_field(field, &mut string_buf);
+                        if flag_collapse_whitespace {
+                            collapse_whitespace(&mut processed);
+                        }
+                        combined.extend_from_slice(&processed);
+                    }
+                }
+                // an all-empty combination is the NULL entry
+                if all_empty {
+                    if flag_no_nulls {
+                        continue;
+                    }
+                    combined.clear();
+                }
+                freq_table.increment_by(combined.clone(), weight);
+            }
+            return vec![freq_table];
+        }
+
         for row in it {
             // safety: we know the row is valid
             row_buffer.clone_from(&unsafe { row.unwrap_unchecked() });
+            let Some(weight) = row_weight(&row_buffer) else {
+                continue;
+            };
+
             for (i, field) in nsel.select(row_buffer.into_iter()).enumerate() {
                 // safety: all_unique_flag_vec is pre-computed to have exactly nsel_len elements,
                 // which matches the number of selected columns that we iterate over.
@@ -639,18 +1422,43 @@ impl Args {
                     continue;
                 }
 
+                let field = if flag_strip_bom_values {
+                    strip_bom(field)
+                } else {
+                    field
+                };
+
                 // safety: freq_tables is pre-allocated with nsel_len elements.
                 // i will always be < nsel_len as it comes from enumerate() over the selected cols
                 if !field.is_empty() {
                     // Reuse buffers instead of creating new ones
                     field_buffer = process_field(field, &mut string_buf);
+                    if flag_collapse_whitespace {
+                        collapse_whitespace(&mut field_buffer);
+                    }
+                    if let Some(ref mut first_seen) = first_seen_vec {
+                        let col_first_seen = &mut first_seen[i];
+                        let next_rank = col_first_seen.len();
+                        col_first_seen
+                            .entry(field_buffer.clone())
+                            .or_insert(next_rank);
+                    }
                     unsafe {
-                        freq_tables.get_unchecked_mut(i).add(field_buffer);
+                        freq_tables
+                            .get_unchecked_mut(i)
+                            .increment_by(field_buffer, weight);
                     }
                 } else if !flag_no_nulls {
+                    if let Some(ref mut first_seen) = first_seen_vec {
+                        let col_first_seen = &mut first_seen[i];
+                        let next_rank = col_first_seen.len();
+                        col_first_seen.entry(EMPTY_BYTE_VEC).or_insert(next_rank);
+                    }
                     // set to null (EMPTY_BYTES) as flag_no_nulls is false
                     unsafe {
-                        freq_tables.get_unchecked_mut(i).add(EMPTY_BYTE_VEC);
+                        freq_tables
+                            .get_unchecked_mut(i)
+                            .increment_by(EMPTY_BYTE_VEC, weight);
                     }
                 }
             }
@@ -663,6 +1471,9 @@ impl Args {
         if nchunks > 1 {
             freq_tables.shrink_to_fit();
         }
+        if let Some(first_seen) = first_seen_vec {
+            let _ = FIRST_SEEN_VEC.set(first_seen);
+        }
         freq_tables
     }
 
@@ -689,7 +1500,7 @@ impl Args {
             flag_memcheck:        false,
         };
         // initialize the stats records hashmap
-        let mut stats_records_hashmap = if self.flag_json {
+        let mut stats_records_hashmap = if self.flag_json || self.flag_jsonl {
             HashMap::with_capacity(headers.len())
         } else {
             HashMap::new()
@@ -716,7 +1527,7 @@ impl Args {
                 let col_name_str = simdutf8::basic::from_utf8(col_name)
                     .unwrap_or(NON_UTF8_ERR)
                     .to_string();
-                if self.flag_json {
+                if self.flag_json || self.flag_jsonl {
                     // Store the stats record in the hashmap for later use
                     // when we're producing JSON output
                     stats_records_hashmap.insert(col_name_str.clone(), stats_record.clone());
@@ -725,6 +1536,29 @@ impl Args {
             })
             .collect();
 
+        // when binning, record each column's numeric range from the stats cache
+        // so numeric columns can be bucketed into equal-width bins
+        let col_numeric_range_vec: Vec<Option<(f64, f64)>> = if self.flag_bins > 0 {
+            csv_stats
+                .iter()
+                .map(|stats_record| {
+                    if stats_record.r#type == "Integer" || stats_record.r#type == "Float" {
+                        match (
+                            stats_record.min.as_deref().and_then(|v| v.parse::<f64>().ok()),
+                            stats_record.max.as_deref().and_then(|v| v.parse::<f64>().ok()),
+                        ) {
+                            (Some(min), Some(max)) if min <= max => Some((min, max)),
+                            _ => None,
+                        }
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         // now, get the unique headers, where cardinality == rowcount
         let row_count = dataset_stats
             .get("qsv__rowcount")
@@ -740,14 +1574,22 @@ impl Args {
             // as it was constructed from csv_fields which has the same length as headers
             let cardinality = unsafe { col_cardinality_vec.get_unchecked(i).1 };
 
-            if cardinality == row_count {
+            // binned numeric columns are never short-circuited, even when all
+            // unique, as binning needs their compiled frequencies. Neither are
+            // any columns with --weight, as row count no longer equals
+            // weighted count
+            if cardinality == row_count
+                && self.flag_weight.is_none()
+                && !matches!(col_numeric_range_vec.get(i), Some(Some(_)))
+            {
                 all_unique_headers_vec.push(i);
             }
         }
 
         COL_CARDINALITY_VEC.get_or_init(|| col_cardinality_vec);
+        COL_NUMERIC_RANGE_VEC.get_or_init(|| col_numeric_range_vec);
 
-        if self.flag_json {
+        if self.flag_json || self.flag_jsonl {
             // Store the stats records hashmap for later use
             // when we're producing JSON output
             STATS_RECORDS.set(stats_records_hashmap).unwrap();
@@ -756,6 +1598,257 @@ impl Args {
         Ok(all_unique_headers_vec)
     }
 
+    fn output_markdown(&self, headers: &Headers, tables: FTables, rconfig: &Config) -> CliResult<()> {
+        // init vars and amortize allocations
+        let head_ftables = headers.iter().zip(tables);
+        let row_count = *FREQ_ROW_COUNT.get().unwrap_or(&0);
+        let unique_headers_vec = UNIQUE_COLUMNS_VEC.get().unwrap();
+        let mut processed_frequencies = Vec::with_capacity(head_ftables.len());
+        let abs_dec_places = self.flag_pct_dec_places.unsigned_abs() as u32;
+        let multiple_fields = headers.len() > 1;
+        let mut md_output = String::with_capacity(1024);
+        let mut itoa_buffer = itoa::Buffer::new();
+
+        for (i, (header, ftab)) in head_ftables.enumerate() {
+            let field_name = if rconfig.no_headers {
+                (i + 1).to_string()
+            } else {
+                String::from_utf8_lossy(header).to_string()
+            };
+
+            let all_unique_header = unique_headers_vec.contains(&i);
+            let bin_range = COL_NUMERIC_RANGE_VEC
+                .get()
+                .and_then(|ranges| ranges.get(i).copied().flatten());
+            self.process_frequencies(
+                all_unique_header,
+                bin_range,
+                abs_dec_places,
+                row_count,
+                i,
+                &ftab,
+                &mut processed_frequencies,
+            );
+
+            if multiple_fields {
+                if i > 0 {
+                    md_output.push('\n');
+                }
+                md_output.push_str("### ");
+                md_output.push_str(&field_name);
+                md_output.push_str("\n\n");
+            }
+            md_output.push_str("| field | value | count | percentage |\n");
+            md_output.push_str("|---|---|---|---|\n");
+            for processed_freq in &processed_frequencies {
+                let value = if self.flag_vis_whitespace {
+                    util::visualize_whitespace(&String::from_utf8_lossy(&processed_freq.value))
+                } else {
+                    String::from_utf8_lossy(&processed_freq.value).to_string()
+                };
+                md_output.push_str("| ");
+                md_output.push_str(&field_name.replace('|', "\\|"));
+                md_output.push_str(" | ");
+                // escape pipes so values cannot break out of their table cell
+                md_output.push_str(&value.replace('|', "\\|"));
+                md_output.push_str(" | ");
+                md_output.push_str(itoa_buffer.format(processed_freq.count));
+                md_output.push_str(" | ");
+                md_output.push_str(&processed_freq.formatted_percentage);
+                md_output.push_str(" |\n");
+            }
+            processed_frequencies.clear();
+        }
+
+        if let Some(output_path) = &self.flag_output {
+            fs::write(output_path, md_output)?;
+        } else {
+            print!("{md_output}");
+        }
+        Ok(())
+    }
+
+    /// --outliers: report only values appearing `max_count` times or fewer,
+    /// per column, rarest first - the complement of the top-values table
+    fn output_outliers(
+        &self,
+        headers: &Headers,
+        tables: FTables,
+        rconfig: &Config,
+        max_count: u64,
+    ) -> CliResult<()> {
+        // safety: UNIQUE_COLUMNS_VEC is always set by sel_headers before we get here
+        let unique_headers_vec = UNIQUE_COLUMNS_VEC.get().unwrap();
+        let abs_dec_places = self.flag_pct_dec_places.unsigned_abs() as u32;
+
+        let mut wtr = Config::new(self.flag_output.as_ref())
+            .quote_style(csv::QuoteStyle::Necessary)
+            .bom(self.flag_bom)
+            .writer()?;
+        wtr.write_record(vec!["field", "value", "count", "percentage"])?;
+
+        // amortize allocations
+        let mut itoa_buffer = itoa::Buffer::new();
+        #[allow(unused_assignments)]
+        let mut value_str = String::with_capacity(100);
+        let mut header_vec: Vec<u8>;
+        let mut row: Vec<&[u8]>;
+
+        for (i, (header, ftab)) in headers.iter().zip(tables).enumerate() {
+            if unique_headers_vec.contains(&i) {
+                // all-unique ID columns are short-circuited - every value
+                // is a singleton, so reporting them all is just noise
+                continue;
+            }
+            header_vec = if rconfig.no_headers {
+                (i + 1).to_string().into_bytes()
+            } else {
+                header.to_vec()
+            };
+
+            // least frequent values first, so we can stop at the threshold
+            let (counts, total_count) = ftab.par_frequent(true);
+            let pct_factor = if total_count > 0 {
+                100.0_f64 / total_count.to_f64().unwrap_or(1.0_f64)
+            } else {
+                0.0_f64
+            };
+            for (value, count) in counts {
+                if count > max_count {
+                    break;
+                }
+                #[allow(clippy::cast_precision_loss)]
+                let formatted_pct = self.format_percentage(count as f64 * pct_factor, abs_dec_places);
+                row = vec![
+                    &*header_vec,
+                    if value.is_empty() {
+                        NULL_VAL
+                    } else if self.flag_vis_whitespace {
+                        value_str = util::visualize_whitespace(&String::from_utf8_lossy(value));
+                        value_str.as_bytes()
+                    } else {
+                        value
+                    },
+                    itoa_buffer.format(count).as_bytes(),
+                    formatted_pct.as_bytes(),
+                ];
+                wtr.write_record(row)?;
+            }
+        }
+        Ok(wtr.flush()?)
+    }
+
+    /// --split-output CSV mode: one "<field>.csv" per selected field,
+    /// with the same columns as the combined output minus the field column
+    fn output_split_csv(
+        &self,
+        headers: &Headers,
+        tables: FTables,
+        rconfig: &Config,
+        split_dir: &str,
+    ) -> CliResult<()> {
+        fs::create_dir_all(split_dir)?;
+
+        // init vars and amortize allocations
+        let head_ftables = headers.iter().zip(tables);
+        let row_count = *FREQ_ROW_COUNT.get().unwrap_or(&0);
+        let unique_headers_vec = UNIQUE_COLUMNS_VEC.get().unwrap();
+        let mut processed_frequencies: Vec<ProcessedFrequency> =
+            Vec::with_capacity(head_ftables.len());
+        let abs_dec_places = self.flag_pct_dec_places.unsigned_abs() as u32;
+        let mut used_file_stems = HashSet::new();
+        let mut itoa_buffer = itoa::Buffer::new();
+        let mut row: Vec<&[u8]>;
+        #[allow(unused_assignments)]
+        let mut value_str = String::with_capacity(100);
+        #[allow(unused_assignments)]
+        let mut cumulative_pct_str = String::with_capacity(20);
+        #[allow(unused_assignments)]
+        let mut normalized_str = String::with_capacity(20);
+
+        let mut header_row = vec!["value", "count", "percentage"];
+        if self.flag_cumulative {
+            header_row.push("cumulative_percentage");
+        }
+        if self.flag_normalize_to_max {
+            header_row.push("normalized");
+        }
+
+        for (i, (header, ftab)) in head_ftables.enumerate() {
+            let field_name = if rconfig.no_headers {
+                (i + 1).to_string()
+            } else {
+                String::from_utf8_lossy(header).to_string()
+            };
+
+            let all_unique_header = unique_headers_vec.contains(&i);
+            let bin_range = COL_NUMERIC_RANGE_VEC
+                .get()
+                .and_then(|ranges| ranges.get(i).copied().flatten());
+            self.process_frequencies(
+                all_unique_header,
+                bin_range,
+                abs_dec_places,
+                row_count,
+                i,
+                &ftab,
+                &mut processed_frequencies,
+            );
+
+            let file_stem = unique_file_stem(&field_name, &mut used_file_stems);
+            let field_path = std::path::Path::new(split_dir)
+                .join(format!("{file_stem}.csv"))
+                .to_string_lossy()
+                .to_string();
+            let mut wtr = Config::new(Some(&field_path))
+                .quote_style(csv::QuoteStyle::Necessary)
+                .bom(self.flag_bom)
+                .writer()?;
+            wtr.write_record(&header_row)?;
+
+            let mut cumulative_pct = 0.0_f64;
+            let max_count = processed_frequencies
+                .iter()
+                .map(|pf| pf.count)
+                .max()
+                .unwrap_or(0);
+            for processed_freq in &processed_frequencies {
+                row = vec![
+                    if self.flag_vis_whitespace {
+                        value_str = util::visualize_whitespace(&String::from_utf8_lossy(
+                            &processed_freq.value,
+                        ));
+                        value_str.as_bytes()
+                    } else {
+                        &processed_freq.value
+                    },
+                    itoa_buffer.format(processed_freq.count).as_bytes(),
+                    processed_freq.formatted_percentage.as_bytes(),
+                ];
+                if self.flag_cumulative {
+                    cumulative_pct += processed_freq.percentage;
+                    cumulative_pct_str =
+                        self.format_percentage(cumulative_pct.min(100.0), abs_dec_places);
+                    row.push(cumulative_pct_str.as_bytes());
+                }
+                if self.flag_normalize_to_max {
+                    #[allow(clippy::cast_precision_loss)]
+                    let normalized = if max_count > 0 {
+                        processed_freq.count as f64 * 100.0 / max_count as f64
+                    } else {
+                        0.0
+                    };
+                    normalized_str = self.format_percentage(normalized, abs_dec_places);
+                    row.push(normalized_str.as_bytes());
+                }
+                wtr.write_record(row)?;
+            }
+            wtr.flush()?;
+            processed_frequencies.clear();
+        }
+        Ok(())
+    }
+
     fn output_json(
         &self,
         headers: &Headers,
@@ -784,16 +1877,21 @@ impl Args {
             };
 
             let all_unique_header = unique_headers_vec.contains(&i);
+            let bin_range = COL_NUMERIC_RANGE_VEC
+                .get()
+                .and_then(|ranges| ranges.get(i).copied().flatten());
             self.process_frequencies(
                 all_unique_header,
+                bin_range,
                 abs_dec_places,
                 rowcount,
+                i,
                 &ftab,
                 &mut processed_frequencies,
             );
 
             // Sort frequencies by count if flag_other_sorted
-            if self.flag_other_sorted {
+            if self.flag_other_sorted && bin_range.is_none() {
                 if self.flag_asc {
                     // ascending order
                     processed_frequencies.sort_by(|a, b| a.count.cmp(&b.count));
@@ -828,6 +1926,19 @@ impl Args {
                 .parse::<f64>()
                 .unwrap_or(0.0);
 
+            // Shannon entropy over the full, untruncated frequency table,
+            // so --limit doesn't distort the measure. Null for all-unique
+            // columns as their frequencies are never compiled
+            let (entropy, normalized_entropy) = if all_unique_header {
+                (None, None)
+            } else {
+                let (field_ent, normalized_ent) = field_entropy(&ftab);
+                (
+                    util::round_num(field_ent, 4).parse::<f64>().ok(),
+                    util::round_num(normalized_ent, 4).parse::<f64>().ok(),
+                )
+            };
+
             // Build stats vector from stats record if type is not empty and not NULL or Boolean
             if !self.flag_no_stats
                 && !dtype.is_empty()
@@ -859,29 +1970,54 @@ impl Args {
                 add_stat(&mut field_stats, "cv", sr.cv);
             }
 
-            fields.push(FrequencyField {
-                field: field_name,
-                r#type: dtype,
-                cardinality,
-                nullcount,
-                sparsity,
-                uniqueness_ratio,
-                stats: field_stats.clone(),
-                frequencies: processed_frequencies
+            let entries: Vec<FrequencyEntry> = {
+                // the running total resets per field
+                let mut cumulative_pct = 0.0_f64;
+                processed_frequencies
                     .iter()
                     .map(|pf| FrequencyEntry {
-                        value:      if self.flag_vis_whitespace {
+                        value: if self.flag_vis_whitespace {
                             util::visualize_whitespace(&String::from_utf8_lossy(&pf.value))
                         } else {
                             String::from_utf8_lossy(&pf.value).to_string()
                         },
-                        count:      pf.count,
+                        count: pf.count,
                         percentage: pf
                             .formatted_percentage
                             .parse::<f64>()
                             .unwrap_or(pf.percentage),
+                        cumulative_percentage: if self.flag_cumulative {
+                            cumulative_pct += pf.percentage;
+                            // guard against f64 accumulation drift past 100%
+                            self.format_percentage(cumulative_pct.min(100.0), abs_dec_places)
+                                .parse::<f64>()
+                                .ok()
+                        } else {
+                            None
+                        },
                     })
-                    .collect(),
+                    .collect()
+            };
+            // binned numeric fields report their entries as "bins"
+            // instead of "frequencies"
+            let (frequencies, bins) = if bin_range.is_some() {
+                (Vec::new(), entries)
+            } else {
+                (entries, Vec::new())
+            };
+
+            fields.push(FrequencyField {
+                field: field_name,
+                r#type: dtype,
+                cardinality,
+                nullcount,
+                sparsity,
+                uniqueness_ratio,
+                entropy,
+                normalized_entropy,
+                stats: field_stats.clone(),
+                frequencies,
+                bins,
             });
 
             // Clear the vectors for the next iteration
@@ -889,6 +2025,42 @@ impl Args {
             processed_frequencies.clear();
         } // end for loop
 
+        if let Some(split_dir) = &self.flag_split_output {
+            // --split-output: one pretty-printed FrequencyField file per field
+            fs::create_dir_all(split_dir)?;
+            let re = regex::Regex::new(r#""stats": \[\],\n\s*"#).unwrap();
+            let mut used_file_stems = HashSet::new();
+            for field in &fields {
+                let file_stem = unique_file_stem(&field.field, &mut used_file_stems);
+                let mut field_json = serde_json::to_string_pretty(field)?;
+                // remove empty stats properties, as the combined output does
+                field_json = re.replace_all(&field_json, "").to_string();
+                fs::write(
+                    std::path::Path::new(split_dir).join(format!("{file_stem}.json")),
+                    field_json,
+                )?;
+            }
+            return Ok(());
+        }
+
+        if self.flag_jsonl {
+            // --jsonl: one compact FrequencyField object per line, in field order
+            let mut jsonl_output = String::with_capacity(1024);
+            for field in &fields {
+                let mut line = serde_json::to_string(field)?;
+                // remove empty stats properties, as the --json output does
+                line = line.replace("\"stats\":[],", "");
+                jsonl_output.push_str(&line);
+                jsonl_output.push('\n');
+            }
+            if let Some(output_path) = &self.flag_output {
+                std::fs::write(output_path, jsonl_output)?;
+            } else {
+                print!("{jsonl_output}");
+            }
+            return Ok(());
+        }
+
         let output = FrequencyOutput {
             input: if is_stdin {
                 "stdin".to_string()
@@ -925,22 +2097,336 @@ impl Args {
         Ok(())
     }
 
+    /// Report to stderr, per column, how many of the top values are needed to
+    /// cover 50%, 80% and 95% of rows - a one-line concentration summary that
+    /// doesn't require dumping the full frequency table.
+    fn coverage_report(&self, headers: &Headers, tables: &FTables) {
+        // safety: UNIQUE_COLUMNS_VEC is always set by sel_headers before we get here
+        let unique_headers_vec = UNIQUE_COLUMNS_VEC.get().unwrap();
+
+        for (i, (header, ftab)) in headers.iter().zip(tables).enumerate() {
+            let col_name = if self.flag_no_headers {
+                (i + 1).to_string()
+            } else {
+                String::from_utf8_lossy(header).to_string()
+            };
+
+            if unique_headers_vec.contains(&i) {
+                eprintln!("coverage: column \"{col_name}\": all unique");
+                continue;
+            }
+
+            // walk the values from most to least frequent, recording how many
+            // distinct values it takes to reach each cumulative threshold
+            let (counts, total_count) = ftab.par_frequent(false);
+            let mut thresholds = [(50_u64, 0_usize), (80, 0), (95, 0)];
+            let mut cum_count = 0_u64;
+            for (n, (_, count)) in counts.iter().enumerate() {
+                cum_count += count;
+                for (pct, values_needed) in &mut thresholds {
+                    if *values_needed == 0 && cum_count * 100 >= total_count * *pct {
+                        *values_needed = n + 1;
+                    }
+                }
+            }
+            eprintln!(
+                "coverage: column \"{col_name}\": 50% -> {}, 80% -> {}, 95% -> {} of {} distinct \
+                 values",
+                thresholds[0].1,
+                thresholds[1].1,
+                thresholds[2].1,
+                counts.len()
+            );
+        }
+    }
+
+    /// write a per-column null report CSV of field,nullcount,nullpercentage,
+    /// using the stats cache nullcount when available, otherwise counting
+    /// empty fields with an additional streaming pass over the input
+    fn null_report(&self, headers: &Headers, report_path: &str) -> CliResult<()> {
+        let abs_dec_places = self.flag_pct_dec_places.unsigned_abs() as u32;
+
+        // get the stats records for the entire CSV, so we can use the
+        // stats cache nullcounts if they're fresh
+        let schema_args = util::SchemaArgs {
+            flag_enum_threshold:  0,
+            flag_ignore_case:     self.flag_ignore_case,
+            flag_strict_dates:    false,
+            flag_pattern_columns: crate::select::SelectColumns::parse("").unwrap(),
+            flag_dates_whitelist: String::new(),
+            flag_prefer_dmy:      false,
+            flag_force:           false,
+            flag_stdout:          false,
+            flag_jobs:            Some(util::njobs(self.flag_jobs)),
+            flag_polars:          false,
+            flag_no_headers:      self.flag_no_headers,
+            flag_delimiter:       self.flag_delimiter,
+            arg_input:            self.arg_input.clone(),
+            flag_memcheck:        false,
+        };
+        let (csv_fields, csv_stats, dataset_stats) =
+            get_stats_records(&schema_args, StatsMode::Frequency)?;
+
+        let mut row_count = *FREQ_ROW_COUNT.get().unwrap_or(&0);
+
+        // nullcounts per selected column, in selection order
+        let mut nullcounts: Vec<u64> = Vec::with_capacity(headers.len());
+        if !csv_fields.is_empty() && csv_stats.len() == csv_fields.len() {
+            // map each selected header to its stats record by column name
+            for header in headers {
+                let nullcount = csv_fields
+                    .iter()
+                    .position(|field| field == header)
+                    .map_or(0, |i| csv_stats[i].nullcount);
+                nullcounts.push(nullcount);
+            }
+            if row_count == 0 {
+                row_count = dataset_stats
+                    .get("qsv__rowcount")
+                    .and_then(|count| count.parse::<u64>().ok())
+                    .unwrap_or_else(|| util::count_rows(&self.rconfig()).unwrap_or_default());
+            }
+        } else {
+            // the stats cache is not available - count empty fields
+            // with a streaming pass over the selected columns
+            let rconfig = self.rconfig();
+            let mut rdr = rconfig.reader()?;
+            let sel = rconfig.selection(rdr.byte_headers()?)?;
+            nullcounts.resize(headers.len(), 0);
+            let mut record = csv::ByteRecord::new();
+            let mut scanned_rows = 0_u64;
+            while rdr.read_byte_record(&mut record)? {
+                scanned_rows += 1;
+                for (i, field) in sel.select(&record).enumerate() {
+                    let field = if self.flag_no_trim {
+                        field
+                    } else {
+                        trim_bs_whitespace(field)
+                    };
+                    if field.is_empty() {
+                        nullcounts[i] += 1;
+                    }
+                }
+            }
+            if row_count == 0 {
+                row_count = scanned_rows;
+            }
+        }
+
+        let mut wtr = Config::new(Some(report_path.to_string()).as_ref()).writer()?;
+        wtr.write_record(vec!["field", "nullcount", "nullpercentage"])?;
+        let mut itoa_buffer = itoa::Buffer::new();
+        for (i, (header, nullcount)) in headers.iter().zip(nullcounts).enumerate() {
+            let field_name = if self.flag_no_headers {
+                (i + 1).to_string()
+            } else {
+                String::from_utf8_lossy(header).to_string()
+            };
+            let percentage = if row_count > 0 {
+                (nullcount as f64 / row_count as f64) * 100.0
+            } else {
+                0.0
+            };
+            wtr.write_record(vec![
+                field_name.as_str(),
+                itoa_buffer.format(nullcount),
+                &self.format_percentage(percentage, abs_dec_places),
+            ])?;
+        }
+        Ok(wtr.flush()?)
+    }
+
+    /// Compare the value distributions of the selected columns and report pairs
+    /// of columns with identical distributions (candidate duplicate/derived columns).
+    /// Two columns are distribution-identical if they hold the same multiset of
+    /// values, regardless of row order.
+    fn find_duplicate_columns(&self, headers: &Headers, tables: &FTables) -> CliResult<()> {
+        // safety: UNIQUE_COLUMNS_VEC is always set by sel_headers before we get here
+        let unique_headers_vec = UNIQUE_COLUMNS_VEC.get().unwrap();
+
+        // compute each column's frequency signature - its (value, count) pairs
+        // sorted by value so signatures can be compared for equality.
+        // all-unique short-circuited columns have no compiled frequencies,
+        // so we skip them by giving them an empty signature
+        let signatures: Vec<Vec<(Vec<u8>, u64)>> = tables
+            .iter()
+            .enumerate()
+            .map(|(i, ftab)| {
+                if unique_headers_vec.contains(&i) {
+                    Vec::new()
+                } else {
+                    let (counts, _) = ftab.par_frequent(false);
+                    let mut signature: Vec<(Vec<u8>, u64)> = counts
+                        .into_iter()
+                        .map(|(value, count)| (value.clone(), count))
+                        .collect();
+                    signature.sort_unstable();
+                    signature
+                }
+            })
+            .collect();
+
+        let col_name = |i: usize| {
+            if self.flag_no_headers {
+                (i + 1).to_string()
+            } else {
+                String::from_utf8_lossy(&headers[i]).to_string()
+            }
+        };
+
+        let mut wtr = Config::new(self.flag_output.as_ref()).writer()?;
+        wtr.write_record(vec!["column_1", "column_2"])?;
+        for i in 0..signatures.len() {
+            if signatures[i].is_empty() {
+                continue;
+            }
+            for j in i + 1..signatures.len() {
+                if signatures[i] == signatures[j] {
+                    wtr.write_record(vec![col_name(i), col_name(j)])?;
+                }
+            }
+        }
+        Ok(wtr.flush()?)
+    }
+
+    /// Print the optimization decisions made while compiling the frequency table
+    /// to stderr. This is a diagnostics aid surfacing the existing internal
+    /// decisions - it does not change how the frequency table is computed.
+    fn explain(&self, headers: &Headers, parallel: bool) {
+        // safety: UNIQUE_COLUMNS_VEC is always set by sel_headers before we get here
+        let unique_headers_vec = UNIQUE_COLUMNS_VEC.get().unwrap();
+        let empty_vec = Vec::new();
+        let col_cardinality_vec = COL_CARDINALITY_VEC.get().unwrap_or(&empty_vec);
+        let stats_cache_used = !col_cardinality_vec.is_empty();
+
+        eprintln!("explain: stats cache used: {stats_cache_used}");
+        eprintln!("explain: parallel: {parallel}");
+        for (i, header) in headers.iter().enumerate() {
+            let col_name = if self.flag_no_headers {
+                (i + 1).to_string()
+            } else {
+                String::from_utf8_lossy(header).to_string()
+            };
+            if unique_headers_vec.contains(&i) {
+                eprintln!(
+                    "explain: column \"{col_name}\": all-unique, short-circuited with capacity 1"
+                );
+            } else {
+                // mirror the capacity decision made in ftables
+                let capacity = col_cardinality_vec
+                    .get(i)
+                    .map_or(1000, |(_, cardinality)| *cardinality as usize);
+                eprintln!("explain: column \"{col_name}\": capacity {capacity}");
+            }
+        }
+    }
+
     fn sel_headers<R: io::Read>(
         &self,
         rdr: &mut csv::Reader<R>,
     ) -> CliResult<(csv::ByteRecord, Selection)> {
         let headers = rdr.byte_headers()?;
+        let sel = self.rconfig().selection(headers)?;
+        let combine_mode = self.flag_combine && sel.len() > 1;
         let all_unique_headers_vec = self.get_unique_headers(headers)?;
 
         UNIQUE_COLUMNS_VEC
-            .set(all_unique_headers_vec)
+            // with --combine there is a single synthetic field, so the
+            // per-column all-unique indices no longer apply
+            .set(if combine_mode {
+                Vec::new()
+            } else {
+                all_unique_headers_vec
+            })
             .map_err(|_| "Cannot set UNIQUE_COLUMNS")?;
 
-        let sel = self.rconfig().selection(headers)?;
-        Ok((sel.select(headers).map(<[u8]>::to_vec).collect(), sel))
+        // resolve the --weight column to its position in the full record,
+        // by name or - for --no-headers inputs - by 1-based column index
+        let weight_col_idx = if let Some(ref weight_col) = self.flag_weight {
+            let Some(weight_idx) = headers
+                .iter()
+                .position(|header| header == weight_col.as_bytes())
+                .or_else(|| {
+                    weight_col
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&i| i >= 1 && i <= headers.len())
+                        .map(|i| i - 1)
+                })
+            else {
+                return fail_incorrectusage_clierror!(
+                    "--weight column \"{weight_col}\" not found in the input."
+                );
+            };
+            Some(weight_idx)
+        } else {
+            None
+        };
+        WEIGHT_COL_IDX
+            .set(weight_col_idx)
+            .map_err(|_| "Cannot set WEIGHT_COL_IDX")?;
+
+        let sel_headers = if combine_mode {
+            // a single synthetic field named after the tab-joined headers
+            let combined_headers = sel.select(headers).collect::<Vec<_>>().join(&b"\t"[..]);
+            let mut headers_record = csv::ByteRecord::new();
+            headers_record.push_field(&combined_headers);
+            headers_record
+        } else {
+            sel.select(headers).map(<[u8]>::to_vec).collect()
+        };
+        Ok((sel_headers, sel))
+    }
+}
+
+/// Sanitize a field name into a unique, filesystem-safe file stem for
+/// --split-output, following the same scheme as the partition command -
+/// strip non-word characters and disambiguate collisions with a _N suffix
+fn unique_file_stem(field_name: &str, used: &mut HashSet<String>) -> String {
+    let safe: String = field_name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    let base = if safe.is_empty() {
+        "empty".to_owned()
+    } else {
+        safe
+    };
+    if !used.contains(&base) {
+        used.insert(base.clone());
+        return base;
+    }
+    let mut counter = 1_usize;
+    loop {
+        let candidate = format!("{base}_{counter}");
+        if !used.contains(&candidate) {
+            used.insert(candidate.clone());
+            return candidate;
+        }
+        counter += 1;
     }
 }
 
+/// Shannon entropy (in bits) and normalized entropy (0..1) of a column's
+/// full frequency table, computed before any --limit truncation
+#[allow(clippy::cast_precision_loss)]
+fn field_entropy(ftab: &FTable) -> (f64, f64) {
+    let (counts, total_count) = ftab.par_frequent(false);
+    if total_count == 0 || counts.len() <= 1 {
+        return (0.0, 0.0);
+    }
+    let total = total_count as f64;
+    let mut entropy = 0.0_f64;
+    for (_, count) in &counts {
+        if *count > 0 {
+            let p = *count as f64 / total;
+            entropy -= p * p.log2();
+        }
+    }
+    // maximum entropy is log2(cardinality) - a uniform distribution
+    (entropy, entropy / (counts.len() as f64).log2())
+}
+
 /// Helper function to add a field to field_stats if it exists
 /// Automatically converts any type to appropriate JSON value
 fn add_stat<T: ToString>(field_stats: &mut Vec<FieldStats>, name: &str, value: Option<T>) {
@@ -967,7 +2453,37 @@ fn add_stat<T: ToString>(field_stats: &mut Vec<FieldStats>, name: &str, value: O
     }
 }
 
+/// --strip-bom-values: strip a leading UTF-8 Byte Order Mark from a value,
+/// so BOM-contaminated values group with their clean counterparts
+#[inline]
+fn strip_bom(value: &[u8]) -> &[u8] {
+    value.strip_prefix(b"\xef\xbb\xbf").unwrap_or(value)
+}
+
 /// trim leading and trailing whitespace from a byte slice
+/// --collapse-whitespace: replace each run of ASCII whitespace with a single
+/// space, in place, so internal whitespace variants count as the same value
+#[inline]
+fn collapse_whitespace(value: &mut Vec<u8>) {
+    let mut write_idx = 0;
+    let mut prev_ws = false;
+    for read_idx in 0..value.len() {
+        let b = value[read_idx];
+        if b.is_ascii_whitespace() {
+            if !prev_ws {
+                value[write_idx] = b' ';
+                write_idx += 1;
+                prev_ws = true;
+            }
+        } else {
+            value[write_idx] = b;
+            write_idx += 1;
+            prev_ws = false;
+        }
+    }
+    value.truncate(write_idx);
+}
+
 #[allow(clippy::inline_always)]
 #[inline(always)]
 fn trim_bs_whitespace(bytes: &[u8]) -> &[u8] {
@@ -24,24 +24,49 @@ Usage:
 geoconvert REQUIRED arguments:
     <input>           The spatial file to convert. To use stdin instead, use a dash "-".
                       Note: SHP input must be a path to a .shp file and cannot use stdin.
-    <input-format>    Valid values are "geojson", "shp", and "csv"
+    <input-format>    Valid values are "geojson", "geojsonl", "shp", and "csv"
     <output-format>   Valid values are:
-                      - For GeoJSON input: "csv", "svg", and "geojsonl"
-                      - For SHP input: "csv", "geojson", and "geojsonl"
-                      - For CSV input: "geojson", "geojsonl", "csv", and "svg"
+                      - For GeoJSON input: "csv", "svg", "geojsonl", and "kml"
+                      - For GeoJSON Lines input: "csv", "svg", "geojson", and "kml"
+                      - For SHP input: "csv", "geojson", "geojsonl", and "kml"
+                      - For CSV input: "geojson", "geojsonl", "csv", "svg", and "kml"
+                      KML output writes one <Placemark> per feature, preserving
+                      feature properties as <ExtendedData>.
 
 geoconvert options:
                                  REQUIRED FOR CSV INPUT
     -g, --geometry <geometry>    The name of the column that has WKT geometry.
+                                 A bare integer is treated as a zero-based column
+                                 index resolved against the CSV headers instead.
                                  Alternative to --latitude and --longitude.
     -y, --latitude <col>         The name of the column with northing values.
     -x, --longitude <col>        The name of the column with easting values.
+    -z, --elevation <col>        The name of the column with elevation values.
+                                 When set, a third ordinate is appended to each
+                                 point, producing [lon, lat, z] coordinates.
+                                 Only valid with --latitude and --longitude.
 
     -l, --max-length <length>    The maximum column length when the output format is CSV.
                                  Oftentimes, the geometry column is too long to fit in a
                                  CSV file, causing other tools like Python & PostgreSQL to fail.
                                  If a column is too long, it will be truncated to the specified
                                  length and an ellipsis ("...") will be appended.
+    --flatten-properties         When converting GeoJSON to CSV, recursively flatten
+                                 nested property objects into dotted-key columns
+                                 (e.g. "address.city"), producing a stable wide schema
+                                 instead of dropping nested properties or flattening
+                                 them inconsistently.
+
+                                 REPROJECTION OPTIONS:
+    --from-crs <epsg>            The EPSG code of the input's coordinate reference
+                                 system, e.g. "EPSG:27700" or just "27700".
+                                 Requires --to-crs.
+    --to-crs <epsg>              The EPSG code to reproject geometries to during
+                                 conversion, e.g. "EPSG:4326". Reprojection applies
+                                 to all geometry-bearing conversions, whatever the
+                                 input and output formats. When --from-crs and
+                                 --to-crs are the same, reprojection is skipped.
+                                 Requires --from-crs.
 
 Common options:
     -h, --help                   Display this message
@@ -51,13 +76,14 @@ Common options:
 use std::{
     env,
     fs::{self, File},
-    io::{self, BufRead, BufReader, BufWriter, Write},
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
     path::Path,
 };
 
 use csv::{Reader, Writer};
 use geozero::{
-    GeozeroDatasource,
+    ColumnValue, CoordDimensions, FeatureProcessor, GeomProcessor, GeozeroDatasource,
+    PropertyProcessor,
     csv::CsvWriter,
     geojson::{GeoJsonLineWriter, GeoJsonWriter},
     svg::SvgWriter,
@@ -122,7 +148,7 @@ where
 #[serde(rename_all = "lowercase")]
 enum InputFormat {
     Geojson,
-    // Geojsonl,
+    Geojsonl,
     Shp,
     Csv,
 }
@@ -135,18 +161,23 @@ enum OutputFormat {
     Svg,
     Geojson,
     Geojsonl,
+    Kml,
 }
 
 #[derive(Deserialize)]
 struct Args {
-    arg_input:         Option<String>,
-    arg_input_format:  InputFormat,
-    arg_output_format: OutputFormat,
-    flag_latitude:     Option<String>,
-    flag_longitude:    Option<String>,
-    flag_geometry:     Option<String>,
-    flag_output:       Option<String>,
-    flag_max_length:   Option<usize>,
+    arg_input:               Option<String>,
+    arg_input_format:        InputFormat,
+    arg_output_format:       OutputFormat,
+    flag_latitude:           Option<String>,
+    flag_longitude:          Option<String>,
+    flag_elevation:          Option<String>,
+    flag_geometry:           Option<String>,
+    flag_output:             Option<String>,
+    flag_max_length:         Option<usize>,
+    flag_flatten_properties: bool,
+    flag_from_crs:           Option<String>,
+    flag_to_crs:             Option<String>,
 }
 
 impl From<geozero::error::GeozeroError> for CliError {
@@ -169,6 +200,554 @@ impl From<geozero::shp::Error> for CliError {
     }
 }
 
+/// --flatten-properties: recursively flatten one nested property object into
+/// dotted-key entries (e.g. "address.city"), leaving scalar values untouched
+fn flatten_properties(
+    properties: &serde_json::Map<String, serde_json::Value>,
+    prefix: &str,
+    flattened: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    for (key, value) in properties {
+        let flat_key = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match value {
+            serde_json::Value::Object(nested) => flatten_properties(nested, &flat_key, flattened),
+            _ => {
+                flattened.insert(flat_key, value.clone());
+            },
+        }
+    }
+}
+
+/// apply --flatten-properties to every feature of a parsed GeoJSON document -
+/// either a FeatureCollection or a single Feature
+fn flatten_geojson_properties(geojson: &mut serde_json::Value) {
+    fn flatten_feature(feature: &mut serde_json::Value) {
+        if let Some(serde_json::Value::Object(properties)) = feature.get_mut("properties") {
+            let mut flattened = serde_json::Map::with_capacity(properties.len());
+            flatten_properties(properties, "", &mut flattened);
+            *properties = flattened;
+        }
+    }
+
+    if let Some(features) = geojson.get_mut("features").and_then(|f| f.as_array_mut()) {
+        for feature in features {
+            flatten_feature(feature);
+        }
+    } else {
+        flatten_feature(geojson);
+    }
+}
+
+/// parse an "EPSG:nnnn" (or bare "nnnn") CRS argument into a proj4rs
+/// projection, erroring clearly when the EPSG code is unknown
+fn parse_crs(arg: &str) -> CliResult<proj4rs::Proj> {
+    let code = arg
+        .strip_prefix("EPSG:")
+        .or_else(|| arg.strip_prefix("epsg:"))
+        .unwrap_or(arg);
+    let Ok(code) = code.parse::<u16>() else {
+        return fail_incorrectusage_clierror!(
+            "Invalid CRS \"{arg}\". Specify an EPSG code, e.g. \"EPSG:4326\"."
+        );
+    };
+    match proj4rs::Proj::from_epsg_code(code) {
+        Ok(proj) => Ok(proj),
+        Err(e) => fail_clierror!("Unknown EPSG code {code}: {e}"),
+    }
+}
+
+/// reproject one coordinate pair between the --from-crs and --to-crs
+/// projections. proj4rs works in radians for geographic CRS, so degrees are
+/// converted going in and coming out as needed
+fn reproject_xy(
+    from: &proj4rs::Proj,
+    to: &proj4rs::Proj,
+    x: f64,
+    y: f64,
+) -> Result<(f64, f64), proj4rs::errors::Error> {
+    let mut point = (x, y);
+    if from.is_latlong() {
+        point.0 = point.0.to_radians();
+        point.1 = point.1.to_radians();
+    }
+    proj4rs::transform::transform(from, to, &mut point)?;
+    if to.is_latlong() {
+        point.0 = point.0.to_degrees();
+        point.1 = point.1.to_degrees();
+    }
+    Ok(point)
+}
+
+/// a geozero processor wrapper that reprojects every coordinate from the
+/// --from-crs projection to the --to-crs projection before handing it to
+/// the wrapped writer. Everything else is delegated untouched
+struct CrsTransformer<'a, P> {
+    inner: &'a mut P,
+    from:  &'a proj4rs::Proj,
+    to:    &'a proj4rs::Proj,
+}
+
+impl<P> CrsTransformer<'_, P> {
+    fn reproject(&self, x: f64, y: f64) -> geozero::error::Result<(f64, f64)> {
+        reproject_xy(self.from, self.to, x, y)
+            .map_err(|e| geozero::error::GeozeroError::Geometry(e.to_string()))
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for CrsTransformer<'_, P> {
+    fn dimensions(&self) -> CoordDimensions {
+        self.inner.dimensions()
+    }
+
+    fn multi_dim(&self) -> bool {
+        self.inner.multi_dim()
+    }
+
+    fn srid(&mut self, srid: Option<i32>, idx: usize) -> geozero::error::Result<()> {
+        self.inner.srid(srid, idx)
+    }
+
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> geozero::error::Result<()> {
+        let (x, y) = self.reproject(x, y)?;
+        self.inner.xy(x, y, idx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        let (x, y) = self.reproject(x, y)?;
+        self.inner.coordinate(x, y, z, m, t, tm, idx)
+    }
+
+    fn empty_point(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.inner.empty_point(idx)
+    }
+
+    fn point_begin(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.inner.point_begin(idx)
+    }
+
+    fn point_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.inner.point_end(idx)
+    }
+
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.inner.multipoint_begin(size, idx)
+    }
+
+    fn multipoint_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.inner.multipoint_end(idx)
+    }
+
+    fn linestring_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        self.inner.linestring_begin(tagged, size, idx)
+    }
+
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        self.inner.linestring_end(tagged, idx)
+    }
+
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.inner.multilinestring_begin(size, idx)
+    }
+
+    fn multilinestring_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.inner.multilinestring_end(idx)
+    }
+
+    fn polygon_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        self.inner.polygon_end(tagged, idx)
+    }
+
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.inner.multipolygon_begin(size, idx)
+    }
+
+    fn multipolygon_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.inner.multipolygon_end(idx)
+    }
+
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.inner.geometrycollection_begin(size, idx)
+    }
+
+    fn geometrycollection_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.inner.geometrycollection_end(idx)
+    }
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for CrsTransformer<'_, P> {
+    fn property(
+        &mut self,
+        idx: usize,
+        name: &str,
+        value: &ColumnValue,
+    ) -> geozero::error::Result<bool> {
+        self.inner.property(idx, name, value)
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for CrsTransformer<'_, P> {
+    fn dataset_begin(&mut self, name: Option<&str>) -> geozero::error::Result<()> {
+        self.inner.dataset_begin(name)
+    }
+
+    fn dataset_end(&mut self) -> geozero::error::Result<()> {
+        self.inner.dataset_end()
+    }
+
+    fn feature_begin(&mut self, idx: u64) -> geozero::error::Result<()> {
+        self.inner.feature_begin(idx)
+    }
+
+    fn feature_end(&mut self, idx: u64) -> geozero::error::Result<()> {
+        self.inner.feature_end(idx)
+    }
+
+    fn properties_begin(&mut self) -> geozero::error::Result<()> {
+        self.inner.properties_begin()
+    }
+
+    fn properties_end(&mut self) -> geozero::error::Result<()> {
+        self.inner.properties_end()
+    }
+
+    fn geometry_begin(&mut self) -> geozero::error::Result<()> {
+        self.inner.geometry_begin()
+    }
+
+    fn geometry_end(&mut self) -> geozero::error::Result<()> {
+        self.inner.geometry_end()
+    }
+}
+
+/// escape the XML special characters of a KML attribute/text value
+fn xml_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// render a geozero property value as a string for the KML <value> element
+fn column_value_string(value: &ColumnValue) -> String {
+    match value {
+        ColumnValue::Byte(v) => v.to_string(),
+        ColumnValue::UByte(v) => v.to_string(),
+        ColumnValue::Bool(v) => v.to_string(),
+        ColumnValue::Short(v) => v.to_string(),
+        ColumnValue::UShort(v) => v.to_string(),
+        ColumnValue::Int(v) => v.to_string(),
+        ColumnValue::UInt(v) => v.to_string(),
+        ColumnValue::Long(v) => v.to_string(),
+        ColumnValue::ULong(v) => v.to_string(),
+        ColumnValue::Float(v) => v.to_string(),
+        ColumnValue::Double(v) => v.to_string(),
+        ColumnValue::String(v) | ColumnValue::Json(v) | ColumnValue::DateTime(v) => {
+            (*v).to_string()
+        },
+        ColumnValue::Binary(_) => String::new(),
+    }
+}
+
+/// a geozero FeatureProcessor that writes KML - one <Placemark> per feature,
+/// with feature properties preserved as <ExtendedData>
+struct KmlWriter<'a, W: Write> {
+    out:          &'a mut W,
+    /// coordinate tuples of the geometry part currently being built
+    coords:       Vec<String>,
+    /// ring index within the current polygon, used to pick the
+    /// outerBoundaryIs/innerBoundaryIs wrapper
+    polygon_ring: usize,
+    in_polygon:   bool,
+    /// the SHP feature iterator doesn't fire dataset events, so the
+    /// kml/Document wrapper is written lazily and closed via finish()
+    wrote_header: bool,
+    finished:     bool,
+}
+
+impl<'a, W: Write> KmlWriter<'a, W> {
+    fn new(out: &'a mut W) -> Self {
+        Self {
+            out,
+            coords: Vec::new(),
+            polygon_ring: 0,
+            in_polygon: false,
+            wrote_header: false,
+            finished: false,
+        }
+    }
+
+    fn write_header(&mut self) -> geozero::error::Result<()> {
+        if !self.wrote_header {
+            self.wrote_header = true;
+            writeln!(self.out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+            writeln!(
+                self.out,
+                r#"<kml xmlns="http://www.opengis.net/kml/2.2"><Document>"#
+            )?;
+        }
+        Ok(())
+    }
+
+    /// close the kml/Document wrapper. Must be called after processing as
+    /// not every geozero datasource fires the dataset_end event
+    fn finish(&mut self) -> geozero::error::Result<()> {
+        self.write_header()?;
+        if !self.finished {
+            self.finished = true;
+            writeln!(self.out, "</Document></kml>")?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> GeomProcessor for KmlWriter<'_, W> {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> geozero::error::Result<()> {
+        self.coords.push(format!("{x},{y}"));
+        Ok(())
+    }
+
+    fn point_begin(&mut self, _idx: usize) -> geozero::error::Result<()> {
+        self.coords.clear();
+        Ok(())
+    }
+
+    fn point_end(&mut self, _idx: usize) -> geozero::error::Result<()> {
+        write!(
+            self.out,
+            "<Point><coordinates>{}</coordinates></Point>",
+            self.coords.join(" ")
+        )?;
+        Ok(())
+    }
+
+    fn multipoint_begin(&mut self, _size: usize, _idx: usize) -> geozero::error::Result<()> {
+        self.coords.clear();
+        write!(self.out, "<MultiGeometry>")?;
+        Ok(())
+    }
+
+    fn multipoint_end(&mut self, _idx: usize) -> geozero::error::Result<()> {
+        for coord in &self.coords {
+            write!(
+                self.out,
+                "<Point><coordinates>{coord}</coordinates></Point>"
+            )?;
+        }
+        write!(self.out, "</MultiGeometry>")?;
+        Ok(())
+    }
+
+    fn linestring_begin(
+        &mut self,
+        _tagged: bool,
+        _size: usize,
+        _idx: usize,
+    ) -> geozero::error::Result<()> {
+        self.coords.clear();
+        Ok(())
+    }
+
+    fn linestring_end(&mut self, tagged: bool, _idx: usize) -> geozero::error::Result<()> {
+        let coordinates = self.coords.join(" ");
+        if !tagged && self.in_polygon {
+            // untagged linestrings inside a polygon are its rings
+            let boundary = if self.polygon_ring == 0 {
+                "outerBoundaryIs"
+            } else {
+                "innerBoundaryIs"
+            };
+            self.polygon_ring += 1;
+            write!(self.out, "<{boundary}><LinearRing><coordinates>")?;
+            write!(
+                self.out,
+                "{coordinates}</coordinates></LinearRing></{boundary}>"
+            )?;
+        } else {
+            write!(
+                self.out,
+                "<LineString><coordinates>{coordinates}</coordinates></LineString>"
+            )?;
+        }
+        Ok(())
+    }
+
+    fn multilinestring_begin(&mut self, _size: usize, _idx: usize) -> geozero::error::Result<()> {
+        write!(self.out, "<MultiGeometry>")?;
+        Ok(())
+    }
+
+    fn multilinestring_end(&mut self, _idx: usize) -> geozero::error::Result<()> {
+        write!(self.out, "</MultiGeometry>")?;
+        Ok(())
+    }
+
+    fn polygon_begin(
+        &mut self,
+        _tagged: bool,
+        _size: usize,
+        _idx: usize,
+    ) -> geozero::error::Result<()> {
+        self.in_polygon = true;
+        self.polygon_ring = 0;
+        write!(self.out, "<Polygon>")?;
+        Ok(())
+    }
+
+    fn polygon_end(&mut self, _tagged: bool, _idx: usize) -> geozero::error::Result<()> {
+        self.in_polygon = false;
+        write!(self.out, "</Polygon>")?;
+        Ok(())
+    }
+
+    fn multipolygon_begin(&mut self, _size: usize, _idx: usize) -> geozero::error::Result<()> {
+        write!(self.out, "<MultiGeometry>")?;
+        Ok(())
+    }
+
+    fn multipolygon_end(&mut self, _idx: usize) -> geozero::error::Result<()> {
+        write!(self.out, "</MultiGeometry>")?;
+        Ok(())
+    }
+
+    fn geometrycollection_begin(
+        &mut self,
+        _size: usize,
+        _idx: usize,
+    ) -> geozero::error::Result<()> {
+        write!(self.out, "<MultiGeometry>")?;
+        Ok(())
+    }
+
+    fn geometrycollection_end(&mut self, _idx: usize) -> geozero::error::Result<()> {
+        write!(self.out, "</MultiGeometry>")?;
+        Ok(())
+    }
+}
+
+impl<W: Write> PropertyProcessor for KmlWriter<'_, W> {
+    fn property(
+        &mut self,
+        _idx: usize,
+        name: &str,
+        value: &ColumnValue,
+    ) -> geozero::error::Result<bool> {
+        write!(
+            self.out,
+            "<Data name=\"{}\"><value>{}</value></Data>",
+            xml_escape(name),
+            xml_escape(&column_value_string(value))
+        )?;
+        Ok(false)
+    }
+}
+
+impl<W: Write> FeatureProcessor for KmlWriter<'_, W> {
+    fn dataset_begin(&mut self, _name: Option<&str>) -> geozero::error::Result<()> {
+        self.write_header()
+    }
+
+    fn dataset_end(&mut self) -> geozero::error::Result<()> {
+        self.finish()
+    }
+
+    fn feature_begin(&mut self, _idx: u64) -> geozero::error::Result<()> {
+        self.write_header()?;
+        write!(self.out, "<Placemark>")?;
+        Ok(())
+    }
+
+    fn feature_end(&mut self, _idx: u64) -> geozero::error::Result<()> {
+        writeln!(self.out, "</Placemark>")?;
+        Ok(())
+    }
+
+    fn properties_begin(&mut self) -> geozero::error::Result<()> {
+        write!(self.out, "<ExtendedData>")?;
+        Ok(())
+    }
+
+    fn properties_end(&mut self) -> geozero::error::Result<()> {
+        write!(self.out, "</ExtendedData>")?;
+        Ok(())
+    }
+}
+
+/// process a geozero datasource into `processor`, routing coordinates
+/// through the --from-crs/--to-crs reprojection when one is set
+fn process_with_crs<D: GeozeroDatasource, P: FeatureProcessor>(
+    datasource: &mut D,
+    processor: &mut P,
+    crs: Option<&(proj4rs::Proj, proj4rs::Proj)>,
+) -> CliResult<()> {
+    if let Some((from, to)) = crs {
+        datasource.process(&mut CrsTransformer {
+            inner: processor,
+            from,
+            to,
+        })?;
+    } else {
+        datasource.process(processor)?;
+    }
+    Ok(())
+}
+
+/// same as `process_with_crs`, but for the SHP reader which exposes a feature
+/// iterator instead of implementing `GeozeroDatasource`
+fn iter_features_with_crs<T: io::Read + io::Seek, P: FeatureProcessor>(
+    reader: &mut geozero::shp::ShpReader<T>,
+    processor: &mut P,
+    crs: Option<&(proj4rs::Proj, proj4rs::Proj)>,
+) -> CliResult<()> {
+    if let Some((from, to)) = crs {
+        let _ = reader
+            .iter_features(&mut CrsTransformer {
+                inner: processor,
+                from,
+                to,
+            })?
+            .collect::<Vec<_>>();
+    } else {
+        let _ = reader.iter_features(processor)?.collect::<Vec<_>>();
+    }
+    Ok(())
+}
+
 /// Validates that the input file exists and is readable
 fn validate_input_file(path: &str) -> CliResult<()> {
     if !Path::new(path).exists() {
@@ -182,6 +761,25 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
 
     let max_length = args.flag_max_length;
 
+    if args.flag_flatten_properties
+        && (args.arg_input_format != InputFormat::Geojson
+            || args.arg_output_format != OutputFormat::Csv)
+    {
+        return fail_incorrectusage_clierror!(
+            "--flatten-properties is only valid when converting GeoJSON to CSV."
+        );
+    }
+
+    // --from-crs/--to-crs: build the reprojection pair once, upfront.
+    // Identical codes mean there's nothing to reproject
+    let crs = match (args.flag_from_crs.as_deref(), args.flag_to_crs.as_deref()) {
+        (Some(from), Some(to)) if from != to => Some((parse_crs(from)?, parse_crs(to)?)),
+        (Some(_), Some(_)) | (None, None) => None,
+        _ => {
+            return fail_incorrectusage_clierror!("--from-crs and --to-crs must be used together.");
+        },
+    };
+
     let mut buf_reader: Box<dyn BufRead> = if let Some(input_path) = args.arg_input.clone() {
         if &input_path == "-" {
             Box::new(BufReader::new(std::io::stdin()))
@@ -202,6 +800,30 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
     // Convert the input data to the specified output format
     match args.arg_input_format {
         InputFormat::Geojson => {
+            // --flatten-properties: parse the document, rewrite each feature's
+            // nested property objects into dotted keys, and convert the
+            // flattened document instead (validated above to be GeoJSON->CSV)
+            if args.flag_flatten_properties {
+                let mut geojson_string = String::new();
+                buf_reader.read_to_string(&mut geojson_string)?;
+                let mut geojson: serde_json::Value = serde_json::from_str(&geojson_string)?;
+                flatten_geojson_properties(&mut geojson);
+                let flattened_string = geojson.to_string();
+                let mut geometry = geozero::geojson::GeoJson(&flattened_string);
+
+                if let Some(max_len) = max_length {
+                    process_csv_with_max_length(&mut wtr, max_len, |writer| {
+                        let mut processor = CsvWriter::new(writer);
+                        process_with_crs(&mut geometry, &mut processor, crs.as_ref())?;
+                        Ok(())
+                    })?;
+                    return Ok(());
+                }
+                let mut processor = CsvWriter::new(&mut wtr);
+                process_with_crs(&mut geometry, &mut processor, crs.as_ref())?;
+                return Ok(wtr.flush()?);
+            }
+
             let mut geometry = geozero::geojson::GeoJsonReader(&mut buf_reader);
 
             match args.arg_output_format {
@@ -209,48 +831,70 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
                     if let Some(max_len) = max_length {
                         process_csv_with_max_length(&mut wtr, max_len, |writer| {
                             let mut processor = CsvWriter::new(writer);
-                            geometry.process(&mut processor)?;
+                            process_with_crs(&mut geometry, &mut processor, crs.as_ref())?;
                             Ok(())
                         })?;
                         return Ok(());
                     }
                     // If max_length is not set, write directly to the output
                     let mut processor = CsvWriter::new(&mut wtr);
-                    geometry.process(&mut processor)?;
+                    process_with_crs(&mut geometry, &mut processor, crs.as_ref())?;
                 },
                 OutputFormat::Svg => {
                     let mut processor = SvgWriter::new(&mut wtr, false);
-                    geometry.process(&mut processor)?;
+                    process_with_crs(&mut geometry, &mut processor, crs.as_ref())?;
                 },
                 OutputFormat::Geojsonl => {
                     let mut processor = GeoJsonLineWriter::new(&mut wtr);
-                    geometry.process(&mut processor)?;
+                    process_with_crs(&mut geometry, &mut processor, crs.as_ref())?;
+                },
+                OutputFormat::Kml => {
+                    let mut processor = KmlWriter::new(&mut wtr);
+                    process_with_crs(&mut geometry, &mut processor, crs.as_ref())?;
+                    processor.finish()?;
                 },
                 OutputFormat::Geojson => {
                     return fail_clierror!("Converting GeoJSON to GeoJSON is not supported");
                 },
             }
         },
-        // InputFormat::Geojsonl => {
-        //     let mut geometry = geozero::geojson::GeoJsonLineReader::new(&mut buf_reader);
-        //     match args.arg_output_format {
-        //         OutputFormat::Csv => {
-        //             let mut processor = CsvWriter::new(&mut wtr);
-        //             geometry.process(&mut processor)?
-        //         },
-        //         OutputFormat::Svg => {
-        //             let mut processor = SvgWriter::new(&mut wtr, false);
-        //             geometry.process(&mut processor)?
-        //         },
-        //         OutputFormat::Geojson => {
-        //             let mut processor = GeoJsonWriter::new(&mut wtr);
-        //             geometry.process(&mut processor)?
-        //         },
-        //         OutputFormat::Geojsonl => {
-        //             return fail_clierror!("Converting GeoJSON Lines to GeoJSON Lines is not
-        // supported");         }
-        //     };
-        // },
+        InputFormat::Geojsonl => {
+            let mut geometry = geozero::geojson::GeoJsonLineReader::new(&mut buf_reader);
+
+            match args.arg_output_format {
+                OutputFormat::Csv => {
+                    if let Some(max_len) = max_length {
+                        process_csv_with_max_length(&mut wtr, max_len, |writer| {
+                            let mut processor = CsvWriter::new(writer);
+                            process_with_crs(&mut geometry, &mut processor, crs.as_ref())?;
+                            Ok(())
+                        })?;
+                        return Ok(());
+                    }
+                    // If max_length is not set, write directly to the output
+                    let mut processor = CsvWriter::new(&mut wtr);
+                    process_with_crs(&mut geometry, &mut processor, crs.as_ref())?;
+                },
+                OutputFormat::Svg => {
+                    let mut processor = SvgWriter::new(&mut wtr, false);
+                    process_with_crs(&mut geometry, &mut processor, crs.as_ref())?;
+                },
+                OutputFormat::Geojson => {
+                    let mut processor = GeoJsonWriter::new(&mut wtr);
+                    process_with_crs(&mut geometry, &mut processor, crs.as_ref())?;
+                },
+                OutputFormat::Kml => {
+                    let mut processor = KmlWriter::new(&mut wtr);
+                    process_with_crs(&mut geometry, &mut processor, crs.as_ref())?;
+                    processor.finish()?;
+                },
+                OutputFormat::Geojsonl => {
+                    return fail_clierror!(
+                        "Converting GeoJSON Lines to GeoJSON Lines is not supported"
+                    );
+                },
+            }
+        },
         InputFormat::Shp => {
             let shp_input_path = if let Some(shp_input_path) = args.arg_input {
                 if shp_input_path == "-" {
@@ -272,17 +916,15 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
             let output_string = match args.arg_output_format {
                 OutputFormat::Geojson => {
                     let mut json: Vec<u8> = Vec::new();
-                    let _ = reader
-                        .iter_features(&mut GeoJsonWriter::new(&mut json))?
-                        .collect::<Vec<_>>();
+                    let mut processor = GeoJsonWriter::new(&mut json);
+                    iter_features_with_crs(&mut reader, &mut processor, crs.as_ref())?;
                     String::from_utf8(json)
                         .map_err(|e| CliError::Other(format!("Invalid UTF-8 in output: {e}")))?
                 },
                 OutputFormat::Geojsonl => {
                     let mut json: Vec<u8> = Vec::new();
-                    let _ = reader
-                        .iter_features(&mut GeoJsonLineWriter::new(&mut json))?
-                        .collect::<Vec<_>>();
+                    let mut processor = GeoJsonLineWriter::new(&mut json);
+                    iter_features_with_crs(&mut reader, &mut processor, crs.as_ref())?;
                     String::from_utf8(json)
                         .map_err(|e| CliError::Other(format!("Invalid UTF-8 in output: {e}")))?
                 },
@@ -290,9 +932,8 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
                     if let Some(max_len) = max_length {
                         process_csv_with_max_length(&mut wtr, max_len, |writer| {
                             let mut csv: Vec<u8> = Vec::new();
-                            let _ = reader
-                                .iter_features(&mut CsvWriter::new(&mut csv))?
-                                .collect::<Vec<_>>();
+                            let mut processor = CsvWriter::new(&mut csv);
+                            iter_features_with_crs(&mut reader, &mut processor, crs.as_ref())?;
                             writer.write_all(&csv)?;
                             Ok(())
                         })?;
@@ -300,12 +941,19 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
                     }
                     // If max_length is not set, write directly to the output
                     let mut csv: Vec<u8> = Vec::new();
-                    let _ = reader
-                        .iter_features(&mut CsvWriter::new(&mut csv))?
-                        .collect::<Vec<_>>();
+                    let mut processor = CsvWriter::new(&mut csv);
+                    iter_features_with_crs(&mut reader, &mut processor, crs.as_ref())?;
                     String::from_utf8(csv)
                         .map_err(|e| CliError::Other(format!("Invalid UTF-8 in output: {e}")))?
                 },
+                OutputFormat::Kml => {
+                    let mut kml: Vec<u8> = Vec::new();
+                    let mut processor = KmlWriter::new(&mut kml);
+                    iter_features_with_crs(&mut reader, &mut processor, crs.as_ref())?;
+                    processor.finish()?;
+                    String::from_utf8(kml)
+                        .map_err(|e| CliError::Other(format!("Invalid UTF-8 in output: {e}")))?
+                },
                 OutputFormat::Svg => {
                     return fail_clierror!("Converting SHP to SVG is not supported");
                 },
@@ -324,27 +972,63 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
                     "Cannot use --geometry flag with --latitude or --longitude."
                 );
             }
+            if args.flag_elevation.is_some()
+                && (args.flag_latitude.is_none() || args.flag_longitude.is_none())
+            {
+                return fail_clierror!("--elevation requires --latitude and --longitude.");
+            }
             if let Some(geometry_col) = args.flag_geometry {
+                // --geometry: a bare integer is a zero-based column index,
+                // resolved against the CSV headers to the column's name
+                let geometry_col = if let Ok(geometry_idx) = geometry_col.parse::<usize>() {
+                    let mut header_line = String::new();
+                    buf_reader.read_line(&mut header_line)?;
+                    let mut header_rdr = csv::ReaderBuilder::new()
+                        .has_headers(false)
+                        .from_reader(header_line.as_bytes());
+                    let mut headers = csv::StringRecord::new();
+                    header_rdr.read_record(&mut headers)?;
+                    let Some(name) = headers.get(geometry_idx) else {
+                        return fail_incorrectusage_clierror!(
+                            "--geometry column index {geometry_idx} is out of range: the input \
+                             only has {} columns.",
+                            headers.len()
+                        );
+                    };
+                    let name = name.to_string();
+                    // put the header line back in front of the remaining input
+                    buf_reader = Box::new(BufReader::new(
+                        io::Cursor::new(header_line.into_bytes()).chain(buf_reader),
+                    ));
+                    name
+                } else {
+                    geometry_col
+                };
                 let mut csv = geozero::csv::CsvReader::new(&geometry_col, buf_reader);
 
                 match args.arg_output_format {
                     OutputFormat::Geojson => {
                         let mut processor = GeoJsonWriter::new(&mut wtr);
-                        csv.process(&mut processor)?;
+                        process_with_crs(&mut csv, &mut processor, crs.as_ref())?;
                     },
                     OutputFormat::Geojsonl => {
                         let mut processor = GeoJsonLineWriter::new(&mut wtr);
-                        csv.process(&mut processor)?;
+                        process_with_crs(&mut csv, &mut processor, crs.as_ref())?;
                     },
                     OutputFormat::Svg => {
                         let mut processor = SvgWriter::new(&mut wtr, false);
-                        csv.process(&mut processor)?;
+                        process_with_crs(&mut csv, &mut processor, crs.as_ref())?;
+                    },
+                    OutputFormat::Kml => {
+                        let mut processor = KmlWriter::new(&mut wtr);
+                        process_with_crs(&mut csv, &mut processor, crs.as_ref())?;
+                        processor.finish()?;
                     },
                     OutputFormat::Csv => {
                         if let Some(max_len) = max_length {
                             process_csv_with_max_length(&mut wtr, max_len, |writer| {
                                 let mut processor = CsvWriter::new(writer);
-                                csv.process(&mut processor)?;
+                                process_with_crs(&mut csv, &mut processor, crs.as_ref())?;
                                 Ok(())
                             })?;
                             return Ok(());
@@ -358,8 +1042,6 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
                 {
                     let mut rdr = csv::Reader::from_reader(buf_reader);
                     let headers = rdr.headers()?.clone();
-                    let mut feature_collection =
-                        serde_json::json!({"type": "FeatureCollection", "features": []});
 
                     let latitude_col_index =
                         headers.iter().position(|y| y == y_col).ok_or_else(|| {
@@ -371,6 +1053,30 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
                                 "Longitude column '{x_col}' not found"
                             ))
                         })?;
+                    let elevation_col_index = if let Some(ref z_col) = args.flag_elevation {
+                        Some(headers.iter().position(|z| z == z_col).ok_or_else(|| {
+                            CliError::IncorrectUsage(format!(
+                                "Elevation column '{z_col}' not found"
+                            ))
+                        })?)
+                    } else {
+                        None
+                    };
+
+                    // stream the FeatureCollection one feature at a time instead of
+                    // materializing it as one giant serde_json::Value, so memory
+                    // stays bounded by a single feature. For non-GeoJSON outputs
+                    // the document is still accumulated as a string, as geozero
+                    // processes it as one document
+                    const FC_PREFIX: &str = "{\"type\":\"FeatureCollection\",\"features\":[";
+                    let streaming = args.arg_output_format == OutputFormat::Geojson;
+                    let mut fc_string = String::new();
+                    if streaming {
+                        wtr.write_all(FC_PREFIX.as_bytes())?;
+                    } else {
+                        fc_string.push_str(FC_PREFIX);
+                    }
+                    let mut first_feature = true;
 
                     for result in rdr.records() {
                         let record = result?;
@@ -390,6 +1096,18 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
                                 CliError::Other(format!("Invalid longitude value: {e}"))
                             })?;
 
+                        // Reproject here, before the Feature is built, so the geozero
+                        // arms below see already-transformed coordinates
+                        let (longitude_value, latitude_value) =
+                            if let Some((from, to)) = crs.as_ref() {
+                                reproject_xy(from, to, longitude_value, latitude_value)
+                                    .map_err(|e| {
+                                        CliError::Other(format!("Reprojection error: {e}"))
+                                    })?
+                            } else {
+                                (longitude_value, latitude_value)
+                            };
+
                         let geometry = feature.get_mut("geometry").ok_or_else(|| {
                             CliError::IncorrectUsage("Missing geometry object".to_string())
                         })?;
@@ -397,14 +1115,31 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
                             CliError::IncorrectUsage("Invalid geometry object".to_string())
                         })?;
                         geometry_obj.insert("type".to_string(), serde_json::Value::from("Point"));
+                        // GeoJSON positions are [longitude, latitude(, elevation)]
+                        let mut coordinates = vec![longitude_value, latitude_value];
+                        if let Some(elevation_idx) = elevation_col_index {
+                            let elevation_value = record
+                                .get(elevation_idx)
+                                .ok_or_else(|| {
+                                    CliError::Other("Missing elevation value".to_string())
+                                })?
+                                .parse::<f64>()
+                                .map_err(|e| {
+                                    CliError::Other(format!("Invalid elevation value: {e}"))
+                                })?;
+                            coordinates.push(elevation_value);
+                        }
                         geometry_obj.insert(
                             "coordinates".to_string(),
-                            serde_json::Value::from(vec![latitude_value, longitude_value]),
+                            serde_json::Value::from(coordinates),
                         );
 
                         // Add properties
                         for (index, value) in record.iter().enumerate() {
-                            if index != longitude_col_index && index != latitude_col_index {
+                            if index != longitude_col_index
+                                && index != latitude_col_index
+                                && Some(index) != elevation_col_index
+                            {
                                 let properties =
                                     feature.get_mut("properties").ok_or_else(|| {
                                         CliError::Other("Missing properties object".to_string())
@@ -424,18 +1159,29 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
                             }
                         }
 
-                        // Add Feature to FeatureCollection
-                        let features = feature_collection
-                            .get_mut("features")
-                            .ok_or_else(|| CliError::Other("Missing features array".to_string()))?;
-                        let features_array = features
-                            .as_array_mut()
-                            .ok_or_else(|| CliError::Other("Invalid features array".to_string()))?;
-                        features_array.push(feature);
+                        // Append the Feature to the FeatureCollection stream
+                        let feature_string = feature.to_string();
+                        if streaming {
+                            if !first_feature {
+                                wtr.write_all(b",")?;
+                            }
+                            wtr.write_all(feature_string.as_bytes())?;
+                        } else {
+                            if !first_feature {
+                                fc_string.push(',');
+                            }
+                            fc_string.push_str(&feature_string);
+                        }
+                        first_feature = false;
                     }
 
-                    // Write FeatureCollection
-                    let fc_string = feature_collection.to_string();
+                    if streaming {
+                        wtr.write_all(b"]}")?;
+                        return Ok(wtr.flush()?);
+                    }
+
+                    // feed the accumulated FeatureCollection through geozero
+                    fc_string.push_str("]}");
                     let mut geometry = geozero::geojson::GeoJson(&fc_string);
                     match args.arg_output_format {
                         OutputFormat::Csv => {
@@ -459,8 +1205,13 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
                             let mut processor = GeoJsonLineWriter::new(&mut wtr);
                             geometry.process(&mut processor)?;
                         },
+                        OutputFormat::Kml => {
+                            let mut processor = KmlWriter::new(&mut wtr);
+                            geometry.process(&mut processor)?;
+                            processor.finish()?;
+                        },
                         OutputFormat::Geojson => {
-                            wtr.write_all(fc_string.as_bytes())?;
+                            // handled by the streaming path above
                         },
                     }
                     return Ok(());
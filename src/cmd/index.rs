@@ -12,7 +12,13 @@ However, if the environment variable QSV_AUTOINDEX_SIZE is set, qsv will
 automatically create an index when the input file size >= specified size (bytes).
 It will also automatically update stale indices as well.
 
+The 'describe' subcommand prints the index's record count, the data file's
+size and whether the index is still current. It exits with a non-zero code
+when the index is stale (i.e. the CSV was modified after the index was built),
+so it can be used to gate CI pipelines.
+
 Usage:
+    qsv index describe [options] <input>
     qsv index [options] <input>
     qsv index --help
 
@@ -42,8 +48,9 @@ use crate::{
 
 #[derive(Deserialize)]
 struct Args {
-    arg_input:   String,
-    flag_output: Option<String>,
+    arg_input:    String,
+    cmd_describe: bool,
+    flag_output:  Option<String>,
 }
 
 pub fn run(argv: &[&str]) -> CliResult<()> {
@@ -58,6 +65,10 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
         Some(p) => PathBuf::from(&p),
     };
 
+    if args.cmd_describe {
+        return describe_index(&args.arg_input, &pidx);
+    }
+
     let rconfig = Config::new(Some(args.arg_input).as_ref());
     let mut rdr = rconfig.reader_file()?;
     let mut wtr =
@@ -67,3 +78,30 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
 
     Ok(())
 }
+
+/// Print the index's record count, the data file's size and whether the
+/// index is still current, failing with a non-zero exit code when stale.
+fn describe_index(input: &str, pidx: &Path) -> CliResult<()> {
+    let Ok(idx_file) = fs::File::open(pidx) else {
+        return fail_clierror!("No index found at {}.", pidx.display());
+    };
+
+    let idx = RandomAccessSimple::open(idx_file)?;
+    // less the header row
+    let record_count = idx.len().saturating_sub(1);
+    let (_, data_fsize) = util::file_metadata(&fs::metadata(input)?);
+
+    println!("index: {}", pidx.display());
+    println!("records: {record_count}");
+    println!("data file size: {data_fsize} bytes");
+
+    if crate::index::is_stale(Path::new(input), pidx)? {
+        return fail_clierror!(
+            "index is STALE: {input} was modified after its index was built. Rerun 'qsv index' \
+             to rebuild it."
+        );
+    }
+    println!("index is current");
+
+    Ok(())
+}
@@ -2678,6 +2678,7 @@ fn setup_helpers(
                 ckan_api_url: Some(ckan_api_url.clone()),
                 ckan_token: ckan_token.clone(),
                 timeout_secs: TIMEOUT_SECS.load(Ordering::Relaxed),
+                force_refresh: false,
             };
 
             let result = match crate::lookup::load_lookup_table(&lookup_table_opts) {
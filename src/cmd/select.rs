@@ -77,6 +77,15 @@ These options only apply to the `select` command, not the `--select` option in o
 
     -S, --sort             Sort the selected columns lexicographically,
                            i.e. by their byte values.
+    --allow-empty          When the selection is a regex, allow it to match no
+                           columns instead of erroring, so speculative patterns
+                           (e.g. /^geo_/) can be used against files that may
+                           not have any matching columns.
+    --dedup-select         Collapse repeated columns in the selection, keeping
+                           the first occurrence of each, so "id,name,id" yields
+                           "id,name". With --no-headers, duplicates are collapsed
+                           by column index. Intentional column duplication
+                           (e.g. "h1,h1") is preserved when this is not set.
 
 Common options:
     -h, --help             Display this message
@@ -100,14 +109,16 @@ use crate::{
 
 #[derive(Deserialize)]
 struct Args {
-    arg_input:       Option<String>,
-    arg_selection:   SelectColumns,
-    flag_random:     bool,
-    flag_seed:       Option<u64>,
-    flag_sort:       bool,
-    flag_output:     Option<String>,
-    flag_no_headers: bool,
-    flag_delimiter:  Option<Delimiter>,
+    arg_input:         Option<String>,
+    arg_selection:     SelectColumns,
+    flag_random:       bool,
+    flag_seed:         Option<u64>,
+    flag_sort:         bool,
+    flag_allow_empty:  bool,
+    flag_dedup_select: bool,
+    flag_output:       Option<String>,
+    flag_no_headers:   bool,
+    flag_delimiter:    Option<Delimiter>,
 }
 
 pub fn run(argv: &[&str]) -> CliResult<()> {
@@ -120,7 +131,7 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
     let rconfig = Config::new(args.arg_input.as_ref())
         .delimiter(args.flag_delimiter)
         .no_headers(args.flag_no_headers)
-        .select(args.arg_selection);
+        .select(args.arg_selection.allow_empty(args.flag_allow_empty));
 
     let mut rdr = rconfig.reader()?;
     let mut wtr = Config::new(args.flag_output.as_ref()).writer()?;
@@ -187,6 +198,11 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
     } else {
         rconfig.selection(&headers)?
     };
+    let sel = if args.flag_dedup_select {
+        sel.deduped()
+    } else {
+        sel
+    };
 
     if !rconfig.no_headers {
         wtr.write_record(sel.iter().map(|&i| &headers[i]))?;
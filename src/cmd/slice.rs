@@ -30,7 +30,38 @@ slice options:
                            The value is the field value. The output is a
                            JSON array. If --no-headers is set, then
                            the keys are the column indices (zero-based).
+    --jsonl                Like --json, but output newline-delimited JSON
+                           (JSON Lines) - one object per row with no
+                           enclosing array, which is easier to stream into
+                           downstream tools.
     --invert               slice all records EXCEPT those in the specified range.
+    --repeat <n>           Emit the resolved range N times consecutively.
+                           The header is only written once. Useful for
+                           generating load-testing fixtures.
+                           [default: 1]
+    --ranges <spec>        A comma-separated list of zero-based, half-open row
+                           ranges to emit in one pass, e.g. "0-5,10-12,20-".
+                           "5-8" emits rows 5, 6 & 7; "20-" continues to the
+                           last record. The union of the ranges is emitted in
+                           document order, with overlaps deduplicated. With an
+                           index, seeks to each range start; without one, the
+                           input is streamed in a single pass. Cannot be used
+                           with --start, --end, --len, --index, --invert
+                           or --every.
+    --every <n>            Emit only rows whose zero-based index within the
+                           resolved slice is divisible by N - i.e. every Nth
+                           row of the slice, starting with its first row.
+                           With --invert, keeps the rows NOT selected by the
+                           stride. With an index, seeks to the start of the
+                           slice and strides from there; without one, the
+                           input is streamed, skipping non-selected rows.
+                           [default: 1]
+    --every-offset <n>     With --every, start the stride on the Nth (zero-based)
+                           row of the resolved slice instead of its first row -
+                           i.e. emit rows where (position - N) % every == 0.
+                           Must be less than --every. Running the same slice
+                           with each offset 0..every partitions it into `every`
+                           disjoint interleaved subsets. [default: 0]
 
 Examples:
   # Slice from the 3rd record to the end
@@ -67,6 +98,18 @@ Examples:
   # Slice records 1 to 9 and 21 to the end as JSON
   $ qsv slice -s 9 -l 10 --invert --json data.csv
 
+  # Every 100th record of the whole file, for a quick visual scan
+  $ qsv slice --every 100 data.csv
+
+  # Every 10th record of records 1000-1999
+  $ qsv slice -s 1000 -l 1000 --every 10 data.csv
+
+  # The odd-positioned records - every 2nd record, starting from the 2nd
+  $ qsv slice --every 2 --every-offset 1 data.csv
+
+  # Records 0-4, 10-11 and 20 to the end, in one pass
+  $ qsv slice --ranges 0-5,10-12,20- data.csv
+
 Common options:
     -h, --help             Display this message
     -o, --output <file>    Write output to <file> instead of stdout.
@@ -75,15 +118,23 @@ Common options:
                            appear in the output as the header row.
     -d, --delimiter <arg>  The field delimiter for reading CSV data.
                            Must be a single character. (default: ,)
+    --quote-style <arg>    The quote style to use when writing CSV output:
+                             necessary   - quote fields only when necessary (default)
+                             always      - quote all fields
+                             non-numeric - quote all non-numeric fields
+                             never       - never quote fields
+                           Overrides the QSV_DEFAULT_QUOTE_STYLE envvar.
+    --crlf                 Use '\r\n' line endings in the output.
+                           Overrides the QSV_CRLF envvar.
 "#;
 
-use std::{fs, path::PathBuf};
+use std::{collections::VecDeque, fs, path::PathBuf};
 
 use serde::Deserialize;
 
 use crate::{
     CliResult,
-    config::{Config, Delimiter},
+    config::{Config, Delimiter, parse_quote_style},
     index::Indexed,
     util,
 };
@@ -91,20 +142,56 @@ use crate::{
 #[allow(clippy::unsafe_derive_deserialize)]
 #[derive(Deserialize)]
 struct Args {
-    arg_input:       Option<String>,
-    flag_start:      Option<isize>,
-    flag_end:        Option<usize>,
-    flag_len:        Option<usize>,
-    flag_index:      Option<isize>,
-    flag_json:       bool,
-    flag_output:     Option<String>,
-    flag_no_headers: bool,
-    flag_delimiter:  Option<Delimiter>,
-    flag_invert:     bool,
+    arg_input:         Option<String>,
+    flag_start:        Option<isize>,
+    flag_end:          Option<usize>,
+    flag_len:          Option<usize>,
+    flag_index:        Option<isize>,
+    flag_json:         bool,
+    flag_jsonl:        bool,
+    flag_output:       Option<String>,
+    flag_no_headers:   bool,
+    flag_delimiter:    Option<Delimiter>,
+    flag_invert:       bool,
+    flag_repeat:       usize,
+    flag_every:        usize,
+    flag_every_offset: usize,
+    flag_ranges:       Option<String>,
+    flag_quote_style:  Option<String>,
+    flag_crlf:         bool,
 }
 
 pub fn run(argv: &[&str]) -> CliResult<()> {
     let mut args: Args = util::get_args(USAGE, argv)?;
+    if args.flag_repeat == 0 {
+        return fail_incorrectusage_clierror!("--repeat must be greater than 0.");
+    }
+    if args.flag_every == 0 {
+        return fail_incorrectusage_clierror!("--every must be greater than 0.");
+    }
+    if args.flag_every_offset >= args.flag_every {
+        return fail_incorrectusage_clierror!("--every-offset must be less than --every.");
+    }
+    // validate --quote-style upfront; wconfig() reparses it infallibly
+    if let Some(ref style) = args.flag_quote_style {
+        parse_quote_style(style)?;
+    }
+    if args.flag_ranges.is_some() {
+        if args.flag_start.is_some()
+            || args.flag_end.is_some()
+            || args.flag_len.is_some()
+            || args.flag_index.is_some()
+        {
+            return fail_incorrectusage_clierror!(
+                "--ranges cannot be used with --start, --end, --len or --index."
+            );
+        }
+        if args.flag_invert || args.flag_every > 1 {
+            return fail_incorrectusage_clierror!(
+                "--ranges cannot be used with --invert or --every."
+            );
+        }
+    }
 
     let tmpdir = tempfile::tempdir()?;
     let work_input = util::process_input(
@@ -132,50 +219,211 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
 }
 
 impl Args {
+    /// whether zero-based row `i` is selected by the resolved range plus
+    /// the --every stride, phased by --every-offset (before --invert
+    /// is applied)
+    #[inline]
+    fn in_slice(&self, i: usize, start: usize, end: usize) -> bool {
+        i >= start && i < end && (i - start) % self.flag_every == self.flag_every_offset
+    }
+
+    /// whether zero-based row `i` is emitted, given either the parsed
+    /// --ranges union or the resolved single range with --invert applied
+    #[inline]
+    fn should_emit(
+        &self,
+        i: usize,
+        start: usize,
+        end: usize,
+        ranges: Option<&[(usize, usize)]>,
+    ) -> bool {
+        match ranges {
+            Some(ranges) => ranges.iter().any(|&(s, e)| i >= s && i < e),
+            None => self.in_slice(i, start, end) != self.flag_invert,
+        }
+    }
+
+    /// write the selected records as a JSON array, or as JSON Lines
+    /// when --jsonl is set
+    fn write_json_output(
+        &self,
+        headers: &csv::ByteRecord,
+        records: impl Iterator<Item = csv::ByteRecord>,
+    ) -> CliResult<()> {
+        if self.flag_jsonl {
+            util::write_jsonl(
+                self.flag_output.as_ref(),
+                self.flag_no_headers,
+                headers,
+                records,
+            )
+        } else {
+            util::write_json(
+                self.flag_output.as_ref(),
+                self.flag_no_headers,
+                headers,
+                records,
+            )
+        }
+    }
+
+    /// parse and normalize the --ranges spec into a sorted, merged list of
+    /// half-open (start, end) row ranges. An open-ended range ("20-") ends
+    /// at usize::MAX, i.e. the last record
+    fn parse_ranges(&self) -> CliResult<Option<Vec<(usize, usize)>>> {
+        let Some(spec) = &self.flag_ranges else {
+            return Ok(None);
+        };
+        let mut ranges: Vec<(usize, usize)> = Vec::new();
+        for part in spec.split(',') {
+            let part = part.trim();
+            let Some((start_str, end_str)) = part.split_once('-') else {
+                return fail_incorrectusage_clierror!(
+                    "Invalid --ranges component {part:?}. Expected \"start-end\" or \"start-\"."
+                );
+            };
+            let Ok(start) = start_str.parse::<usize>() else {
+                return fail_incorrectusage_clierror!("Invalid --ranges start in {part:?}.");
+            };
+            let end = if end_str.is_empty() {
+                usize::MAX
+            } else {
+                match end_str.parse::<usize>() {
+                    Ok(end) if end >= start => end,
+                    _ => {
+                        return fail_incorrectusage_clierror!("Invalid --ranges end in {part:?}.");
+                    },
+                }
+            };
+            ranges.push((start, end));
+        }
+        // merge overlapping ranges so each row is emitted at most once,
+        // in document order
+        ranges.sort_unstable();
+        let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some((_, last_end)) if start <= *last_end => *last_end = (*last_end).max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        Ok(Some(merged))
+    }
+
     fn no_index(&self) -> CliResult<()> {
+        // a negative --index is resolved in a single streaming pass with a
+        // small ring buffer of the last |index| records, avoiding the extra
+        // row-counting pass that range() would otherwise need
+        if let Some(index) = self.flag_index
+            && index < 0
+            && !self.flag_invert
+            && self.flag_start.is_none()
+            && self.flag_end.is_none()
+            && self.flag_len.is_none()
+        {
+            return self.no_index_negative_index(index.unsigned_abs());
+        }
+
         let mut rdr = self.rconfig().reader()?;
 
         let (start, end) = self.range()?;
-        if self.flag_json {
+        let ranges = self.parse_ranges()?;
+        if self.flag_json || self.flag_jsonl {
             let headers = rdr.byte_headers()?.clone();
             let records = rdr.byte_records().enumerate().filter_map(move |(i, r)| {
-                let should_include = if self.flag_invert {
-                    i < start || i >= end
-                } else {
-                    i >= start && i < end
-                };
+                let should_include = self.should_emit(i, start, end, ranges.as_deref());
                 if should_include {
                     Some(r.unwrap())
                 } else {
                     None
                 }
             });
-            util::write_json(
-                self.flag_output.as_ref(),
-                self.flag_no_headers,
-                &headers,
-                records,
-            )
+            if self.flag_repeat > 1 {
+                // buffer the resolved range so we can emit it repeatedly
+                let records_vec: Vec<csv::ByteRecord> = records.collect();
+                let repeated = (0..self.flag_repeat).flat_map(|_| records_vec.iter().cloned());
+                return self.write_json_output(&headers, repeated);
+            }
+            self.write_json_output(&headers, records)
         } else {
             let mut wtr = self.wconfig().writer()?;
             self.rconfig().write_headers(&mut rdr, &mut wtr)?;
 
-            for (i, r) in rdr.byte_records().enumerate() {
-                if self.flag_invert == (i < start || i >= end) {
-                    wtr.write_byte_record(&r?)?;
+            if self.flag_repeat > 1 {
+                // buffer the resolved range so we can emit it repeatedly
+                let mut records_vec: Vec<csv::ByteRecord> = Vec::new();
+                for (i, r) in rdr.byte_records().enumerate() {
+                    if self.should_emit(i, start, end, ranges.as_deref()) {
+                        records_vec.push(r?);
+                    }
+                }
+                for _ in 0..self.flag_repeat {
+                    for r in &records_vec {
+                        wtr.write_byte_record(r)?;
+                    }
+                }
+            } else {
+                for (i, r) in rdr.byte_records().enumerate() {
+                    if self.should_emit(i, start, end, ranges.as_deref()) {
+                        wtr.write_byte_record(&r?)?;
+                    }
                 }
             }
             Ok(wtr.flush()?)
         }
     }
 
+    /// slice a single record addressed from the end of the data (--index -N)
+    /// in one streaming pass, keeping just the last N records in a ring buffer
+    fn no_index_negative_index(&self, lookback: usize) -> CliResult<()> {
+        let mut rdr = self.rconfig().reader()?;
+        let headers = rdr.byte_headers()?.clone();
+
+        let mut ring: VecDeque<csv::ByteRecord> = VecDeque::with_capacity(lookback + 1);
+        let mut record = csv::ByteRecord::new();
+        while rdr.read_byte_record(&mut record)? {
+            if ring.len() == lookback {
+                ring.pop_front();
+            }
+            ring.push_back(record.clone());
+        }
+
+        // the target record is at the front of the ring only when the data
+        // has at least |index| records
+        let target = if ring.len() == lookback {
+            ring.pop_front()
+        } else {
+            None
+        };
+
+        if self.flag_json || self.flag_jsonl {
+            let repeated = (0..self.flag_repeat).flat_map(|_| target.clone());
+            return self.write_json_output(&headers, repeated);
+        }
+
+        let mut wtr = self.wconfig().writer()?;
+        if !self.rconfig().no_headers {
+            wtr.write_byte_record(&headers)?;
+        }
+        if let Some(target) = target {
+            for _ in 0..self.flag_repeat {
+                wtr.write_byte_record(&target)?;
+            }
+        }
+        Ok(wtr.flush()?)
+    }
+
     fn with_index(&self, mut indexed_file: Indexed<fs::File, fs::File>) -> CliResult<()> {
+        if let Some(ranges) = self.parse_ranges()? {
+            return self.with_index_ranges(indexed_file, &ranges);
+        }
+
         let (start, end) = self.range()?;
         if end - start == 0 && !self.flag_invert {
             return Ok(());
         }
 
-        if self.flag_json {
+        if self.flag_json || self.flag_jsonl {
             let headers = indexed_file.byte_headers()?.clone();
             let total_rows = util::count_rows(&self.rconfig())?;
             let records = if self.flag_invert {
@@ -187,6 +435,16 @@ impl Args {
                     records.push(r.unwrap());
                 }
 
+                // Get in-range records not selected by the --every stride
+                if self.flag_every > 1 {
+                    indexed_file.seek(start as u64)?;
+                    for (i, r) in indexed_file.byte_records().take(end - start).enumerate() {
+                        if i % self.flag_every != self.flag_every_offset {
+                            records.push(r.unwrap());
+                        }
+                    }
+                }
+
                 // Get records after end
                 indexed_file.seek(end as u64)?;
                 for r in indexed_file.byte_records().take(total_rows as usize - end) {
@@ -198,40 +456,99 @@ impl Args {
                 indexed_file
                     .byte_records()
                     .take(end - start)
-                    .map(|r| r.unwrap())
+                    .enumerate()
+                    .filter(|(i, _)| i % self.flag_every == self.flag_every_offset)
+                    .map(|(_, r)| r.unwrap())
                     .collect::<Vec<_>>()
             };
-            util::write_json(
-                self.flag_output.as_ref(),
-                self.flag_no_headers,
-                &headers,
-                records.into_iter(),
-            )
+            let repeated =
+                (0..self.flag_repeat).flat_map(|_| records.iter().cloned());
+            self.write_json_output(&headers, repeated)
         } else {
             let mut wtr = self.wconfig().writer()?;
             self.rconfig().write_headers(&mut *indexed_file, &mut wtr)?;
 
             let total_rows = util::count_rows(&self.rconfig())? as usize;
-            if self.flag_invert {
-                // Get records before start
-                indexed_file.seek(0)?;
-                for r in indexed_file.byte_records().take(start) {
-                    wtr.write_byte_record(&r?)?;
+            for _ in 0..self.flag_repeat {
+                if self.flag_invert {
+                    // Get records before start
+                    indexed_file.seek(0)?;
+                    for r in indexed_file.byte_records().take(start) {
+                        wtr.write_byte_record(&r?)?;
+                    }
+
+                    // Get in-range records not selected by the --every stride
+                    if self.flag_every > 1 {
+                        indexed_file.seek(start as u64)?;
+                        for (i, r) in indexed_file.byte_records().take(end - start).enumerate() {
+                            if i % self.flag_every != self.flag_every_offset {
+                                wtr.write_byte_record(&r?)?;
+                            }
+                        }
+                    }
+
+                    // Get records after end
+                    indexed_file.seek(end as u64)?;
+                    for r in indexed_file.byte_records().take(total_rows - end) {
+                        wtr.write_byte_record(&r?)?;
+                    }
+                } else {
+                    // with an index, we can just re-seek to the start of the
+                    // range for each repetition instead of buffering it
+                    indexed_file.seek(start as u64)?;
+                    for (i, r) in indexed_file.byte_records().take(end - start).enumerate() {
+                        if i % self.flag_every == self.flag_every_offset {
+                            wtr.write_byte_record(&r?)?;
+                        }
+                    }
                 }
+            }
+            Ok(wtr.flush()?)
+        }
+    }
 
-                // Get records after end
-                indexed_file.seek(end as u64)?;
-                for r in indexed_file.byte_records().take(total_rows - end) {
-                    wtr.write_byte_record(&r?)?;
+    /// emit the union of the --ranges windows by seeking to each range start
+    fn with_index_ranges(
+        &self,
+        mut indexed_file: Indexed<fs::File, fs::File>,
+        ranges: &[(usize, usize)],
+    ) -> CliResult<()> {
+        let total_rows = util::count_rows(&self.rconfig())? as usize;
+
+        if self.flag_json || self.flag_jsonl {
+            let headers = indexed_file.byte_headers()?.clone();
+            let mut records: Vec<csv::ByteRecord> = Vec::new();
+            for &(start, end) in ranges {
+                // ranges are sorted, so everything past EOF can be skipped
+                if start >= total_rows {
+                    break;
                 }
-            } else {
+                let end = end.min(total_rows);
+                indexed_file.seek(start as u64)?;
+                for r in indexed_file.byte_records().take(end - start) {
+                    records.push(r?);
+                }
+            }
+            let repeated = (0..self.flag_repeat).flat_map(|_| records.iter().cloned());
+            return self.write_json_output(&headers, repeated);
+        }
+
+        let mut wtr = self.wconfig().writer()?;
+        self.rconfig().write_headers(&mut *indexed_file, &mut wtr)?;
+        for _ in 0..self.flag_repeat {
+            for &(start, end) in ranges {
+                // ranges are sorted, so everything past EOF can be skipped
+                if start >= total_rows {
+                    break;
+                }
+                let end = end.min(total_rows);
                 indexed_file.seek(start as u64)?;
                 for r in indexed_file.byte_records().take(end - start) {
                     wtr.write_byte_record(&r?)?;
                 }
             }
-            Ok(wtr.flush()?)
         }
+        Ok(wtr.flush()?)
     }
 
     fn range(&self) -> CliResult<(usize, usize)> {
@@ -267,6 +584,17 @@ impl Args {
     }
 
     fn wconfig(&self) -> Config {
-        Config::new(self.flag_output.as_ref())
+        let mut wconfig = Config::new(self.flag_output.as_ref());
+        if let Some(quote_style) = self
+            .flag_quote_style
+            .as_deref()
+            .and_then(|style| parse_quote_style(style).ok())
+        {
+            wconfig = wconfig.quote_style(quote_style);
+        }
+        if self.flag_crlf {
+            wconfig = wconfig.crlf(true);
+        }
+        wconfig
     }
 }
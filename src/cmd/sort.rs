@@ -3,18 +3,39 @@ Sorts CSV data in lexicographical, natural, numerical, reverse, unique or random
 
 Note that this requires reading all of the CSV data into memory. If
 you need to sort a large file that may not fit into memory, use the
-extsort command instead.
+--external option or the extsort command instead.
 
 For examples, see https://github.com/dathere/qsv/blob/master/tests/test_sort.rs.
 
 Usage:
-    qsv sort [options] [<input>]
+    qsv sort [options] [<input>...]
     qsv sort --help
 
 sort options:
     -s, --select <arg>      Select a subset of columns to sort.
                             See 'qsv select --help' for the format details.
+    --keys <spec>           Comma-separated list of sort keys, giving each key
+                            column its own comparison type and direction, e.g.
+                            "2:num:desc,1:str:asc". Each key is
+                            <column>[:<type>][:<direction>], where <column> is a
+                            1-based column index or a column name, <type> is str,
+                            num or natural (default: str) and <direction> is asc
+                            or desc (default: asc). Keys are compared in the
+                            order given. When set, --keys supersedes --select,
+                            --numeric, --natural & --reverse. --ignore-case
+                            applies to str & natural keys and --decimal-comma
+                            to num keys. Cannot be used with --merge, --random
+                            or --external.
+    --merge                 Treat the inputs as individually pre-sorted files and
+                            perform a streaming k-way merge on the sort key,
+                            without re-sorting, respecting --select, --numeric,
+                            --natural, --reverse, --ignore-case & --unique.
+                            Errors if an input turns out not to be sorted.
     -N, --numeric           Compare according to string numerical value
+    --decimal-comma         When used with --numeric, parse numbers with a comma
+                            as the decimal separator and "." or space as optional
+                            thousands separators (e.g. "1.234,56"), so localized
+                            numeric data sorts correctly.
     --natural               Compare strings using natural sort order
                             (treats numbers within strings as actual numbers, e.g.
                             "data1.txt", "data2.txt", "data10.txt", as opposed to
@@ -25,6 +46,27 @@ sort options:
     -i, --ignore-case       Compare strings disregarding case
     -u, --unique            When set, identical consecutive lines will be dropped
                             to keep only one line per sorted value.
+    --duplicates-output <file>
+                            Write the duplicate rows dropped by --unique (the
+                            2nd+ occurrence per sort key) to <file>, so the
+                            removed rows can be audited. The union of the deduped
+                            output and this file is the input. Requires --unique.
+    --with-row-index        Append a "row_index" column carrying each row's
+                            original 0-based position in the input, so the sorted
+                            output can be joined back to the source or restored
+                            to the original order. Cannot be used with --merge.
+    --external              Sort in bounded memory by writing sorted runs to temp
+                            files and k-way merging them, so inputs larger than
+                            memory can be sorted. Respects --select, --numeric,
+                            --natural, --reverse, --ignore-case & --unique, and
+                            produces the same output as the in-memory sort.
+                            Cannot be used with --merge, --random or
+                            --with-row-index.
+    --mem-limit <mb>        The approximate amount of row data, in megabytes, to
+                            buffer in memory before spilling a sorted run to a
+                            temp file when --external is set. Set to 0 to spill
+                            a run per row (only useful for testing).
+                            [default: 500]
 
                             RANDOM SORTING OPTIONS:
     --random                Randomize (scramble) the data by row
@@ -49,9 +91,15 @@ sort options:
                             (i.e. the order of identical values is not guaranteed
                             to be preserved). It has the added side benefit that the
                             sort will also be in-place (i.e. does not allocate),
-                            which is useful for sorting large files that will 
+                            which is useful for sorting large files that will
                             otherwise NOT fit in memory using the default allocating
                             stable sort.
+    --stable                Guarantee that rows comparing equal on the sort key
+                            keep their original input order. The default sort is
+                            already stable, so this matters when combined with
+                            --faster: --faster --stable falls back to the stable
+                            allocating sort algorithm instead of the faster
+                            unstable one.
 
 Common options:
     -h, --help              Display this message
@@ -62,6 +110,12 @@ Common options:
                             appear as the header row in the output.
     -d, --delimiter <arg>   The field delimiter for reading CSV data.
                             Must be a single character. (default: ,)
+    --quote-style <arg>     The quote style to use when writing CSV output:
+                              necessary   - quote fields only when necessary (default)
+                              always      - quote all fields
+                              non-numeric - quote all non-numeric fields
+                              never       - never quote fields
+                            Overrides the QSV_DEFAULT_QUOTE_STYLE envvar.
     --memcheck              Check if there is enough memory to load the entire
                             CSV into memory using CONSERVATIVE heuristics.
                             Ignored if --random or --faster is set.
@@ -82,29 +136,38 @@ use self::Number::{Float, Int};
 use crate::{
     CliResult,
     cmd::dedup::iter_cmp_ignore_case,
-    config::{Config, Delimiter},
-    select::SelectColumns,
+    config::{Config, Delimiter, parse_quote_style},
+    select::{SelectColumns, Selection},
     util,
 };
 
 #[derive(Deserialize)]
 struct Args {
-    arg_input:        Option<String>,
-    flag_select:      SelectColumns,
-    flag_numeric:     bool,
-    flag_natural:     bool,
-    flag_reverse:     bool,
-    flag_ignore_case: bool,
-    flag_unique:      bool,
-    flag_random:      bool,
-    flag_seed:        Option<u64>,
-    flag_rng:         String,
-    flag_jobs:        Option<usize>,
-    flag_faster:      bool,
-    flag_output:      Option<String>,
-    flag_no_headers:  bool,
-    flag_delimiter:   Option<Delimiter>,
-    flag_memcheck:    bool,
+    arg_input:              Vec<String>,
+    flag_select:            SelectColumns,
+    flag_keys:              Option<String>,
+    flag_merge:             bool,
+    flag_numeric:           bool,
+    flag_decimal_comma:     bool,
+    flag_natural:           bool,
+    flag_reverse:           bool,
+    flag_ignore_case:       bool,
+    flag_unique:            bool,
+    flag_duplicates_output: Option<String>,
+    flag_with_row_index:    bool,
+    flag_external:          bool,
+    flag_mem_limit:         u64,
+    flag_random:            bool,
+    flag_seed:              Option<u64>,
+    flag_rng:               String,
+    flag_jobs:              Option<usize>,
+    flag_faster:            bool,
+    flag_stable:            bool,
+    flag_output:            Option<String>,
+    flag_no_headers:        bool,
+    flag_delimiter:         Option<Delimiter>,
+    flag_quote_style:       Option<String>,
+    flag_memcheck:          bool,
 }
 
 #[derive(Debug, EnumString, PartialEq)]
@@ -118,11 +181,72 @@ enum RngKind {
 pub fn run(argv: &[&str]) -> CliResult<()> {
     let args: Args = util::get_args(USAGE, argv)?;
     let numeric = args.flag_numeric;
+    let decimal_comma = args.flag_decimal_comma;
     let natural = args.flag_natural;
     let reverse = args.flag_reverse;
     let random = args.flag_random;
-    let faster = args.flag_faster;
-    let rconfig = Config::new(args.arg_input.as_ref())
+    // --stable overrides --faster, as the unstable algorithm cannot guarantee
+    // the input order of equal-key rows
+    let faster = args.flag_faster && !args.flag_stable;
+
+    if decimal_comma && !numeric && args.flag_keys.is_none() {
+        return fail_incorrectusage_clierror!("--decimal-comma requires --numeric.");
+    }
+    if args.flag_duplicates_output.is_some() && !args.flag_unique {
+        return fail_incorrectusage_clierror!("--duplicates-output requires --unique.");
+    }
+    // validate --quote-style upfront; wconfig() reparses it infallibly
+    if let Some(ref style) = args.flag_quote_style {
+        parse_quote_style(style)?;
+    }
+
+    if let Some(keys_spec) = &args.flag_keys {
+        if args.flag_merge {
+            return fail_incorrectusage_clierror!("--keys cannot be used with --merge.");
+        }
+        if random {
+            return fail_incorrectusage_clierror!("--keys cannot be used with --random.");
+        }
+        if args.flag_external {
+            return fail_incorrectusage_clierror!("--keys cannot be used with --external.");
+        }
+        if args.arg_input.len() > 1 {
+            return fail_incorrectusage_clierror!(
+                "Multiple input files are only supported with --merge."
+            );
+        }
+        return sort_by_keys(&args, keys_spec);
+    }
+
+    if args.flag_external {
+        if args.flag_merge {
+            return fail_incorrectusage_clierror!("--external cannot be used with --merge.");
+        }
+        if random {
+            return fail_incorrectusage_clierror!("--external cannot be used with --random.");
+        }
+        if args.flag_with_row_index {
+            return fail_incorrectusage_clierror!(
+                "--external cannot be used with --with-row-index."
+            );
+        }
+        if args.arg_input.len() > 1 {
+            return fail_incorrectusage_clierror!(
+                "Multiple input files are only supported with --merge."
+            );
+        }
+        return external_sort(&args);
+    }
+    if args.flag_merge {
+        return merge_sorted_inputs(&args);
+    }
+    if args.arg_input.len() > 1 {
+        return fail_incorrectusage_clierror!(
+            "Multiple input files are only supported with --merge."
+        );
+    }
+
+    let rconfig = Config::new(args.arg_input.first())
         .delimiter(args.flag_delimiter)
         .no_headers(args.flag_no_headers)
         .select(args.flag_select);
@@ -156,6 +280,13 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
     let ignore_case = args.flag_ignore_case;
 
     let mut all = rdr.byte_records().collect::<Result<Vec<_>, _>>()?;
+    if args.flag_with_row_index {
+        // append rather than prepend so the --select indices stay valid
+        let mut itoa_buf = itoa::Buffer::new();
+        for (row_index, record) in all.iter_mut().enumerate() {
+            record.push_field(itoa_buf.format(row_index).as_bytes());
+        }
+    }
     // Tuple ordering and boolean flag meanings:
     // numeric: Sort numerically
     // natural: Sort in natural order https://en.wikipedia.org/wiki/Natural_sort_order
@@ -250,13 +381,21 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
         (true, false, false, false, false) => all.par_sort_by(|r1, r2| {
             let a = sel.select(r1);
             let b = sel.select(r2);
-            iter_cmp_num(a, b)
+            if decimal_comma {
+                iter_cmp_num_comma(a, b)
+            } else {
+                iter_cmp_num(a, b)
+            }
         }),
         // --numeric --faster unstable, non-allocating, parallel numeric sort
         (true, false, false, false, true) => all.par_sort_unstable_by(|r1, r2| {
             let a = sel.select(r1);
             let b = sel.select(r2);
-            iter_cmp_num(a, b)
+            if decimal_comma {
+                iter_cmp_num_comma(a, b)
+            } else {
+                iter_cmp_num(a, b)
+            }
         }),
 
         // --reverse stable parallel sort
@@ -305,13 +444,21 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
         (true, false, true, false, false) => all.par_sort_by(|r1, r2| {
             let a = sel.select(r1);
             let b = sel.select(r2);
-            iter_cmp_num(b, a)
+            if decimal_comma {
+                iter_cmp_num_comma(b, a)
+            } else {
+                iter_cmp_num(b, a)
+            }
         }),
         // --numeric --reverse --faster unstable sort
         (true, false, true, false, true) => all.par_sort_unstable_by(|r1, r2| {
             let a = sel.select(r1);
             let b = sel.select(r2);
-            iter_cmp_num(b, a)
+            if decimal_comma {
+                iter_cmp_num_comma(b, a)
+            } else {
+                iter_cmp_num(b, a)
+            }
         }),
 
         // --numeric --natural stable sort (natural takes precedence over numeric)
@@ -357,15 +504,41 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
         }),
     }
 
-    let mut wtr = Config::new(args.flag_output.as_ref()).writer()?;
+    let mut wtr = wconfig(args).writer()?;
     let mut prev: Option<csv::ByteRecord> = None;
-    rconfig.write_headers(&mut rdr, &mut wtr)?;
+    if args.flag_with_row_index && !rconfig.no_headers {
+        let mut indexed_headers = headers.clone();
+        indexed_headers.push_field(b"row_index");
+        wtr.write_byte_record(&indexed_headers)?;
+    } else {
+        rconfig.write_headers(&mut rdr, &mut wtr)?;
+    }
+    let mut dup_wtr = match args.flag_duplicates_output {
+        Some(ref dup_output) => Some(Config::new(Some(dup_output)).writer()?),
+        None => None,
+    };
+    if let Some(ref mut dup_wtr) = dup_wtr
+        && !rconfig.no_headers
+    {
+        // the duplicates file carries the same header row as the main output
+        if args.flag_with_row_index {
+            let mut indexed_headers = headers.clone();
+            indexed_headers.push_field(b"row_index");
+            dup_wtr.write_byte_record(&indexed_headers)?;
+        } else {
+            dup_wtr.write_byte_record(&headers)?;
+        }
+    }
     if args.flag_unique {
         for r in all {
             match prev {
                 Some(other_r) => {
                     let comparison = if numeric {
-                        iter_cmp_num(sel.select(&r), sel.select(&other_r))
+                        if decimal_comma {
+                            iter_cmp_num_comma(sel.select(&r), sel.select(&other_r))
+                        } else {
+                            iter_cmp_num(sel.select(&r), sel.select(&other_r))
+                        }
                     } else if natural {
                         if ignore_case {
                             iter_cmp_natural_ignore_case(sel.select(&r), sel.select(&other_r))
@@ -378,7 +551,11 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
                         iter_cmp(sel.select(&r), sel.select(&other_r))
                     };
                     match comparison {
-                        cmp::Ordering::Equal => (),
+                        cmp::Ordering::Equal => {
+                            if let Some(ref mut dup_wtr) = dup_wtr {
+                                dup_wtr.write_byte_record(&r)?;
+                            }
+                        },
                         _ => {
                             wtr.write_byte_record(&r)?;
                         },
@@ -395,9 +572,591 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
             wtr.write_byte_record(&r)?;
         }
     }
+    if let Some(ref mut dup_wtr) = dup_wtr {
+        dup_wtr.flush()?;
+    }
+    Ok(wtr.flush()?)
+}
+
+/// k-way merge individually pre-sorted inputs into one sorted stream,
+/// comparing records on the sort key per the sort option flags.
+/// Errors if an input turns out not to be sorted on the key.
+fn merge_sorted_inputs(args: &Args) -> CliResult<()> {
+    if args.arg_input.is_empty() {
+        return fail_incorrectusage_clierror!("--merge requires at least one input file.");
+    }
+    if args.flag_random {
+        return fail_incorrectusage_clierror!("--merge cannot be used with --random.");
+    }
+    if args.flag_with_row_index {
+        return fail_incorrectusage_clierror!("--merge cannot be used with --with-row-index.");
+    }
+
+    let numeric = args.flag_numeric;
+    let decimal_comma = args.flag_decimal_comma;
+    let natural = args.flag_natural;
+    let reverse = args.flag_reverse;
+    let ignore_case = args.flag_ignore_case;
+
+    // compare two records on their selected sort keys, respecting the
+    // --numeric, --natural, --ignore-case and --reverse flags
+    let key_cmp = |a: &csv::ByteRecord, sel_a: &Selection, b: &csv::ByteRecord, sel_b: &Selection| {
+        let x = sel_a.select(a);
+        let y = sel_b.select(b);
+        let ord = if numeric {
+            if decimal_comma {
+                iter_cmp_num_comma(x, y)
+            } else {
+                iter_cmp_num(x, y)
+            }
+        } else if natural {
+            if ignore_case {
+                iter_cmp_natural_ignore_case(x, y)
+            } else {
+                iter_cmp_natural(x, y)
+            }
+        } else if ignore_case {
+            iter_cmp_ignore_case(x, y)
+        } else {
+            iter_cmp(x, y)
+        };
+        if reverse { ord.reverse() } else { ord }
+    };
+
+    // open a reader per input, resolving the sort key selection against
+    // each input's own headers
+    let mut readers = Vec::with_capacity(args.arg_input.len());
+    let mut selections = Vec::with_capacity(args.arg_input.len());
+    let mut currents: Vec<Option<csv::ByteRecord>> = Vec::with_capacity(args.arg_input.len());
+    let mut first_headers: Option<csv::ByteRecord> = None;
+
+    for input in &args.arg_input {
+        let rconfig = Config::new(Some(input))
+            .delimiter(args.flag_delimiter)
+            .no_headers(args.flag_no_headers)
+            .select(args.flag_select.clone());
+        let mut rdr = rconfig.reader()?;
+        let headers = rdr.byte_headers()?.clone();
+        let sel = rconfig.selection(&headers)?;
+        if first_headers.is_none() {
+            first_headers = Some(headers);
+        }
+
+        let mut record = csv::ByteRecord::new();
+        let current = if rdr.read_byte_record(&mut record)? {
+            Some(record)
+        } else {
+            None
+        };
+        readers.push(rdr);
+        selections.push(sel);
+        currents.push(current);
+    }
+
+    let mut wtr = wconfig(args).writer()?;
+    if !args.flag_no_headers
+        && let Some(ref headers) = first_headers
+    {
+        wtr.write_byte_record(headers)?;
+    }
+    let mut dup_wtr = match args.flag_duplicates_output {
+        Some(ref dup_output) => Some(Config::new(Some(dup_output)).writer()?),
+        None => None,
+    };
+    if let Some(ref mut dup_wtr) = dup_wtr
+        && !args.flag_no_headers
+        && let Some(ref headers) = first_headers
+    {
+        dup_wtr.write_byte_record(headers)?;
+    }
+
+    // the last record written, along with the index of the input it came
+    // from, so --unique compares it with that input's own selection
+    let mut prev: Option<(csv::ByteRecord, usize)> = None;
+    loop {
+        // pick the input whose current record sorts first.
+        // the number of inputs is small, so a linear scan beats a heap
+        let mut min_idx: Option<usize> = None;
+        for (i, current) in currents.iter().enumerate() {
+            if let Some(record) = current
+                && match min_idx {
+                    Some(j) => {
+                        // safety: currents[j] is Some, as min_idx only tracks Some entries
+                        key_cmp(
+                            record,
+                            &selections[i],
+                            currents[j].as_ref().unwrap(),
+                            &selections[j],
+                        ) == cmp::Ordering::Less
+                    },
+                    None => true,
+                }
+            {
+                min_idx = Some(i);
+            }
+        }
+        let Some(i) = min_idx else {
+            break; // all inputs exhausted
+        };
+
+        // safety: currents[i] is Some, as min_idx only tracks Some entries
+        let record = currents[i].take().unwrap();
+        let write_record = if args.flag_unique {
+            prev.as_ref().is_none_or(|(prev_record, prev_idx)| {
+                key_cmp(&record, &selections[i], prev_record, &selections[*prev_idx])
+                    != cmp::Ordering::Equal
+            })
+        } else {
+            true
+        };
+        if write_record {
+            wtr.write_byte_record(&record)?;
+        } else if let Some(ref mut dup_wtr) = dup_wtr {
+            dup_wtr.write_byte_record(&record)?;
+        }
+
+        // advance the reader we just consumed from, verifying it is sorted
+        let mut next_record = csv::ByteRecord::new();
+        if readers[i].read_byte_record(&mut next_record)? {
+            if key_cmp(&next_record, &selections[i], &record, &selections[i])
+                == cmp::Ordering::Less
+            {
+                return fail_clierror!(
+                    "Input file \"{}\" is not sorted on the sort key.",
+                    args.arg_input[i]
+                );
+            }
+            currents[i] = Some(next_record);
+        }
+        prev = Some((record, i));
+    }
+
+    if let Some(ref mut dup_wtr) = dup_wtr {
+        dup_wtr.flush()?;
+    }
     Ok(wtr.flush()?)
 }
 
+/// the output writer config, applying --quote-style when set
+fn wconfig(args: &Args) -> Config {
+    let mut wconfig = Config::new(args.flag_output.as_ref());
+    if let Some(quote_style) = args
+        .flag_quote_style
+        .as_deref()
+        .and_then(|style| parse_quote_style(style).ok())
+    {
+        wconfig = wconfig.quote_style(quote_style);
+    }
+    wconfig
+}
+
+/// a single --keys sort key: the 0-based column index to compare on, how to
+/// compare it, and the direction
+struct SortKey {
+    index:      usize,
+    key_type:   SortKeyType,
+    descending: bool,
+}
+
+#[derive(Clone, Copy)]
+enum SortKeyType {
+    Str,
+    Num,
+    Natural,
+}
+
+/// parse a --keys spec like "2:num:desc,1:str:asc" into sort keys.
+/// Each key is <column>[:<type>][:<direction>], where <column> is a 1-based
+/// column index or a column name, <type> is str, num or natural and
+/// <direction> is asc or desc
+fn parse_sort_keys(
+    spec: &str,
+    headers: &csv::ByteRecord,
+    no_headers: bool,
+) -> CliResult<Vec<SortKey>> {
+    let mut keys = Vec::new();
+    for key_spec in spec.split(',') {
+        let mut parts = key_spec.split(':');
+        let Some(column) = parts.next().map(str::trim).filter(|column| !column.is_empty())
+        else {
+            return fail_incorrectusage_clierror!("Empty --keys key in \"{spec}\".");
+        };
+        let index = if let Ok(column_number) = column.parse::<usize>() {
+            if column_number == 0 || column_number > headers.len() {
+                return fail_incorrectusage_clierror!(
+                    "--keys column {column_number} is out of range: the input only has {} \
+                     columns.",
+                    headers.len()
+                );
+            }
+            column_number - 1
+        } else if no_headers {
+            return fail_incorrectusage_clierror!(
+                "--keys column \"{column}\" must be a 1-based column index with --no-headers."
+            );
+        } else {
+            match headers.iter().position(|header| header == column.as_bytes()) {
+                Some(index) => index,
+                None => {
+                    return fail_incorrectusage_clierror!("--keys column \"{column}\" not found.");
+                },
+            }
+        };
+        let mut key_type = SortKeyType::Str;
+        let mut descending = false;
+        for modifier in parts {
+            match modifier.trim().to_ascii_lowercase().as_str() {
+                "str" | "string" => key_type = SortKeyType::Str,
+                "num" | "numeric" => key_type = SortKeyType::Num,
+                "natural" => key_type = SortKeyType::Natural,
+                "asc" => descending = false,
+                "desc" => descending = true,
+                modifier => {
+                    return fail_incorrectusage_clierror!(
+                        "Invalid --keys modifier \"{modifier}\" in \"{key_spec}\". Valid \
+                         modifiers are: str, num, natural, asc & desc."
+                    );
+                },
+            }
+        }
+        keys.push(SortKey {
+            index,
+            key_type,
+            descending,
+        });
+    }
+    Ok(keys)
+}
+
+/// compare two records per the parsed --keys sort keys, applied in order
+fn sort_keys_cmp(
+    keys: &[SortKey],
+    a: &csv::ByteRecord,
+    b: &csv::ByteRecord,
+    ignore_case: bool,
+    decimal_comma: bool,
+) -> cmp::Ordering {
+    for key in keys {
+        let x = a.get(key.index).unwrap_or(b"");
+        let y = b.get(key.index).unwrap_or(b"");
+        let ord = match key.key_type {
+            SortKeyType::Str => {
+                if ignore_case {
+                    iter_cmp_ignore_case(std::iter::once(x), std::iter::once(y))
+                } else {
+                    x.cmp(y)
+                }
+            },
+            SortKeyType::Num => {
+                if decimal_comma {
+                    iter_cmp_num_comma(std::iter::once(x), std::iter::once(y))
+                } else {
+                    iter_cmp_num(std::iter::once(x), std::iter::once(y))
+                }
+            },
+            SortKeyType::Natural => {
+                if ignore_case {
+                    compare_natural_strings_ignore_case(x, y)
+                } else {
+                    compare_natural_strings(x, y)
+                }
+            },
+        };
+        let ord = if key.descending { ord.reverse() } else { ord };
+        if ord != cmp::Ordering::Equal {
+            return ord;
+        }
+    }
+    cmp::Ordering::Equal
+}
+
+/// sort on a --keys spec, giving each key column its own comparison type and
+/// direction. Supersedes --select, --numeric, --natural & --reverse
+fn sort_by_keys(args: &Args, keys_spec: &str) -> CliResult<()> {
+    let ignore_case = args.flag_ignore_case;
+    let decimal_comma = args.flag_decimal_comma;
+
+    let rconfig = Config::new(args.arg_input.first())
+        .delimiter(args.flag_delimiter)
+        .no_headers(args.flag_no_headers);
+
+    // we're loading the entire file into memory, we need to check avail memory
+    if let Some(path) = rconfig.path.clone()
+        && !args.flag_faster
+    {
+        util::mem_file_check(&path, false, args.flag_memcheck)?;
+    }
+
+    let mut rdr = rconfig.reader()?;
+    let headers = rdr.byte_headers()?.clone();
+    let keys = parse_sort_keys(keys_spec, &headers, rconfig.no_headers)?;
+
+    util::njobs(args.flag_jobs);
+
+    let mut all = rdr.byte_records().collect::<Result<Vec<_>, _>>()?;
+    if args.flag_with_row_index {
+        // append rather than prepend so the --keys indices stay valid
+        let mut itoa_buf = itoa::Buffer::new();
+        for (row_index, record) in all.iter_mut().enumerate() {
+            record.push_field(itoa_buf.format(row_index).as_bytes());
+        }
+    }
+
+    if args.flag_faster && !args.flag_stable {
+        all.par_sort_unstable_by(|r1, r2| {
+            sort_keys_cmp(&keys, r1, r2, ignore_case, decimal_comma)
+        });
+    } else {
+        all.par_sort_by(|r1, r2| sort_keys_cmp(&keys, r1, r2, ignore_case, decimal_comma));
+    }
+
+    let mut wtr = wconfig(args).writer()?;
+    if args.flag_with_row_index && !rconfig.no_headers {
+        let mut indexed_headers = headers.clone();
+        indexed_headers.push_field(b"row_index");
+        wtr.write_byte_record(&indexed_headers)?;
+    } else {
+        rconfig.write_headers(&mut rdr, &mut wtr)?;
+    }
+    let mut dup_wtr = match args.flag_duplicates_output {
+        Some(ref dup_output) => Some(Config::new(Some(dup_output)).writer()?),
+        None => None,
+    };
+    if let Some(ref mut dup_wtr) = dup_wtr
+        && !rconfig.no_headers
+    {
+        if args.flag_with_row_index {
+            let mut indexed_headers = headers.clone();
+            indexed_headers.push_field(b"row_index");
+            dup_wtr.write_byte_record(&indexed_headers)?;
+        } else {
+            dup_wtr.write_byte_record(&headers)?;
+        }
+    }
+
+    let mut prev: Option<csv::ByteRecord> = None;
+    for r in all {
+        let write_record = if args.flag_unique {
+            prev.as_ref().is_none_or(|prev_record| {
+                sort_keys_cmp(&keys, &r, prev_record, ignore_case, decimal_comma)
+                    != cmp::Ordering::Equal
+            })
+        } else {
+            true
+        };
+        if write_record {
+            wtr.write_byte_record(&r)?;
+        } else if let Some(ref mut dup_wtr) = dup_wtr {
+            dup_wtr.write_byte_record(&r)?;
+        }
+        prev = Some(r);
+    }
+    if let Some(ref mut dup_wtr) = dup_wtr {
+        dup_wtr.flush()?;
+    }
+    Ok(wtr.flush()?)
+}
+
+/// external merge sort: sort the input in bounded memory by sorting
+/// --mem-limit sized runs in memory, spilling each sorted run to a temp file,
+/// and k-way merging the runs into the output, comparing records on the sort
+/// key per the sort option flags. Produces the same output as the in-memory
+/// stable sort. The runs live in a TempDir, so they are removed on completion
+/// and on error alike.
+fn external_sort(args: &Args) -> CliResult<()> {
+    let numeric = args.flag_numeric;
+    let decimal_comma = args.flag_decimal_comma;
+    let natural = args.flag_natural;
+    let reverse = args.flag_reverse;
+    let ignore_case = args.flag_ignore_case;
+    let flag_unique = args.flag_unique;
+
+    let rconfig = Config::new(args.arg_input.first())
+        .delimiter(args.flag_delimiter)
+        .no_headers(args.flag_no_headers)
+        .select(args.flag_select.clone());
+
+    let mut rdr = rconfig.reader()?;
+    let headers = rdr.byte_headers()?.clone();
+    let sel = rconfig.selection(&headers)?;
+
+    // compare two records on their selected sort keys, respecting the
+    // --numeric, --natural, --ignore-case and --reverse flags
+    let record_cmp = |a: &csv::ByteRecord, b: &csv::ByteRecord| {
+        let x = sel.select(a);
+        let y = sel.select(b);
+        let ord = if numeric {
+            if decimal_comma {
+                iter_cmp_num_comma(x, y)
+            } else {
+                iter_cmp_num(x, y)
+            }
+        } else if natural {
+            if ignore_case {
+                iter_cmp_natural_ignore_case(x, y)
+            } else {
+                iter_cmp_natural(x, y)
+            }
+        } else if ignore_case {
+            iter_cmp_ignore_case(x, y)
+        } else {
+            iter_cmp(x, y)
+        };
+        if reverse { ord.reverse() } else { ord }
+    };
+
+    let mem_limit_bytes = args
+        .flag_mem_limit
+        .saturating_mul(1024 * 1024)
+        .max(1) as usize;
+
+    // read the input into --mem-limit sized runs, sorting each run in memory
+    // and spilling it to a temp file in run order
+    let tmp_dir = tempfile::tempdir()?;
+    let mut run_paths: Vec<std::path::PathBuf> = Vec::new();
+    let mut run: Vec<csv::ByteRecord> = Vec::new();
+    let mut run_bytes = 0_usize;
+    let mut record = csv::ByteRecord::new();
+
+    while rdr.read_byte_record(&mut record)? {
+        // rough per-record memory estimate: the field data plus the record's
+        // field index entries
+        run_bytes += record.as_slice().len() + 8 * record.len();
+        run.push(std::mem::take(&mut record));
+        if run_bytes >= mem_limit_bytes {
+            run.sort_by(|r1, r2| record_cmp(r1, r2));
+            let run_path = tmp_dir.path().join(format!("run-{}.csv", run_paths.len()));
+            let mut run_wtr = csv::WriterBuilder::new().flexible(true).from_path(&run_path)?;
+            for r in &run {
+                run_wtr.write_byte_record(r)?;
+            }
+            run_wtr.flush()?;
+            run_paths.push(run_path);
+            run.clear();
+            run_bytes = 0;
+        }
+    }
+    run.sort_by(|r1, r2| record_cmp(r1, r2));
+
+    let mut wtr = wconfig(args).writer()?;
+    rconfig.write_headers(&mut rdr, &mut wtr)?;
+    let mut dup_wtr = match args.flag_duplicates_output {
+        Some(ref dup_output) => Some(Config::new(Some(dup_output)).writer()?),
+        None => None,
+    };
+    if let Some(ref mut dup_wtr) = dup_wtr
+        && !rconfig.no_headers
+    {
+        dup_wtr.write_byte_record(&headers)?;
+    }
+    let mut prev: Option<csv::ByteRecord> = None;
+
+    if run_paths.is_empty() {
+        // the input fit in one run, so no temp files are needed
+        for r in run {
+            let write_record = if flag_unique {
+                prev.as_ref()
+                    .is_none_or(|prev_record| record_cmp(&r, prev_record) != cmp::Ordering::Equal)
+            } else {
+                true
+            };
+            if write_record {
+                wtr.write_byte_record(&r)?;
+            } else if let Some(ref mut dup_wtr) = dup_wtr {
+                dup_wtr.write_byte_record(&r)?;
+            }
+            prev = Some(r);
+        }
+        if let Some(ref mut dup_wtr) = dup_wtr {
+            dup_wtr.flush()?;
+        }
+        return Ok(wtr.flush()?);
+    }
+
+    // spill the final partial run so the merge below handles all records
+    if !run.is_empty() {
+        let run_path = tmp_dir.path().join(format!("run-{}.csv", run_paths.len()));
+        let mut run_wtr = csv::WriterBuilder::new().flexible(true).from_path(&run_path)?;
+        for r in &run {
+            run_wtr.write_byte_record(r)?;
+        }
+        run_wtr.flush()?;
+        run_paths.push(run_path);
+        run.clear();
+    }
+
+    // k-way merge the sorted runs. Runs are scanned in creation order and a
+    // tie goes to the earliest run, so the merge is stable and the output
+    // matches the in-memory stable sort byte for byte
+    let mut run_rdrs = Vec::with_capacity(run_paths.len());
+    let mut currents: Vec<Option<csv::ByteRecord>> = Vec::with_capacity(run_paths.len());
+    for run_path in &run_paths {
+        let mut run_rdr = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_path(run_path)?;
+        let mut record = csv::ByteRecord::new();
+        let current = if run_rdr.read_byte_record(&mut record)? {
+            Some(record)
+        } else {
+            None
+        };
+        run_rdrs.push(run_rdr);
+        currents.push(current);
+    }
+
+    loop {
+        // pick the run whose current record sorts first.
+        // the number of runs is small, so a linear scan beats a heap
+        let mut min_idx: Option<usize> = None;
+        for (i, current) in currents.iter().enumerate() {
+            if let Some(record) = current
+                && match min_idx {
+                    // safety: currents[j] is Some, as min_idx only tracks Some entries
+                    Some(j) => {
+                        record_cmp(record, currents[j].as_ref().unwrap()) == cmp::Ordering::Less
+                    },
+                    None => true,
+                }
+            {
+                min_idx = Some(i);
+            }
+        }
+        let Some(i) = min_idx else {
+            break; // all runs exhausted
+        };
+
+        // safety: currents[i] is Some, as min_idx only tracks Some entries
+        let record = currents[i].take().unwrap();
+        let mut next_record = csv::ByteRecord::new();
+        if run_rdrs[i].read_byte_record(&mut next_record)? {
+            currents[i] = Some(next_record);
+        }
+
+        let write_record = if flag_unique {
+            prev.as_ref()
+                .is_none_or(|prev_record| record_cmp(&record, prev_record) != cmp::Ordering::Equal)
+        } else {
+            true
+        };
+        if write_record {
+            wtr.write_byte_record(&record)?;
+        } else if let Some(ref mut dup_wtr) = dup_wtr {
+            dup_wtr.write_byte_record(&record)?;
+        }
+        prev = Some(record);
+    }
+
+    if let Some(ref mut dup_wtr) = dup_wtr {
+        dup_wtr.flush()?;
+    }
+    wtr.flush()?;
+    // remove the spilled runs now rather than relying on TempDir's
+    // best-effort drop cleanup
+    tmp_dir.close()?;
+    Ok(())
+}
+
 /// Order `a` and `b` lexicographically using `Ord`
 #[inline]
 pub fn iter_cmp<A, L, R>(mut a: L, mut b: R) -> cmp::Ordering
@@ -533,6 +1292,57 @@ where
     }
 }
 
+/// Try parsing `a` and `b` as comma-decimal localized numbers when ordering,
+/// for --numeric --decimal-comma
+#[inline]
+pub fn iter_cmp_num_comma<'a, L, R>(mut a: L, mut b: R) -> cmp::Ordering
+where
+    L: Iterator<Item = &'a [u8]>,
+    R: Iterator<Item = &'a [u8]>,
+{
+    loop {
+        match (next_num_comma(&mut a), next_num_comma(&mut b)) {
+            (None, None) => return cmp::Ordering::Equal,
+            (None, _) => return cmp::Ordering::Less,
+            (_, None) => return cmp::Ordering::Greater,
+            (Some(x), Some(y)) => match compare_num(x, y) {
+                cmp::Ordering::Equal => (),
+                non_eq => return non_eq,
+            },
+        }
+    }
+}
+
+/// --decimal-comma: parse a comma-decimal localized number, treating "." and
+/// spaces as thousands separators (e.g. "1.234,56" parses as 1234.56)
+#[inline]
+fn next_num_comma<'a, X>(xs: &mut X) -> Option<Number>
+where
+    X: Iterator<Item = &'a [u8]>,
+{
+    match xs.next() {
+        Some(bytes) => {
+            // integers without separators parse as-is
+            if let Ok(i) = atoi_simd::parse::<i64>(bytes) {
+                return Some(Number::Int(i));
+            }
+            let Ok(s) = from_utf8(bytes) else {
+                return None;
+            };
+            let mut normalized = String::with_capacity(s.len());
+            for c in s.chars() {
+                match c {
+                    ',' => normalized.push('.'),
+                    '.' | ' ' | '\u{a0}' => (),
+                    _ => normalized.push(c),
+                }
+            }
+            normalized.parse::<f64>().ok().map(Number::Float)
+        },
+        None => None,
+    }
+}
+
 #[inline]
 fn compare_natural_strings(a: &[u8], b: &[u8]) -> cmp::Ordering {
     compare_natural_bytes(a, b, false)
@@ -17,8 +17,9 @@ When splitting by kb-size, the CSV data is split into chunks of the given size i
 The number of rows in each chunk may vary, but the size of each chunk will not exceed the
 desired size.
 
-Uses multithreading to go faster if the CSV has an index when splitting by size or
-by number of chunks. Splitting by kb-size is always done sequentially with a single thread.
+Uses multithreading to go faster if the CSV has an index. When splitting by kb-size,
+the chunk boundaries are first computed with a sequential planning scan (so they are
+identical to the single-threaded algorithm's), then the chunks are written concurrently.
 
 The default is to split by size with a chunk size of 500.
 
@@ -71,17 +72,46 @@ split arguments:
 split options:
     -s, --size <arg>       The number of records to write into each chunk.
                            [default: 500]
+    --precount             When splitting by --chunks, perform an explicit initial
+                           row-count pass (reported to stderr unless --quiet) before
+                           splitting, so the chunk division is exact even for data
+                           streamed on stdin - which is first written to a temporary
+                           file. Note that this reads the input twice.
+                           Only valid with --chunks.
     -c, --chunks <arg>     The number of chunks to split the data into.
                            This option is mutually exclusive with --size.
                            The number of rows in each chunk is determined by
                            the number of records in the CSV data and the number
                            of desired chunks. If the number of records is not evenly
-                           divisible by the number of chunks, the last chunk will
-                           have fewer records.
+                           divisible by the number of chunks, the remainder is
+                           distributed one extra row across the first chunks, so
+                           chunk sizes differ by at most one row.
     -k, --kb-size <arg>    The size of each chunk in kilobytes. The number of rows
                            in each chunk may vary, but the size of each chunk will
                            not exceed the desired size.
                            This option is mutually exclusive with --size and --chunks.
+    --max-rows <n>         When splitting by --kb-size, also force a chunk rollover
+                           once a chunk reaches <n> rows, even if the byte budget
+                           isn't exhausted - a dual size AND row-count constraint
+                           that keeps chunk row counts predictable when many small
+                           records share a chunk. Only valid with --kb-size.
+    --max-chunks <n>       Abort with an error instead of writing more than <n>
+                           chunks, leaving the chunks already written in place.
+                           A safety limit against accidentally creating a huge
+                           number of files (e.g. --size 1 on an unexpectedly
+                           large input). Applies to all three splitting modes.
+    --manifest <file>      Write a machine-readable JSON manifest of the created
+                           chunks to <file> after all chunks (and --filter
+                           commands) complete - a JSON array of
+                           {"file","start_row","rows","bytes"} objects, sorted
+                           by start row. Works in all three splitting modes.
+    --count                Don't split anything. Instead, print only the number
+                           of chunks that would be created to stdout, for
+                           scripting (e.g. setting up N downstream jobs). For
+                           --size and --chunks this is row-count arithmetic;
+                           for --kb-size the chunk boundaries are planned with
+                           a scan of the input. No files are written and the
+                           output directory is not created.
 
     -j, --jobs <arg>       The number of splitting jobs to run in parallel.
                            This only works when the given CSV data has
@@ -97,6 +127,30 @@ split options:
     --pad <arg>            The zero padding width that is used in the
                            generated filename.
                            [default: 0]
+    --rename-header <spec>  Rename columns in the header row written to each chunk.
+                            Data rows are unchanged. <spec> is a comma-delimited
+                            list of <from>=<to> pairs,
+                            e.g. --rename-header "old1=new1,old2=new2"
+                            All <from> columns must exist in the input headers.
+
+                            COMPRESSION OPTIONS:
+    --compress <algo>       Compress each chunk as it is written, using the
+                            specified algorithm - "gzip" or "zstd".
+                            The appropriate extension (.gz or .zst) is appended
+                            to each chunk's filename.
+    --compress-level <n>    The compression level to use with --compress.
+                            For gzip, valid levels are 0 (no compression) to 9.
+                            For zstd, valid levels are 1 to 22.
+                            When not set, the algorithm's default level is used
+                            (gzip: 6, zstd: 3).
+    --gzip                  Shorthand for --compress gzip. Writes each chunk
+                            directly as <name>.csv.gz with a streaming gzip
+                            encoder, avoiding the process-per-chunk overhead of
+                            --filter "gzip $FILE". Cannot be used with --compress.
+    --gzip-level <n>        Shorthand for --compress-level with --gzip.
+                            Valid levels are 0 (no compression) to 9.
+                            When not set, gzip's default level 6 is used.
+                            Only valid with --gzip.
 
                             FILTER OPTIONS:
     --filter <command>      Run the specified command on each chunk after it is written.
@@ -111,6 +165,14 @@ split options:
                             Only valid when --filter is used.
     --filter-ignore-errors  Ignore errors when running the filter command.
                             Only valid when --filter is used.
+    --filter-retries <n>    Retry a failing filter command up to <n> times before
+                            giving up (or ignoring the failure when
+                            --filter-ignore-errors is set). Useful when the filter
+                            command uploads chunks to a flaky network target.
+                            Only valid when --filter is used. [default: 0]
+    --filter-retry-delay <secs>  The number of seconds to wait between filter
+                            command retries. Only used when --filter-retries
+                            is set. [default: 1]
 
 Common options:
     -h, --help             Display this message
@@ -119,19 +181,26 @@ Common options:
                            appear in all chunks as the header row.
     -d, --delimiter <arg>  The field delimiter for reading CSV data.
                            Must be a single character. (default: ,)
+    --quote-style <arg>    The quote style to use when writing the chunks:
+                             necessary   - quote fields only when necessary (default)
+                             always      - quote all fields
+                             non-numeric - quote all non-numeric fields
+                             never       - never quote fields
+                           Overrides the QSV_DEFAULT_QUOTE_STYLE envvar.
     -q, --quiet            Do not display an output summary to stderr.
 "#;
 
 use std::{fs, io, path::Path, process::Command};
 
 use dunce;
+use flate2::{Compression, write::GzEncoder};
 use log::{debug, error};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     CliResult,
-    config::{Config, Delimiter},
+    config::{Config, Delimiter, parse_quote_style},
     index::Indexed,
     util::{self, FilenameTemplate},
 };
@@ -142,16 +211,38 @@ struct Args {
     arg_outdir:                String,
     flag_size:                 usize,
     flag_chunks:               Option<usize>,
+    flag_precount:             bool,
     flag_kb_size:              Option<usize>,
+    flag_max_rows:             Option<usize>,
+    flag_max_chunks:           Option<usize>,
+    flag_manifest:             Option<String>,
+    flag_count:                bool,
     flag_jobs:                 Option<usize>,
     flag_filename:             FilenameTemplate,
     flag_pad:                  usize,
+    flag_compress:             Option<String>,
+    flag_compress_level:       Option<u32>,
+    flag_gzip:                 bool,
+    flag_gzip_level:           Option<u32>,
+    flag_rename_header:        Option<String>,
     flag_no_headers:           bool,
     flag_delimiter:            Option<Delimiter>,
+    flag_quote_style:          Option<String>,
     flag_quiet:                bool,
     flag_filter:               Option<String>,
     flag_filter_cleanup:       bool,
     flag_filter_ignore_errors: bool,
+    flag_filter_retries:       u32,
+    flag_filter_retry_delay:   u64,
+}
+
+/// one --manifest entry per written chunk
+#[derive(Serialize)]
+struct ChunkManifestEntry {
+    file:      String,
+    start_row: u64,
+    rows:      u64,
+    bytes:     u64,
 }
 
 pub fn run(argv: &[&str]) -> CliResult<()> {
@@ -159,6 +250,72 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
     if args.flag_size == 0 {
         return fail_incorrectusage_clierror!("--size must be greater than 0.");
     }
+    // validate --quote-style upfront; new_writer() reparses it infallibly
+    if let Some(ref style) = args.flag_quote_style {
+        parse_quote_style(style)?;
+    }
+    if let Some(max_rows) = args.flag_max_rows {
+        if args.flag_kb_size.is_none() {
+            return fail_incorrectusage_clierror!("--max-rows is only valid with --kb-size.");
+        }
+        if max_rows == 0 {
+            return fail_incorrectusage_clierror!("--max-rows must be greater than 0.");
+        }
+    }
+    if args.flag_max_chunks == Some(0) {
+        return fail_incorrectusage_clierror!("--max-chunks must be greater than 0.");
+    }
+
+    // --gzip/--gzip-level are shorthand for --compress gzip/--compress-level;
+    // normalize them before validating the compression options
+    if args.flag_gzip {
+        if args.flag_compress.is_some() {
+            return fail_incorrectusage_clierror!("--gzip cannot be used with --compress.");
+        }
+        args.flag_compress = Some("gzip".to_owned());
+        args.flag_compress_level = args.flag_gzip_level.or(args.flag_compress_level);
+    } else if args.flag_gzip_level.is_some() {
+        return fail_incorrectusage_clierror!("--gzip-level requires --gzip.");
+    }
+
+    // validate the compression options
+    match args.flag_compress.as_deref() {
+        Some("gzip") => {
+            if let Some(level) = args.flag_compress_level
+                && level > 9
+            {
+                return fail_incorrectusage_clierror!(
+                    "Invalid --compress-level {level} for gzip. Valid levels are 0 to 9."
+                );
+            }
+        },
+        Some("zstd") => {
+            if let Some(level) = args.flag_compress_level
+                && !(1..=22).contains(&level)
+            {
+                return fail_incorrectusage_clierror!(
+                    "Invalid --compress-level {level} for zstd. Valid levels are 1 to 22."
+                );
+            }
+        },
+        Some(algo) => {
+            return fail_incorrectusage_clierror!(
+                "Invalid --compress algorithm \"{algo}\". Valid algorithms are \"gzip\" and \
+                 \"zstd\"."
+            );
+        },
+        None => {
+            if args.flag_compress_level.is_some() {
+                return fail_incorrectusage_clierror!("--compress-level requires --compress.");
+            }
+        },
+    }
+
+    // --count: report the chunk count only, without creating the output
+    // directory or writing any files
+    if args.flag_count {
+        return args.count_chunks();
+    }
 
     // check if outdir is set correctly
     if Path::new(&args.arg_outdir).is_file() && args.arg_input.is_none() {
@@ -191,8 +348,43 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
         args.arg_input = Some(temp_path);
     }
 
+    // --precount: make the --chunks division exact even for streamed stdin,
+    // by first materializing stdin to a temp file and counting rows upfront.
+    // The temp file guard must outlive the split itself
+    let _stdin_temp_file;
+    if args.flag_precount {
+        if args.flag_chunks.is_none() {
+            return fail_incorrectusage_clierror!("--precount is only valid with --chunks.");
+        }
+        if args.rconfig().is_stdin() {
+            let temp_dir = std::env::temp_dir();
+            let mut stdin_temp_file = tempfile::Builder::new()
+                .suffix(".csv")
+                .tempfile_in(&temp_dir)?;
+            io::copy(&mut io::stdin(), &mut stdin_temp_file)?;
+            args.arg_input = Some(stdin_temp_file.path().to_string_lossy().to_string());
+            _stdin_temp_file = Some(stdin_temp_file);
+        } else {
+            _stdin_temp_file = None;
+        }
+        let row_count = util::count_rows(&args.rconfig())?;
+        if !args.flag_quiet {
+            eprintln!("precount: {row_count} records");
+        }
+    }
+
+    // validate --rename-header upfront so the (possibly parallel) chunk
+    // writers can apply it infallibly later
+    if args.flag_rename_header.is_some() {
+        let mut rdr = args.rconfig().reader()?;
+        args.rename_headers(&rdr.byte_headers()?.clone())?;
+    }
+
     if let Some(kb_size) = args.flag_kb_size {
-        args.split_by_kb_size(kb_size)
+        match args.rconfig().indexed()? {
+            Some(idx) => args.parallel_split_by_kb_size(&idx, kb_size),
+            None => args.split_by_kb_size(kb_size),
+        }
     } else {
         // we're splitting by rowcount or by number of chunks
         match args.rconfig().indexed()? {
@@ -226,6 +418,14 @@ impl Args {
         let chunk_size_bytes = chunk_size * 1024;
         let mut chunk_size_bytes_left = chunk_size_bytes - header_byte_size;
 
+        // --max-rows: also roll over once a chunk reaches this many rows,
+        // even if the byte budget isn't exhausted
+        let max_rows = self.flag_max_rows.unwrap_or(usize::MAX);
+        let mut rows_in_chunk = 1; // the first row is written before the loop
+
+        let mut manifest_chunks: Vec<(usize, u64, u64)> = Vec::new();
+        let mut chunk_start_row = 0_u64;
+
         let mut not_empty = rdr.read_byte_record(&mut row)?;
         let mut curr_size_bytes;
         let mut next_size_bytes;
@@ -247,20 +447,27 @@ impl Args {
                 0
             };
 
-            if curr_size_bytes + next_size_bytes >= chunk_size_bytes_left {
+            if curr_size_bytes + next_size_bytes >= chunk_size_bytes_left
+                || (not_empty && rows_in_chunk >= max_rows)
+            {
                 wtr.flush()?;
                 // Run filter command if specified
                 if self.flag_filter.is_some() {
                     self.run_filter_command(chunk_start, self.flag_pad)?;
                 }
+                num_chunks += 1;
+                self.check_max_chunks(num_chunks)?;
+                manifest_chunks.push((chunk_start, chunk_start_row, rows_in_chunk as u64));
                 chunk_start = i; // Set start index for next chunk
+                chunk_start_row = (i + 1) as u64;
                 wtr = self.new_writer(&headers, i, self.flag_pad)?;
                 chunk_size_bytes_left = chunk_size_bytes - header_byte_size;
-                num_chunks += 1;
+                rows_in_chunk = 0;
             }
             if next_size_bytes > 0 {
                 wtr.write_byte_record(&row)?;
                 chunk_size_bytes_left -= curr_size_bytes;
+                rows_in_chunk += 1;
                 i += 1;
             }
         }
@@ -270,6 +477,14 @@ impl Args {
             self.run_filter_command(chunk_start, self.flag_pad)?;
         }
 
+        if let Some(ref manifest_path) = self.flag_manifest {
+            // compressed writers only finalize their stream on drop, so drop
+            // the last chunk's writer before measuring the file sizes
+            drop(wtr);
+            manifest_chunks.push((chunk_start, chunk_start_row, rows_in_chunk as u64));
+            self.write_manifest(manifest_path, manifest_chunks)?;
+        }
+
         if !self.flag_quiet {
             eprintln!(
                 "Wrote chunk/s to '{}'. Size/chunk: <= {}KB; Num chunks: {}",
@@ -282,46 +497,226 @@ impl Args {
         Ok(())
     }
 
+    /// Compute the chunk boundaries the sequential kb-size algorithm would
+    /// produce, without writing anything. Returns one (filename row number,
+    /// start row, number of rows) triplet per chunk, mirroring the row
+    /// assignment and filename numbering of `split_by_kb_size` exactly.
+    fn kb_size_chunk_plan(&self, chunk_size: usize) -> CliResult<Vec<(usize, u64, usize)>> {
+        let rconfig = self.rconfig();
+        let mut rdr = rconfig.reader()?;
+        let headers = rdr.byte_headers()?.clone();
+
+        let header_byte_size = if self.flag_no_headers {
+            0
+        } else {
+            let mut headerbuf_wtr = csv::WriterBuilder::new().from_writer(vec![]);
+            headerbuf_wtr.write_byte_record(&headers)?;
+
+            // safety: we know the inner vec is valid
+            headerbuf_wtr.into_inner().unwrap().len()
+        };
+
+        let mut plan: Vec<(usize, u64, usize)> = Vec::new();
+        let mut chunk_name = 0;
+        let mut chunk_start_row = 0_u64;
+        let mut i = 0;
+        let mut row = csv::ByteRecord::new();
+        let chunk_size_bytes = chunk_size * 1024;
+        let mut chunk_size_bytes_left = chunk_size_bytes - header_byte_size;
+
+        let max_rows = self.flag_max_rows.unwrap_or(usize::MAX);
+        let mut rows_in_chunk = 1; // the first row always goes to the first chunk
+
+        let mut not_empty = rdr.read_byte_record(&mut row)?;
+        let mut curr_size_bytes;
+        let mut next_size_bytes;
+
+        while not_empty {
+            let mut buf_curr_wtr = csv::WriterBuilder::new().from_writer(vec![]);
+            buf_curr_wtr.write_byte_record(&row)?;
+
+            curr_size_bytes = buf_curr_wtr.into_inner().unwrap().len();
+
+            not_empty = rdr.read_byte_record(&mut row)?;
+            next_size_bytes = if not_empty {
+                let mut buf_next_wtr = csv::WriterBuilder::new().from_writer(vec![]);
+                buf_next_wtr.write_byte_record(&row)?;
+
+                buf_next_wtr.into_inner().unwrap().len()
+            } else {
+                0
+            };
+
+            if curr_size_bytes + next_size_bytes >= chunk_size_bytes_left
+                || (not_empty && rows_in_chunk >= max_rows)
+            {
+                plan.push((chunk_name, chunk_start_row, rows_in_chunk));
+                chunk_name = i;
+                chunk_start_row = (i + 1) as u64;
+                chunk_size_bytes_left = chunk_size_bytes - header_byte_size;
+                rows_in_chunk = 0;
+            }
+            if next_size_bytes > 0 {
+                chunk_size_bytes_left -= curr_size_bytes;
+                rows_in_chunk += 1;
+                i += 1;
+            }
+        }
+        plan.push((chunk_name, chunk_start_row, rows_in_chunk));
+
+        Ok(plan)
+    }
+
+    fn parallel_split_by_kb_size(
+        &self,
+        idx: &Indexed<fs::File, fs::File>,
+        chunk_size: usize,
+    ) -> CliResult<()> {
+        if idx.count() == 0 {
+            return self.split_by_kb_size(chunk_size);
+        }
+
+        // plan the chunk boundaries with a sequential scan so they match the
+        // single-threaded algorithm exactly, then write the chunks concurrently
+        let plan = self.kb_size_chunk_plan(chunk_size)?;
+        let num_chunks = plan.len();
+        if num_chunks == 1 {
+            // there's only one chunk, we can just do a sequential split
+            // which has less overhead and better error handling
+            return self.split_by_kb_size(chunk_size);
+        }
+        // the chunk count is known upfront, so enforce --max-chunks
+        // before writing anything at all
+        if let Some(max_chunks) = self.flag_max_chunks
+            && num_chunks > max_chunks
+        {
+            return fail_clierror!(
+                "Aborting: the split would create {num_chunks} chunks, exceeding --max-chunks \
+                 {max_chunks}."
+            );
+        }
+
+        util::njobs(self.flag_jobs);
+
+        // the plan already holds each chunk's name, start row & row count,
+        // so collect the manifest entries before the plan is consumed
+        let manifest_chunks: Vec<(usize, u64, u64)> = plan
+            .iter()
+            .map(|&(name_idx, start_row, nrows)| (name_idx, start_row, nrows as u64))
+            .collect();
+
+        // safety: we cannot use ? here because we're in a closure
+        plan.into_par_iter()
+            .for_each(|(name_idx, start_row, nrows)| {
+                let conf = self.rconfig();
+                // safety: safe to unwrap because we know the file is indexed
+                let mut idx = conf.indexed().unwrap().unwrap();
+                // safety: the only way this can fail is if the first row of the
+                // chunk is not a valid CSV record, which is impossible because
+                // we're reading from a file with a valid index
+                let headers = idx.byte_headers().unwrap();
+
+                let mut wtr = self
+                    // safety: the only way this can fail is if we cannot create a file
+                    .new_writer(headers, name_idx, self.flag_pad)
+                    .unwrap();
+
+                // safety: the planning scan guarantees the row range is in bounds
+                idx.seek(start_row).unwrap();
+                let mut write_row;
+                for row in idx.byte_records().take(nrows) {
+                    write_row = row.unwrap();
+                    wtr.write_byte_record(&write_row).unwrap();
+                }
+                // safety: safe to unwrap because we know the writer is a file
+                // the only way this can fail is if we cannot write to the file
+                wtr.flush().unwrap();
+
+                // Run filter command if specified
+                if self.flag_filter.is_some() {
+                    // We can't use ? here because we're in a closure
+                    if let Err(e) = self.run_filter_command(name_idx, self.flag_pad) {
+                        eprintln!("Error running filter command: {e}");
+                    }
+                }
+            });
+
+        if let Some(ref manifest_path) = self.flag_manifest {
+            self.write_manifest(manifest_path, manifest_chunks)?;
+        }
+
+        if !self.flag_quiet {
+            eprintln!(
+                "Wrote chunk/s to '{}'. Size/chunk: <= {}KB; Num chunks: {}",
+                dunce::canonicalize(Path::new(&self.arg_outdir))?.display(),
+                chunk_size,
+                num_chunks
+            );
+        }
+
+        Ok(())
+    }
+
     fn sequential_split(&self) -> CliResult<()> {
         let rconfig = self.rconfig();
         let mut rdr = rconfig.reader()?;
         let headers = rdr.byte_headers()?.clone();
 
-        #[allow(clippy::cast_precision_loss)]
-        let chunk_size = if let Some(flag_chunks) = self.flag_chunks {
-            let count = util::count_rows(&rconfig)?;
-            let chunk = flag_chunks;
-            if chunk == 0 {
+        // --chunks: distribute the remainder one extra row across the first
+        // `count % N` chunks, so chunk sizes differ by at most one row
+        let (chunk_size, mut extra_chunks) = if let Some(flag_chunks) = self.flag_chunks {
+            if flag_chunks == 0 {
                 return fail_incorrectusage_clierror!("--chunk must be greater than 0.");
             }
-            (count as f64 / chunk as f64).ceil() as usize
+            let count = util::count_rows(&rconfig)? as usize;
+            (count / flag_chunks, count % flag_chunks)
         } else {
-            self.flag_size
+            (self.flag_size, 0)
         };
 
         let mut wtr = self.new_writer(&headers, 0, self.flag_pad)?;
         let mut i: usize = 0;
         let mut nchunks: usize = 0;
+        // track the start index of the current chunk explicitly, so the filter
+        // command runs exactly once per written chunk - deriving it from i at
+        // the end is off-by-one when rows % chunk_size == 0 and underflows
+        // when the file has no data rows at all
+        let mut chunk_start: usize = 0;
+        let mut manifest_chunks: Vec<(usize, u64, u64)> = Vec::new();
         let mut row = csv::ByteRecord::new();
+        let mut rows_left_in_chunk = chunk_size + usize::from(extra_chunks > 0);
+        extra_chunks = extra_chunks.saturating_sub(1);
         while rdr.read_byte_record(&mut row)? {
-            if i > 0 && i.is_multiple_of(chunk_size) {
+            if i > 0 && rows_left_in_chunk == 0 {
                 wtr.flush()?;
                 // Run filter command if specified
                 if self.flag_filter.is_some() {
-                    self.run_filter_command(i - chunk_size, self.flag_pad)?;
+                    self.run_filter_command(chunk_start, self.flag_pad)?;
                 }
                 nchunks += 1;
+                self.check_max_chunks(nchunks)?;
+                manifest_chunks.push((chunk_start, chunk_start as u64, (i - chunk_start) as u64));
+                chunk_start = i;
                 wtr = self.new_writer(&headers, i, self.flag_pad)?;
+                rows_left_in_chunk = chunk_size + usize::from(extra_chunks > 0);
+                extra_chunks = extra_chunks.saturating_sub(1);
             }
             wtr.write_byte_record(&row)?;
+            rows_left_in_chunk = rows_left_in_chunk.saturating_sub(1);
             i += 1;
         }
         wtr.flush()?;
         // Run filter command for the last chunk if specified
         if self.flag_filter.is_some() {
-            // Calculate the start index for the last chunk
-            let last_chunk_start = ((i - 1) / chunk_size) * chunk_size;
-            self.run_filter_command(last_chunk_start, self.flag_pad)?;
+            self.run_filter_command(chunk_start, self.flag_pad)?;
+        }
+
+        if let Some(ref manifest_path) = self.flag_manifest {
+            // compressed writers only finalize their stream on drop, so drop
+            // the last chunk's writer before measuring the file sizes
+            drop(wtr);
+            manifest_chunks.push((chunk_start, chunk_start as u64, (i - chunk_start) as u64));
+            self.write_manifest(manifest_path, manifest_chunks)?;
         }
 
         if !self.flag_quiet {
@@ -338,27 +733,67 @@ impl Args {
     }
 
     fn parallel_split(&self, idx: &Indexed<fs::File, fs::File>) -> CliResult<()> {
-        let chunk_size;
-        let idx_count = idx.count();
+        let idx_count = idx.count() as usize;
 
-        #[allow(clippy::cast_precision_loss)]
-        let nchunks = if let Some(flag_chunks) = self.flag_chunks {
-            chunk_size = (idx_count as f64 / flag_chunks as f64).ceil() as usize;
-            flag_chunks
+        if self.flag_chunks == Some(0) {
+            return fail_incorrectusage_clierror!("--chunk must be greater than 0.");
+        }
+
+        // --chunks: distribute the remainder one extra row across the first
+        // `count % N` chunks, so chunk sizes differ by at most one row
+        let (chunk_size, extra_chunks) = if let Some(flag_chunks) = self.flag_chunks {
+            (idx_count / flag_chunks, idx_count % flag_chunks)
+        } else {
+            (self.flag_size, 0)
+        };
+        let nchunks = if self.flag_chunks.is_some() {
+            // empty trailing chunks would all share the same start offset
+            // (and thus the same filename), so don't create them at all
+            self.flag_chunks.unwrap().min(idx_count.max(1))
         } else {
-            chunk_size = self.flag_size;
-            util::num_of_chunks(idx_count as usize, self.flag_size)
+            util::num_of_chunks(idx_count, self.flag_size)
         };
-        if nchunks == 1 {
+        if nchunks <= 1 {
             // there's only one chunk, we can just do a sequential split
             // which has less overhead and better error handling
             return self.sequential_split();
         }
 
+        // each chunk's (start row, number of rows), with the `{start}.csv`
+        // naming still based on the cumulative start offsets
+        let mut next_start = 0_usize;
+        let chunks: Vec<(usize, usize)> = (0..nchunks)
+            .map(|n| {
+                let start = next_start;
+                let rows = if self.flag_chunks.is_some() {
+                    chunk_size + usize::from(n < extra_chunks)
+                } else {
+                    chunk_size.min(idx_count - start)
+                };
+                next_start += rows;
+                (start, rows)
+            })
+            .collect();
+        // the chunk count is known upfront, so enforce --max-chunks
+        // before writing anything at all
+        if let Some(max_chunks) = self.flag_max_chunks
+            && nchunks > max_chunks
+        {
+            return fail_clierror!(
+                "Aborting: the split would create {nchunks} chunks, exceeding --max-chunks \
+                 {max_chunks}."
+            );
+        }
+
         util::njobs(self.flag_jobs);
 
+        let manifest_chunks: Vec<(usize, u64, u64)> = chunks
+            .iter()
+            .map(|&(start, rows)| (start, start as u64, rows as u64))
+            .collect();
+
         // safety: we cannot use ? here because we're in a closure
-        (0..nchunks).into_par_iter().for_each(|i| {
+        chunks.into_par_iter().for_each(|(start, nrows)| {
             let conf = self.rconfig();
             // safety: safe to unwrap because we know the file is indexed
             let mut idx = conf.indexed().unwrap().unwrap();
@@ -369,14 +804,14 @@ impl Args {
 
             let mut wtr = self
                 // safety: the only way this can fail is if we cannot create a file
-                .new_writer(headers, i * chunk_size, self.flag_pad)
+                .new_writer(headers, start, self.flag_pad)
                 .unwrap();
 
             // safety: we know that there is more than one chunk, so we can safely
             // seek to the start of the chunk
-            idx.seek((i * chunk_size) as u64).unwrap();
+            idx.seek(start as u64).unwrap();
             let mut write_row;
-            for row in idx.byte_records().take(chunk_size) {
+            for row in idx.byte_records().take(nrows) {
                 write_row = row.unwrap();
                 wtr.write_byte_record(&write_row).unwrap();
             }
@@ -387,12 +822,16 @@ impl Args {
             // Run filter command if specified
             if self.flag_filter.is_some() {
                 // We can't use ? here because we're in a closure
-                if let Err(e) = self.run_filter_command(i * chunk_size, self.flag_pad) {
+                if let Err(e) = self.run_filter_command(start, self.flag_pad) {
                     eprintln!("Error running filter command: {e}");
                 }
             }
         });
 
+        if let Some(ref manifest_path) = self.flag_manifest {
+            self.write_manifest(manifest_path, manifest_chunks)?;
+        }
+
         if !self.flag_quiet {
             eprintln!(
                 "Wrote {} chunk/s to '{}'. Rows/chunk: {} Num records: {}",
@@ -406,6 +845,86 @@ impl Args {
         Ok(())
     }
 
+    /// the filename a chunk is written to, including any compression extension
+    fn chunk_filename(&self, start: usize, width: usize) -> String {
+        format!(
+            "{}{}",
+            self.flag_filename.filename(&format!("{start:0>width$}")),
+            self.compress_extension()
+        )
+    }
+
+    /// --manifest: write a JSON array describing every written chunk,
+    /// sorted by start row. `chunks` holds (filename index, start row,
+    /// row count) triplets; byte sizes are taken from the finished files
+    fn write_manifest(
+        &self,
+        manifest_path: &str,
+        mut chunks: Vec<(usize, u64, u64)>,
+    ) -> CliResult<()> {
+        chunks.sort_unstable_by_key(|&(_, start_row, _)| start_row);
+        let outdir = Path::new(&self.arg_outdir);
+        let entries: Vec<ChunkManifestEntry> = chunks
+            .iter()
+            .map(|&(name_idx, start_row, rows)| {
+                let file = self.chunk_filename(name_idx, self.flag_pad);
+                let bytes = fs::metadata(outdir.join(&file)).map_or(0, |metadata| metadata.len());
+                ChunkManifestEntry {
+                    file,
+                    start_row,
+                    rows,
+                    bytes,
+                }
+            })
+            .collect();
+        fs::write(manifest_path, serde_json::to_string_pretty(&entries)?)?;
+        Ok(())
+    }
+
+    /// --max-chunks: refuse to start another chunk once `chunks_written`
+    /// chunks are already on disk. The chunks written so far are kept
+    fn check_max_chunks(&self, chunks_written: usize) -> CliResult<()> {
+        if let Some(max_chunks) = self.flag_max_chunks
+            && chunks_written >= max_chunks
+        {
+            return fail_clierror!(
+                "Aborting: --max-chunks {max_chunks} reached. {chunks_written} chunk/s already \
+                 written to '{}'.",
+                self.arg_outdir
+            );
+        }
+        Ok(())
+    }
+
+    /// --count: compute the number of chunks the chosen mode would produce.
+    /// The row and chunk modes are arithmetic over the row count; kb-size
+    /// reuses the sequential chunk-planning scan
+    fn count_chunks(&self) -> CliResult<()> {
+        let num_chunks = if let Some(kb_size) = self.flag_kb_size {
+            self.kb_size_chunk_plan(kb_size)?.len()
+        } else if let Some(flag_chunks) = self.flag_chunks {
+            if flag_chunks == 0 {
+                return fail_incorrectusage_clierror!("--chunk must be greater than 0.");
+            }
+            let row_count = util::count_rows(&self.rconfig())? as usize;
+            // chunks with no rows to hold aren't written
+            flag_chunks.min(row_count.max(1))
+        } else {
+            let row_count = util::count_rows(&self.rconfig())? as usize;
+            row_count.div_ceil(self.flag_size).max(1)
+        };
+        println!("{num_chunks}");
+        Ok(())
+    }
+
+    fn compress_extension(&self) -> &'static str {
+        match self.flag_compress.as_deref() {
+            Some("gzip") => ".gz",
+            Some("zstd") => ".zst",
+            _ => "",
+        }
+    }
+
     fn new_writer(
         &self,
         headers: &csv::ByteRecord,
@@ -413,19 +932,72 @@ impl Args {
         width: usize,
     ) -> CliResult<csv::Writer<Box<dyn io::Write + 'static>>> {
         let dir = Path::new(&self.arg_outdir);
-        let path = dir.join(self.flag_filename.filename(&format!("{start:0>width$}")));
-        let spath = Some(path.display().to_string());
-        let mut wtr = Config::new(spath.as_ref()).writer()?;
+        let filename = self.flag_filename.filename(&format!("{start:0>width$}"));
+
+        let quote_style = self
+            .flag_quote_style
+            .as_deref()
+            .and_then(|style| parse_quote_style(style).ok());
+        let mut wtr = if let Some(ref algo) = self.flag_compress {
+            let path = dir.join(format!("{filename}{}", self.compress_extension()));
+            let file = fs::File::create(path)?;
+            let encoder: Box<dyn io::Write + 'static> = if algo == "gzip" {
+                let level = self.flag_compress_level.unwrap_or(6);
+                Box::new(GzEncoder::new(file, Compression::new(level)))
+            } else {
+                let level = self.flag_compress_level.unwrap_or(3);
+                #[allow(clippy::cast_possible_wrap)]
+                Box::new(zstd::stream::write::Encoder::new(file, level as i32)?.auto_finish())
+            };
+            let mut builder = csv::WriterBuilder::new();
+            if let Some(quote_style) = quote_style {
+                builder.quote_style(quote_style);
+            }
+            builder.from_writer(encoder)
+        } else {
+            let path = dir.join(filename);
+            let spath = Some(path.display().to_string());
+            let mut wconfig = Config::new(spath.as_ref());
+            if let Some(quote_style) = quote_style {
+                wconfig = wconfig.quote_style(quote_style);
+            }
+            wconfig.writer()?
+        };
         if !self.rconfig().no_headers {
-            wtr.write_record(headers)?;
+            let headers = self.rename_headers(headers)?;
+            wtr.write_record(&headers)?;
         }
         Ok(wtr)
     }
 
+    /// apply the --rename-header <from=to,...> spec to the header row written
+    /// to each chunk, validating that all <from> columns exist
+    fn rename_headers(&self, headers: &csv::ByteRecord) -> CliResult<csv::ByteRecord> {
+        let Some(ref rename_spec) = self.flag_rename_header else {
+            return Ok(headers.clone());
+        };
+
+        let mut new_headers: Vec<Vec<u8>> = headers.iter().map(<[u8]>::to_vec).collect();
+        for pair in rename_spec.split(',') {
+            let Some((from, to)) = pair.split_once('=') else {
+                return fail_incorrectusage_clierror!(
+                    "Invalid --rename-header pair \"{pair}\". Expected the form <from>=<to>."
+                );
+            };
+            let Some(pos) = headers.iter().position(|h| h == from.as_bytes()) else {
+                return fail_incorrectusage_clierror!(
+                    "--rename-header column \"{from}\" not found in headers."
+                );
+            };
+            new_headers[pos] = to.as_bytes().to_vec();
+        }
+        Ok(new_headers.into_iter().collect())
+    }
+
     fn run_filter_command(&self, start: usize, width: usize) -> CliResult<()> {
         if let Some(ref filter_cmd) = self.flag_filter {
             let outdir = Path::new(&self.arg_outdir).canonicalize()?;
-            let filename = self.flag_filename.filename(&format!("{start:0>width$}"));
+            let filename = self.chunk_filename(start, width);
             let file_path = outdir.join(&filename);
 
             debug!(
@@ -471,31 +1043,48 @@ impl Args {
                 },
             };
 
-            // Execute the command using the appropriate shell based on platform
-            let status = if cfg!(windows) {
-                debug!("Running Windows command: cmd /C {cmd}");
-                let cmd_vec = cmd.split(' ').collect::<Vec<&str>>();
-                Command::new("cmd")
-                    .arg("/C")
-                    .args(&cmd_vec)
-                    .current_dir(&canonical_outdir)
-                    .env("FILE", path_str)
-                    .status()
-            } else {
-                debug!("Running Unix command: sh -c {cmd}");
-                Command::new("sh")
-                    .arg("-c")
-                    .arg(&cmd)
-                    .current_dir(&canonical_outdir)
-                    .env("FILE", path_str)
-                    .status()
-            };
-
-            let status = match status {
-                Ok(status) => status,
-                Err(e) => {
-                    return fail_clierror!("Failed to execute filter command: {e}");
-                },
+            // Execute the command using the appropriate shell based on platform,
+            // retrying transient failures up to --filter-retries times
+            let mut attempt = 0_u32;
+            let status = loop {
+                let status = if cfg!(windows) {
+                    debug!("Running Windows command: cmd /C {cmd}");
+                    let cmd_vec = cmd.split(' ').collect::<Vec<&str>>();
+                    Command::new("cmd")
+                        .arg("/C")
+                        .args(&cmd_vec)
+                        .current_dir(&canonical_outdir)
+                        .env("FILE", &path_str)
+                        .status()
+                } else {
+                    debug!("Running Unix command: sh -c {cmd}");
+                    Command::new("sh")
+                        .arg("-c")
+                        .arg(&cmd)
+                        .current_dir(&canonical_outdir)
+                        .env("FILE", &path_str)
+                        .status()
+                };
+
+                let status = match status {
+                    Ok(status) => status,
+                    Err(e) => {
+                        return fail_clierror!("Failed to execute filter command: {e}");
+                    },
+                };
+
+                if status.success() || attempt >= self.flag_filter_retries {
+                    break status;
+                }
+                attempt += 1;
+                wwarn!(
+                    "Filter command failed with exit code: {}. Retrying ({attempt}/{}) in {} \
+                     second/s...",
+                    status.code().unwrap_or(-1),
+                    self.flag_filter_retries,
+                    self.flag_filter_retry_delay
+                );
+                std::thread::sleep(std::time::Duration::from_secs(self.flag_filter_retry_delay));
             };
 
             if !status.success() && !self.flag_filter_ignore_errors {
@@ -814,6 +814,7 @@ fn register_lookup(
         ckan_api_url: CKAN_API.get().cloned(),
         ckan_token: CKAN_TOKEN.get().and_then(std::clone::Clone::clone),
         timeout_secs: TIMEOUT_SECS.load(Ordering::Relaxed),
+        force_refresh: false,
     };
 
     let lookup_table = lookup::load_lookup_table(&lookup_opts).map_err(|e| {
@@ -32,7 +32,9 @@ qsv supports a custom format - `currency`. This format will only accept a valid
       Negative amounts: ($100.00) or -$100.00
       Different styles: 1.000,00 (used in some countries for euros)
 
-qsv also supports two custom keywords - `dynamicEnum` and `uniqueCombinedWith`.
+qsv also supports four custom keywords - `dynamicEnum`, `uniqueCombinedWith`,
+`columnComparisons` and `enumCaseInsensitive` - as well as two dataset-level
+assertions - `minRecords` and `maxRecords`.
 
 dynamicEnum
 ===========
@@ -86,6 +88,43 @@ The "dynamicEnum" value has the form:
 
 If colname is not specified, the first column of the CSV file is read and used for validation.
 
+Several comma-separated columns (by name or 0-based index) may be listed - the allowed
+values are the UNION of all listed columns, so a value is valid if it appears in ANY of
+them. The column list is a single URI segment, so it composes with the caching config:
+
+    // a value is valid if it matches either the code or the alias column
+    dynamicEnum = "lookup.csv|code,alias"
+
+    // same, cached as product_cache for 1 hour
+    dynamicEnum = "product_cache;3600|lookup.csv|code,alias"
+
+    // union of the first and third columns, by index
+    dynamicEnum = "lookup.csv|0,2"
+
+A trailing "ci" modifier makes the lookup case-insensitive - both the lookup values and the
+value being validated are lowercased before comparison. The modifier is always the LAST
+segment, after the optional column selector, so it composes with the caching config:
+
+    // case-insensitive lookup against the "name" column
+    dynamicEnum = "lookup.csv|name|ci"
+
+    // case-insensitive lookup against the first column, cached for 1 hour
+    dynamicEnum = "product_cache;3600|lookup.csv|ci"
+
+Note that a lone "|ci" is always parsed as the modifier - a lookup column literally named
+"ci" must be selected by its 0-based column index instead.
+
+When the polars feature is enabled, the lookup file may also be in one of the "special"
+formats qsv can read - Parquet (.parquet), Arrow IPC (.arrow/.ipc), Avro (.avro) or
+JSON/JSONL (.json/.jsonl/.ndjson). The selected column is read through the same
+polars-based converters used when slicing these formats:
+
+    // validate against the "name" column of a Parquet lookup file
+    dynamicEnum = "lookup.parquet|name"
+
+Without the polars feature, only CSV/TSV lookup files are supported and a special format
+lookup file is an error.
+
 uniqueCombinedWith
 ==================
 `uniqueCombinedWith` allows you to validate that combinations of values across specified columns
@@ -107,6 +146,44 @@ records will be written to the .invalid file, while valid records will be writte
 `uniqueCombinedWith` complements the standard `uniqueItems` keyword, which can only validate
 uniqueness across a single column.
 
+As `uniqueCombinedWith` tracks seen combinations across rows, rows are validated in file
+order even when --jobs > 1, so the first occurrence of a combination deterministically
+passes and the later duplicates are the ones flagged.
+
+columnComparisons
+=================
+`columnComparisons` allows you to assert cross-column numeric comparisons, which are not
+expressible in per-property JSON Schema. It is a top-level keyword taking a list of
+{left, op, right} objects, evaluated per record. For example:
+
+    // Validate that GeoX is less than GeoY and that open_dt is on or before closed_dt
+    columnComparisons = [{"left": "GeoX", "op": "<", "right": "GeoY"},
+                         {"left": "open_dt", "op": "<=", "right": "closed_dt"}]
+
+Supported operators are <, <=, >, >=, == and !=. Values are compared as parsed 64-bit floats,
+with a clear error when a value isn't numeric. Errors report the left column as the field.
+
+enumCaseInsensitive
+===================
+`enumCaseInsensitive` is like the standard `enum` keyword, but matches string values
+case-insensitively, so the allowed variants don't have to be listed in every casing.
+For example:
+
+    // "male", "Male" and "MALE" all validate
+    "gender": { "type": "string", "enumCaseInsensitive": ["Male", "Female"] }
+
+minRecords & maxRecords
+=======================
+`minRecords` and `maxRecords` are top-level, dataset-level assertions on the number of
+data rows in the file, evaluated once after the row stream completes. For example:
+
+    // Validate that the file has between 1 and 10000 data rows
+    minRecords = 1
+    maxRecords = 10000
+
+When breached, a dataset-level error with row_number 0 is added to the error report and
+the exit code is non-zero, even when every individual row is valid.
+
 -------------------------------------------------------
 
 You can create a JSON Schema file from a reference CSV file using the `qsv schema` command.
@@ -159,24 +236,105 @@ https://github.com/dathere/qsv/blob/master/tests/test_validate.rs.
 
 Usage:
     qsv validate schema [<json-schema>]
-    qsv validate [options] [<input>] [<json-schema>]
+    qsv validate [options] [--enum <spec>...] [<input>] [<json-schema>]
     qsv validate --help
 
 Validate arguments:
     <input>                    Input CSV file to validate. If not provided, will read from stdin.
     <json-schema>              JSON Schema file to validate against. If not provided, `validate`
                                will run in RFC 4180 validation mode. The file can be a local file
-                               or a URL (http and https schemes supported).
+                               or a URL (http and https schemes supported). If set to "-", the
+                               schema is read from stdin (the CSV data must then come from a
+                               file).
 
 Validate options:
+    --schema-text <json>       The JSON Schema to validate against, as an inline JSON string.
+                               Handy in shell scripts that generate schemas on the fly.
+                               Cannot be used together with a <json-schema>.
+    --enum <spec>              Validate a column against an inline list of allowed values,
+                               without requiring a JSON Schema file. <spec> has the form
+                               <column>=<value1>,<value2>,...
+                               Can be specified multiple times to validate several columns.
+                               Produces the usual valid/invalid split and error report.
+                               Cannot be used together with a <json-schema>.
+    --type-map <spec>          Validate each listed column's inferred type against an expected
+                               type, using the stats cache (computing stats if needed).
+                               <spec> is a comma-separated list of <column>=<type> pairs,
+                               e.g. --type-map "id=Integer,name=String". Valid types are
+                               Integer, Float, String, Date, DateTime, Boolean & NULL
+                               (case-insensitive). This is a fast, dataset-level type check -
+                               no per-row validation is done. Mismatched columns are reported
+                               to stderr. Cannot be used together with a <json-schema>.
+    --cross-file-unique <cols> Check that the combination of values in the given columns
+                               (in standard qsv selection syntax) is unique ACROSS all
+                               validated files, not just within one. To validate several
+                               files in one run, <input> can be a directory or an
+                               ".infile-list" file with one file path per line.
+                               Collisions are reported to stderr with the file name and
+                               row number of both occurrences. This extends the
+                               uniqueCombinedWith custom keyword's combined-uniqueness
+                               check so it spans files.
+                               Cannot be used together with a <json-schema>.
     --trim                     Trim leading and trailing whitespace from fields before validating.
+    --coerce-booleans          Coerce common textual boolean representations to JSON
+                               booleans when building the per-row JSON object, so
+                               "type: boolean" schema fields validate. Accepted tokens
+                               (case-insensitive) - true/t/yes/y for true & false/f/no/n
+                               for false, in addition to the true/false/1/0 literals
+                               the command always accepts.
+    --relaxed-numbers <dmark>  Accept localized numbers with thousands separators
+                               when coercing "type: number" and "type: integer"
+                               schema fields, so schemas with numeric min/max work
+                               on financial data. <dmark> is the decimal mark -
+                               "." (thousands separator ",", e.g. 1,234.56) or
+                               "," (thousands separator ".", e.g. 1.234,56).
+                               The thousands separator is stripped and the decimal
+                               mark is normalized to "." before coercion.
+    --schema-columns-only      Construct the per-row JSON object using only the columns
+                               declared in the schema's "properties" (including those
+                               declared in if/then/else subschemas), skipping all other
+                               columns. This speeds up validating wide CSVs where the
+                               schema describes a small subset of the columns and avoids
+                               "additionalProperties" surprises.
     --no-format-validation     Disable JSON Schema format validation. Ignores all JSON Schema
                                "format" keywords (e.g. date,email, uri, currency, etc.). This is
                                useful when you want to validate the structure of the CSV file
                                w/o worrying about the data types and domain/range of the fields.
+    --ref-base <url_or_dir>    The base location used to resolve non-absolute "$ref"s during
+                               JSON Schema compilation - either a URL
+                               (e.g. https://example.com/schemas/) or a local directory.
+                               Relative "$ref"s in the schema resolve against it, so shared
+                               definitions in a schema registry resolve consistently
+                               across projects.
     --fail-fast                Stops on first error.
+    --autofix-lengths          When validating without a JSON Schema, repair ragged records
+                               by padding/truncating them to the header length instead of
+                               erroring out, writing the repaired data to <input>.fixed.csv
+                               and reporting how many rows were repaired.
+    --error-context <cols>     A comma-separated list of context columns whose values are
+                               included as extra columns in each error row of the
+                               "validation-errors.tsv" report, making errors self-locating
+                               (e.g. an id column).
+    --errors-format <fmt>      The format of the validation error report - "tsv" (written
+                               to <input>.validation-errors.tsv) or "jsonl" (one JSON
+                               object per error, written to
+                               <input>.validation-errors.jsonl), which is easier to consume
+                               programmatically, particularly when error messages contain
+                               tabs or newlines. In RFC 4180 mode, a record length error is
+                               also written to the JSONL report when this is set.
+                               [default: tsv]
+    --json-pointer-errors      Include the JSON Pointer to the failing value
+                               ("instance_pointer", e.g. /product) and the pointer to the
+                               failing schema constraint ("schema_pointer", e.g.
+                               /properties/product/type) in each error object of the JSONL
+                               error report, making errors machine-locatable even for
+                               nested schemas. Requires --errors-format jsonl.
     --valid <suffix>           Valid record output file suffix. [default: valid]
     --invalid <suffix>         Invalid record output file suffix. [default: invalid]
+    --out-delimiter <arg>      The field delimiter to use when writing the valid &
+                               invalid record output files. Must be a single character.
+                               When not set, the input's delimiter is used, so TSV/SSV
+                               inputs round-trip losslessly. (default: input delimiter)
     --json                     When validating without a JSON Schema, return the RFC 4180 check
                                as a JSON file instead of a message.
     --pretty-json              Same as --json, but pretty printed.
@@ -185,6 +343,11 @@ Validate options:
                                Setting this will override the default behavior of creating
                                a valid file only when there are invalid records.
                                To send valid records to stdout, use `-` as the filename.
+    --invalid-output <file>    Stream invalid records to <file> instead of creating
+                               the ".invalid" sidecar file, making validate usable in
+                               a pipe without touching the filesystem.
+                               To send invalid records to stdout, use `-` as the filename.
+                               Cannot be `-` when --valid-output is also `-`.
     -j, --jobs <arg>           The number of jobs to run in parallel.
                                When not set, the number of jobs is set to the
                                number of CPUs detected.
@@ -194,6 +357,19 @@ Validate options:
                                Set to 0 to load all rows in one batch.
                                Set to 1 to force batch optimization even for files with
                                less than 50000 rows. [default: 50000]
+    --report-slow <ms>         Report to stderr any row whose validation took longer
+                               than <ms> milliseconds, with its row number. A diagnostics
+                               option for identifying ReDoS-prone regex patterns or
+                               pathological rows dominating runtime.
+    --max-record-bytes <n>     Flag any record larger than <n> bytes as invalid with a
+                               specific error instead of validating it, guarding against
+                               pathological records (e.g. megabytes of one field) blowing
+                               up memory during validation.
+    --output-stats <file>      Write validation run metrics as JSON to <file> at the end
+                               of the run - record & invalid counts, elapsed time, rows/sec,
+                               the jobs & batch size used, and a peak memory estimate.
+                               Use `-` to write the metrics to stdout.
+                               For benchmarking large validations and tuning --jobs.
 
                                FANCY REGEX OPTIONS:
     --fancy-regex              Use the fancy regex engine instead of the default regex engine
@@ -229,6 +405,9 @@ Validate options:
                                private resources.
                                If the QSV_CKAN_TOKEN envvar is set, it will be used instead.
                                Not available on qsvlite.
+    --refresh-lookups          Force re-download of remote dynamicEnum lookup tables,
+                               even if their cached copies are still within their
+                               cache age. Not available on qsvlite.
 
 Common options:
     -h, --help                 Display this message
@@ -258,7 +437,7 @@ use std::{
 
 use bitvec::prelude::*;
 use csv::ByteRecord;
-use foldhash::{HashSet, HashSetExt};
+use foldhash::{HashMap, HashMapExt, HashSet, HashSetExt};
 use indicatif::HumanCount;
 #[cfg(any(feature = "feature_capable", feature = "lite"))]
 use indicatif::{ProgressBar, ProgressDrawTarget};
@@ -301,6 +480,9 @@ static CKAN_API: OnceLock<String> = OnceLock::new();
 
 #[cfg(not(feature = "lite"))]
 static CKAN_TOKEN: OnceLock<Option<String>> = OnceLock::new();
+
+#[cfg(not(feature = "lite"))]
+static REFRESH_LOOKUPS: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
 static DELIMITER: OnceLock<Option<Delimiter>> = OnceLock::new();
 
 /// write to stderr and log::error, using ValidationError
@@ -322,16 +504,33 @@ macro_rules! fail_validation_error {
 #[allow(dead_code)]
 struct Args {
     cmd_schema:                bool,
+    flag_schema_text:          Option<String>,
+    flag_enum:                 Vec<String>,
+    flag_type_map:             Option<String>,
+    flag_cross_file_unique:    Option<String>,
     flag_trim:                 bool,
+    flag_coerce_booleans:      bool,
+    flag_relaxed_numbers:      Option<String>,
     flag_no_format_validation: bool,
+    flag_ref_base:             Option<String>,
+    flag_schema_columns_only:  bool,
     flag_fail_fast:            bool,
+    flag_autofix_lengths:      bool,
+    flag_error_context:        Option<String>,
+    flag_errors_format:        String,
+    flag_json_pointer_errors:  bool,
     flag_valid:                Option<String>,
     flag_invalid:              Option<String>,
+    flag_out_delimiter:        Option<Delimiter>,
     flag_json:                 bool,
     flag_pretty_json:          bool,
     flag_valid_output:         Option<String>,
+    flag_invalid_output:       Option<String>,
     flag_jobs:                 Option<usize>,
     flag_batch:                usize,
+    flag_report_slow:          Option<u64>,
+    flag_max_record_bytes:     Option<u64>,
+    flag_output_stats:         Option<String>,
     flag_no_headers:           bool,
     flag_delimiter:            Option<Delimiter>,
     flag_progressbar:          bool,
@@ -346,6 +545,7 @@ struct Args {
     flag_cache_dir:            String,
     flag_ckan_api:             String,
     flag_ckan_token:           Option<String>,
+    flag_refresh_lookups:      bool,
 }
 
 enum JSONtypes {
@@ -354,6 +554,9 @@ enum JSONtypes {
     Integer,
     Boolean,
     Unsupported,
+    /// the column is not declared in the schema and --schema-columns-only
+    /// is set, so it is not mapped into the per-row JSON object at all
+    Skip,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -386,12 +589,27 @@ fn currency_format_checker(s: &str) -> bool {
 
 struct DynEnumValidator {
     dynenum_set: HashSet<String>,
+    // when set, dynenum_set was lowercased at compile time and instances
+    // are lowercased before lookup ("ci" URI modifier)
+    ignore_case: bool,
 }
 
 impl DynEnumValidator {
     #[allow(dead_code)]
-    const fn new(dynenum_set: HashSet<String>) -> Self {
-        Self { dynenum_set }
+    const fn new(dynenum_set: HashSet<String>, ignore_case: bool) -> Self {
+        Self {
+            dynenum_set,
+            ignore_case,
+        }
+    }
+
+    #[inline]
+    fn contains(&self, value: &str) -> bool {
+        if self.ignore_case {
+            self.dynenum_set.contains(&value.to_lowercase())
+        } else {
+            self.dynenum_set.contains(value)
+        }
     }
 }
 
@@ -402,7 +620,7 @@ impl Keyword for DynEnumValidator {
         instance: &'instance Value,
         instance_path: &LazyLocation,
     ) -> Result<(), ValidationError<'instance>> {
-        if self.dynenum_set.contains(instance.as_str().unwrap()) {
+        if self.contains(instance.as_str().unwrap()) {
             Ok(())
         } else {
             let error = ValidationError::custom(
@@ -418,7 +636,7 @@ impl Keyword for DynEnumValidator {
     #[inline]
     fn is_valid(&self, instance: &Value) -> bool {
         if let Value::String(s) = instance {
-            self.dynenum_set.contains(s)
+            self.contains(s)
         } else {
             false
         }
@@ -595,6 +813,271 @@ fn unique_combined_with_validator_factory<'a>(
     )))
 }
 
+/// custom keyword validator for "enumCaseInsensitive" - like the standard
+/// `enum` keyword, but matches string values case-insensitively
+struct EnumCaseInsensitiveValidator {
+    // the allowed values, lowercased when the schema is compiled
+    allowed_values: HashSet<String>,
+}
+
+impl Keyword for EnumCaseInsensitiveValidator {
+    fn validate<'instance>(
+        &self,
+        instance: &'instance Value,
+        instance_path: &LazyLocation,
+    ) -> Result<(), ValidationError<'instance>> {
+        if self.is_valid(instance) {
+            Ok(())
+        } else {
+            Err(ValidationError::custom(
+                Location::default(),
+                instance_path.into(),
+                instance,
+                format!("{instance} is not a valid enumCaseInsensitive value"),
+            ))
+        }
+    }
+
+    #[inline]
+    fn is_valid(&self, instance: &Value) -> bool {
+        if let Value::String(s) = instance {
+            self.allowed_values.contains(&s.to_lowercase())
+        } else {
+            false
+        }
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn enum_case_insensitive_validator_factory<'a>(
+    _parent: &'a Map<String, Value>,
+    value: &'a Value,
+    location: Location,
+) -> Result<Box<dyn Keyword>, ValidationError<'a>> {
+    let values = value.as_array().ok_or_else(|| {
+        ValidationError::custom(
+            Location::default(),
+            location.clone(),
+            value,
+            "'enumCaseInsensitive' must be an array of allowed values",
+        )
+    })?;
+
+    let mut allowed_values = HashSet::with_capacity(values.len());
+    for allowed in values {
+        let Some(allowed_str) = allowed.as_str() else {
+            return Err(ValidationError::custom(
+                Location::default(),
+                location.clone(),
+                allowed,
+                "'enumCaseInsensitive' values must be strings",
+            ));
+        };
+        allowed_values.insert(allowed_str.to_lowercase());
+    }
+
+    if allowed_values.is_empty() {
+        return Err(ValidationError::custom(
+            Location::default(),
+            location,
+            value,
+            "'enumCaseInsensitive' must specify at least one allowed value",
+        ));
+    }
+
+    Ok(Box::new(EnumCaseInsensitiveValidator { allowed_values }))
+}
+
+#[derive(Clone, Copy)]
+enum ComparisonOp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl ComparisonOp {
+    fn parse(op: &str) -> Option<Self> {
+        match op {
+            "<" => Some(Self::Lt),
+            "<=" => Some(Self::Le),
+            ">" => Some(Self::Gt),
+            ">=" => Some(Self::Ge),
+            "==" => Some(Self::Eq),
+            "!=" => Some(Self::Ne),
+            _ => None,
+        }
+    }
+
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Eq => "==",
+            Self::Ne => "!=",
+        }
+    }
+
+    fn compare(self, left: f64, right: f64) -> bool {
+        match self {
+            Self::Lt => left < right,
+            Self::Le => left <= right,
+            Self::Gt => left > right,
+            Self::Ge => left >= right,
+            Self::Eq => (left - right).abs() < f64::EPSILON,
+            Self::Ne => (left - right).abs() >= f64::EPSILON,
+        }
+    }
+}
+
+struct ColumnComparison {
+    left:  String,
+    op:    ComparisonOp,
+    right: String,
+}
+
+/// custom keyword validator for the top-level "columnComparisons" keyword,
+/// asserting cross-column numeric comparisons (e.g. GeoX < GeoY) that are
+/// not expressible in per-property JSON Schema
+struct ColumnComparisonsValidator {
+    comparisons: Vec<ColumnComparison>,
+}
+
+impl ColumnComparisonsValidator {
+    /// get a column's value as f64, erroring when it isn't numeric
+    fn numeric_value(obj: &Map<String, Value>, col: &str) -> Result<f64, String> {
+        match obj.get(col) {
+            Some(Value::Number(n)) => n
+                .as_f64()
+                .ok_or_else(|| format!("value of column \"{col}\" is not a valid number")),
+            Some(Value::String(s)) => s.parse::<f64>().map_err(|_| {
+                format!("value \"{s}\" of column \"{col}\" is not numeric")
+            }),
+            Some(Value::Null) | None => Err(format!("column \"{col}\" is null or missing")),
+            Some(_) => Err(format!("value of column \"{col}\" is not numeric")),
+        }
+    }
+
+    fn check(&self, obj: &Map<String, Value>) -> Result<(), (String, String)> {
+        for comparison in &self.comparisons {
+            let left = Self::numeric_value(obj, &comparison.left)
+                .map_err(|e| (comparison.left.clone(), e))?;
+            let right = Self::numeric_value(obj, &comparison.right)
+                .map_err(|e| (comparison.left.clone(), e))?;
+            if !comparison.op.compare(left, right) {
+                return Err((
+                    comparison.left.clone(),
+                    format!(
+                        "{} {} {} is not satisfied ({left} {} {right})",
+                        comparison.left,
+                        comparison.op.as_str(),
+                        comparison.right,
+                        comparison.op.as_str()
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Keyword for ColumnComparisonsValidator {
+    fn validate<'instance>(
+        &self,
+        instance: &'instance Value,
+        instance_path: &LazyLocation,
+    ) -> Result<(), ValidationError<'instance>> {
+        let obj = instance.as_object().ok_or_else(|| {
+            ValidationError::custom(
+                Location::default(),
+                instance_path.into(),
+                instance,
+                "Instance must be an object",
+            )
+        })?;
+
+        if let Err((left_column, error_msg)) = self.check(obj) {
+            // point the error at the left column so the per-row error
+            // report sets "field" to it
+            let error_location = instance_path.push(left_column.as_str());
+            return Err(ValidationError::custom(
+                Location::default(),
+                (&error_location).into(),
+                instance,
+                error_msg,
+            ));
+        }
+        Ok(())
+    }
+
+    fn is_valid(&self, instance: &Value) -> bool {
+        let Some(obj) = instance.as_object() else {
+            return false;
+        };
+        self.check(obj).is_ok()
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn column_comparisons_validator_factory<'a>(
+    _parent: &'a Map<String, Value>,
+    value: &'a Value,
+    location: Location,
+) -> Result<Box<dyn Keyword>, ValidationError<'a>> {
+    let comparison_specs = value.as_array().ok_or_else(|| {
+        ValidationError::custom(
+            Location::default(),
+            location.clone(),
+            value,
+            "'columnComparisons' must be an array of {left, op, right} objects",
+        )
+    })?;
+
+    let mut comparisons = Vec::with_capacity(comparison_specs.len());
+    for spec in comparison_specs {
+        let (Some(left), Some(op), Some(right)) = (
+            spec.get("left").and_then(Value::as_str),
+            spec.get("op").and_then(Value::as_str),
+            spec.get("right").and_then(Value::as_str),
+        ) else {
+            return Err(ValidationError::custom(
+                Location::default(),
+                location.clone(),
+                spec,
+                "each 'columnComparisons' entry must have string 'left', 'op' and 'right' keys",
+            ));
+        };
+        let Some(op) = ComparisonOp::parse(op) else {
+            return Err(ValidationError::custom(
+                Location::default(),
+                location.clone(),
+                spec,
+                "'columnComparisons' op must be one of: <, <=, >, >=, ==, !=",
+            ));
+        };
+        comparisons.push(ColumnComparison {
+            left: left.to_string(),
+            op,
+            right: right.to_string(),
+        });
+    }
+
+    if comparisons.is_empty() {
+        return Err(ValidationError::custom(
+            Location::default(),
+            location,
+            value,
+            "'columnComparisons' must specify at least one comparison",
+        ));
+    }
+
+    Ok(Box::new(ColumnComparisonsValidator { comparisons }))
+}
+
 /// Parse the dynamicEnum URI string to extract cache_name, final_uri, cache_age and column
 /// Format: "[cache_name;cache_age]|URL[|column]" where cache_name, cache_age and column are
 /// optional
@@ -625,7 +1108,8 @@ fn unique_combined_with_validator_factory<'a>(
 ///   - cache_name: Name to use for caching the lookup table
 ///   - final_uri: The actual URI/URL to load the lookup table from
 ///   - cache_age: How long to cache the lookup table in seconds
-///   - column: Optional column name/index to use from the lookup table
+///   - column: Optional column name/index (or comma-separated list of them) to use from the
+///     lookup table
 #[cfg(not(feature = "lite"))]
 fn parse_dynenum_uri(uri: &str) -> (String, String, i64, Option<String>) {
     const DEFAULT_CACHE_AGE_SECS: i64 = 3600; // 1 hour
@@ -845,6 +1329,7 @@ fn test_parse_dynenum_uri() {
 /// - "https://example.com/data.csv" - Cache as data.csv with 1 hour default cache
 /// - "custom_name;600|https://example.com/data.csv" - Cache as custom_name.csv for 600 seconds
 /// - "resname|ckan://1234567" - Get CKAN resource ID 1234567, cache as resname.csv
+/// - "lookup.csv|code,alias" - Valid if the value appears in EITHER the code or alias column
 ///
 /// # Arguments
 /// * `_parent` - Parent JSON Schema object (unused)
@@ -870,8 +1355,35 @@ fn dyn_enum_validator_factory<'a>(
         )
     })?;
 
+    // the trailing "|ci" modifier requests case-insensitive matching. It is
+    // stripped before URI parsing so it composes with both the caching config
+    // and the column selector, e.g. "product_cache;3600|lookup.csv|name|ci"
+    let (uri, ignore_case) = match uri.strip_suffix("|ci") {
+        Some(stripped) => (stripped, true),
+        None => (uri, false),
+    };
+
     let (lookup_name, final_uri, cache_age_secs, column) = parse_dynenum_uri(uri);
 
+    // with the polars feature, Parquet/Arrow/Avro/JSON/JSONL lookup files are
+    // transparently converted to CSV by Config's special format handling.
+    // Without it, error clearly instead of parsing the raw bytes as CSV
+    #[cfg(not(feature = "polars"))]
+    if std::path::Path::new(&final_uri.to_lowercase())
+        .extension()
+        .is_some_and(|ext| {
+            matches!(
+                ext.to_str(),
+                Some("parquet" | "arrow" | "ipc" | "avro" | "json" | "jsonl" | "ndjson")
+            )
+        })
+    {
+        return fail_validation_error!(
+            "dynamicEnum lookup file \"{final_uri}\" requires the polars feature. This qsv \
+             binary variant only supports CSV/TSV lookup files."
+        );
+    }
+
     // Create lookup table options
     let opts = LookupTableOptions {
         name: lookup_name,
@@ -882,6 +1394,7 @@ fn dyn_enum_validator_factory<'a>(
         ckan_api_url: CKAN_API.get().cloned(),
         ckan_token: CKAN_TOKEN.get().and_then(std::clone::Clone::clone),
         timeout_secs: TIMEOUT_SECS.load(Ordering::Relaxed),
+        force_refresh: REFRESH_LOOKUPS.load(Ordering::Relaxed),
     };
 
     // Load the lookup table
@@ -903,45 +1416,57 @@ fn dyn_enum_validator_factory<'a>(
         Err(e) => return fail_validation_error!("Error opening dynamicEnum file: {e}"),
     };
 
-    // Get column index based on name or default to first column
-    let column_idx = if let Some(col_name) = column {
-        // Try parsing as index first
-        if let Ok(idx) = col_name.parse::<usize>() {
-            idx
-        } else {
-            // Try finding column by name
-            match rdr.headers() {
-                Ok(headers) => {
-                    let idx = headers.iter().position(|h| h == col_name);
-                    match idx {
-                        Some(i) => i,
+    // Get the column indices to draw allowed values from, defaulting to the
+    // first column. Several comma-separated columns may be named or indexed
+    // (e.g. "lookup.csv|code,alias") - the enum set is the UNION of all of
+    // them, so a value is valid if it appears in ANY of the listed columns
+    let column_idxs: Vec<usize> = if let Some(ref col_spec) = column {
+        let mut idxs = Vec::with_capacity(col_spec.len());
+        for col_name in col_spec.split(',') {
+            // Try parsing as index first
+            if let Ok(idx) = col_name.parse::<usize>() {
+                idxs.push(idx);
+            } else {
+                // Try finding column by name
+                match rdr.headers() {
+                    Ok(headers) => match headers.iter().position(|h| h == col_name) {
+                        Some(i) => idxs.push(i),
                         None => {
                             return fail_validation_error!(
                                 "Column '{}' not found in lookup table",
                                 col_name
                             );
                         },
-                    }
-                },
-                Err(e) => return fail_validation_error!("Error reading headers: {e}"),
+                    },
+                    Err(e) => return fail_validation_error!("Error reading headers: {e}"),
+                }
             }
         }
+        idxs
     } else {
-        0
+        vec![0]
     };
 
     for result in rdr.records() {
         match result {
             Ok(record) => {
-                if let Some(value) = record.get(column_idx) {
-                    enum_set.insert(value.to_owned());
+                for &column_idx in &column_idxs {
+                    if let Some(value) = record.get(column_idx) {
+                        // the set is built lowercased once at schema compile time,
+                        // so per-row validation only lowercases the instance
+                        if ignore_case {
+                            enum_set.insert(value.to_lowercase());
+                        } else {
+                            enum_set.insert(value.to_owned());
+                        }
+                    }
                 }
             },
             Err(e) => return fail_validation_error!("Error reading dynamicEnum file - {e}"),
         }
     }
 
-    Ok(Box::new(DynEnumValidator::new(enum_set)))
+    Ok(Box::new(DynEnumValidator::new(enum_set, ignore_case)))
 }
 
 #[cfg(feature = "lite")]
@@ -957,11 +1482,35 @@ fn dyn_enum_validator_factory<'a>(
             Err(e) => return fail_validation_error!("Failed to create temporary file: {e}"),
         };
 
+        // the trailing "|ci" modifier requests case-insensitive matching
+        let (uri, ignore_case) = match uri.strip_suffix("|ci") {
+            Some(stripped) => (stripped, true),
+            None => (uri.as_str(), false),
+        };
+
         // Split URI to get column specification
         let parts: Vec<&str> = uri.split('|').collect();
         let base_uri = parts[0];
         let column = parts.get(1).map(std::string::ToString::to_string);
 
+        // qsvlite is not compiled with polars, so special format lookup
+        // files cannot be converted to CSV - error clearly instead of
+        // parsing the raw bytes as CSV
+        if std::path::Path::new(&base_uri.to_lowercase())
+            .extension()
+            .is_some_and(|ext| {
+                matches!(
+                    ext.to_str(),
+                    Some("parquet" | "arrow" | "ipc" | "avro" | "json" | "jsonl" | "ndjson")
+                )
+            })
+        {
+            return fail_validation_error!(
+                "dynamicEnum lookup file \"{base_uri}\" requires the polars feature. This qsv \
+                 binary variant only supports CSV/TSV lookup files."
+            );
+        }
+
         let dynenum_path = if base_uri.starts_with("http") {
             let valid_url = reqwest::Url::parse(base_uri).map_err(|e| {
                 ValidationError::custom(
@@ -1017,34 +1566,49 @@ fn dyn_enum_validator_factory<'a>(
             Err(e) => return fail_validation_error!("Error opening dynamicEnum file: {e}"),
         };
 
-        // Get column index based on name or default to first column
-        let column_idx = if let Some(col_name) = column {
-            // Try parsing as index first
-            if let Ok(idx) = col_name.parse::<usize>() {
-                idx
-            } else {
-                // Try finding column by name
-                match rdr.headers() {
-                    Ok(headers) => headers.iter().position(|h| h == col_name).unwrap_or(0),
-                    Err(_) => 0,
-                }
-            }
+        // Get the column indices to draw allowed values from, defaulting to
+        // the first column. Several comma-separated columns may be named or
+        // indexed (e.g. "lookup.csv|code,alias") - the enum set is the UNION
+        // of all of them
+        let column_idxs: Vec<usize> = if let Some(ref col_spec) = column {
+            col_spec
+                .split(',')
+                .map(|col_name| {
+                    // Try parsing as index first, then finding column by name
+                    if let Ok(idx) = col_name.parse::<usize>() {
+                        idx
+                    } else {
+                        match rdr.headers() {
+                            Ok(headers) => {
+                                headers.iter().position(|h| h == col_name).unwrap_or(0)
+                            },
+                            Err(_) => 0,
+                        }
+                    }
+                })
+                .collect()
         } else {
-            0
+            vec![0]
         };
 
         for result in rdr.records() {
             match result {
                 Ok(record) => {
-                    if let Some(value) = record.get(column_idx) {
-                        enum_set.insert(value.to_owned());
+                    for &column_idx in &column_idxs {
+                        if let Some(value) = record.get(column_idx) {
+                            if ignore_case {
+                                enum_set.insert(value.to_lowercase());
+                            } else {
+                                enum_set.insert(value.to_owned());
+                            }
+                        }
                     }
                 },
                 Err(e) => return fail_validation_error!("Error reading dynamicEnum file - {e}"),
             };
         }
 
-        Ok(Box::new(DynEnumValidator::new(enum_set)))
+        Ok(Box::new(DynEnumValidator::new(enum_set, ignore_case)))
     } else {
         Err(ValidationError::custom(
             Location::default(),
@@ -1056,7 +1620,131 @@ fn dyn_enum_validator_factory<'a>(
 }
 
 pub fn run(argv: &[&str]) -> CliResult<()> {
-    let args: Args = util::get_args(USAGE, argv)?;
+    let mut args: Args = util::get_args(USAGE, argv)?;
+
+    if args.flag_errors_format != "tsv" && args.flag_errors_format != "jsonl" {
+        return fail_incorrectusage_clierror!(
+            "Invalid --errors-format \"{}\". Valid values are \"tsv\" and \"jsonl\".",
+            args.flag_errors_format
+        );
+    }
+
+    if args.flag_json_pointer_errors && args.flag_errors_format != "jsonl" {
+        return fail_incorrectusage_clierror!(
+            "--json-pointer-errors requires --errors-format jsonl."
+        );
+    }
+
+    // two streams cannot be interleaved on one descriptor
+    if args.flag_valid_output.as_deref() == Some("-")
+        && args.flag_invalid_output.as_deref() == Some("-")
+    {
+        return fail_incorrectusage_clierror!(
+            "--valid-output and --invalid-output cannot both be set to stdout (\"-\")."
+        );
+    }
+
+    // --type-map is a dataset-level type check against the stats cache,
+    // not a per-row validation, so handle it upfront
+    if let Some(ref type_map_spec) = args.flag_type_map {
+        if args.arg_json_schema.is_some() {
+            return fail_incorrectusage_clierror!(
+                "--type-map cannot be used together with a JSON Schema file."
+            );
+        }
+        return validate_type_map(&args, type_map_spec);
+    }
+
+    // --cross-file-unique spans files, so like --type-map it is a
+    // dataset-level check handled upfront
+    if let Some(ref cross_file_cols) = args.flag_cross_file_unique {
+        if args.arg_json_schema.is_some() {
+            return fail_incorrectusage_clierror!(
+                "--cross-file-unique cannot be used together with a JSON Schema file."
+            );
+        }
+        return validate_cross_file_unique(&args, cross_file_cols);
+    }
+
+    // --enum is an ergonomic shortcut for "this column must be one of these values"
+    // without authoring a JSON Schema file. Synthesize a schema from the inline
+    // enum specs and run the usual JSON Schema validation with it.
+    if !args.flag_enum.is_empty() {
+        if args.arg_json_schema.is_some() {
+            return fail_incorrectusage_clierror!(
+                "--enum cannot be used together with a JSON Schema file."
+            );
+        }
+
+        let mut properties = Map::new();
+        for spec in &args.flag_enum {
+            let Some((col, values)) = spec.split_once('=') else {
+                return fail_incorrectusage_clierror!(
+                    "Invalid --enum spec \"{spec}\". Expected the form \
+                     <column>=<value1>,<value2>,..."
+                );
+            };
+            let allowed: Vec<Value> = values
+                .split(',')
+                .map(|v| Value::String(v.to_string()))
+                .collect();
+            properties.insert(col.to_string(), json!({ "enum": allowed }));
+        }
+        let schema = json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": properties
+        });
+
+        // write the synthesized schema to a temp file so it flows through
+        // the same schema loading/compilation path as a user-supplied schema
+        let temp_dir = crate::config::TEMP_FILE_DIR
+            .get_or_init(|| tempfile::TempDir::new().unwrap().keep());
+        let mut schema_file = tempfile::Builder::new()
+            .suffix(".schema.json")
+            .tempfile_in(temp_dir)?;
+        schema_file.write_all(schema.to_string().as_bytes())?;
+        let (_, schema_path) = schema_file
+            .keep()
+            .map_err(|e| format!("Failed to keep temporary schema file: {e}"))?;
+        args.arg_json_schema = Some(schema_path.to_string_lossy().to_string());
+    }
+
+    // --schema-text and a "-" schema argument supply the schema as an inline
+    // JSON string or on stdin, for shell scripts that generate schemas on the
+    // fly. Write the schema text to a temp file so it flows through the same
+    // schema loading/compilation path as a schema file.
+    let inline_schema = if let Some(ref schema_text) = args.flag_schema_text {
+        if args.arg_json_schema.is_some() {
+            return fail_incorrectusage_clierror!(
+                "--schema-text cannot be used together with a JSON Schema file."
+            );
+        }
+        Some(schema_text.clone())
+    } else if args.arg_json_schema.as_deref() == Some("-") {
+        if args.arg_input.is_none() || args.arg_input.as_deref() == Some("-") {
+            return fail_incorrectusage_clierror!(
+                "The CSV data must come from a file when reading the JSON Schema from stdin."
+            );
+        }
+        let mut schema_text = String::new();
+        std::io::stdin().read_to_string(&mut schema_text)?;
+        Some(schema_text)
+    } else {
+        None
+    };
+    if let Some(schema_text) = inline_schema {
+        let temp_dir = crate::config::TEMP_FILE_DIR
+            .get_or_init(|| tempfile::TempDir::new().unwrap().keep());
+        let mut schema_file = tempfile::Builder::new()
+            .suffix(".schema.json")
+            .tempfile_in(temp_dir)?;
+        schema_file.write_all(schema_text.as_bytes())?;
+        let (_, schema_path) = schema_file
+            .keep()
+            .map_err(|e| format!("Failed to keep temporary schema file: {e}"))?;
+        args.arg_json_schema = Some(schema_path.to_string_lossy().to_string());
+    }
 
     // Is the JSON Schema file valid?
     if args.cmd_schema {
@@ -1098,6 +1786,8 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
         util::timeout_secs(args.flag_timeout)? as u16,
         Ordering::Relaxed,
     );
+    #[cfg(not(feature = "lite"))]
+    REFRESH_LOOKUPS.store(args.flag_refresh_lookups, Ordering::Relaxed);
 
     let mut rconfig = Config::new(args.arg_input.as_ref())
         .no_headers(args.flag_no_headers)
@@ -1196,6 +1886,62 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
             }
         }
 
+        // if --autofix-lengths is set, repair ragged records by padding/truncating
+        // them to the header length instead of erroring out, writing the repaired
+        // data to <input>.fixed.csv and reporting how many rows were repaired
+        if args.flag_autofix_lengths {
+            rconfig = rconfig.flexible(true);
+            let mut flex_rdr = rconfig.reader()?;
+
+            let fixed_path = format!(
+                "{}.fixed.csv",
+                args.arg_input
+                    .clone()
+                    .unwrap_or_else(|| "stdin.csv".to_string())
+            );
+            let mut wtr = Config::new(Some(&fixed_path)).writer()?;
+            if !args.flag_no_headers {
+                wtr.write_byte_record(flex_rdr.byte_headers()?)?;
+            }
+
+            // with headers, records are repaired to the header length;
+            // without headers, to the length of the first record
+            let mut expected_len = if args.flag_no_headers { 0 } else { header_len };
+            let mut record = csv::ByteRecord::new();
+            let mut fixed_count: u64 = 0;
+            let mut record_idx: u64 = 0;
+            while flex_rdr.read_byte_record(&mut record)? {
+                record_idx += 1;
+                if expected_len == 0 {
+                    expected_len = record.len();
+                }
+                if record.len() == expected_len {
+                    wtr.write_byte_record(&record)?;
+                } else {
+                    fixed_count += 1;
+                    let mut fixed_record =
+                        csv::ByteRecord::with_capacity(record.as_slice().len(), expected_len);
+                    for field in record.iter().take(expected_len) {
+                        fixed_record.push_field(field);
+                    }
+                    for _ in record.len()..expected_len {
+                        fixed_record.push_field(b"");
+                    }
+                    wtr.write_byte_record(&fixed_record)?;
+                }
+            }
+            wtr.flush()?;
+
+            if !args.flag_quiet {
+                winfo!(
+                    "Repaired {} ragged record/s out of {}. Fixed output: {fixed_path}",
+                    HumanCount(fixed_count),
+                    HumanCount(record_idx)
+                );
+            }
+            return Ok(());
+        }
+
         // Now, let's validate the rest of the records the fastest way possible.
         // We do this by using csv::ByteRecord, which does not validate utf8
         // making for higher throughput and lower memory usage compared to csv::StringRecord
@@ -1210,6 +1956,24 @@ pub fn run(argv: &[&str]) -> CliResult<()> {
         'rfc4180_check: loop {
             result = rdr.read_byte_record(&mut record);
             if let Err(e) = result {
+                // when the error report format is JSONL, also emit the error as a
+                // single JSON object so it can be consumed programmatically
+                if args.flag_errors_format == "jsonl" {
+                    let input_path = args
+                        .arg_input
+                        .clone()
+                        .unwrap_or_else(|| "stdin.csv".to_string());
+                    let error_obj = json!({
+                        "row_number": record_idx + 1,
+                        "field": Value::Null,
+                        "error": format!("{e}"),
+                    });
+                    let mut jsonl_file =
+                        File::create(input_path + ".validation-errors.jsonl")?;
+                    jsonl_file.write_all(error_obj.to_string().as_bytes())?;
+                    jsonl_file.write_all(b"\n")?;
+                }
+
                 // read_byte_record() does not validate utf8, so we know this is not a utf8 error
                 if flag_json {
                     // we're returning a JSON error, so we have more machine-friendly details
@@ -1352,6 +2116,27 @@ Alternatively, transcode your data to UTF-8 first using `iconv` or `recode`."#
     let headers = rdr.byte_headers()?.clone();
     let header_len = headers.len();
 
+    // resolve --error-context columns to (column name, column index) pairs
+    // so we can include their values in each error row of the error report
+    let context_columns: Vec<(String, usize)> =
+        if let Some(ref error_context) = args.flag_error_context {
+            let mut context_columns = Vec::new();
+            for col_name in error_context.split(',') {
+                let col_name = col_name.trim();
+                match headers.iter().position(|h| h == col_name.as_bytes()) {
+                    Some(idx) => context_columns.push((col_name.to_string(), idx)),
+                    None => {
+                        return fail_incorrectusage_clierror!(
+                            "--error-context column \"{col_name}\" not found in headers."
+                        );
+                    },
+                }
+            }
+            context_columns
+        } else {
+            Vec::new()
+        };
+
     #[cfg(not(feature = "lite"))]
     let qsv_cache_dir = lookup::set_qsv_cache_dir(&args.flag_cache_dir)?;
     #[cfg(not(feature = "lite"))]
@@ -1375,6 +2160,10 @@ Alternatively, transcode your data to UTF-8 first using `iconv` or `recode`."#
         })
         .unwrap();
 
+    // whether the schema uses the stateful uniqueCombinedWith keyword,
+    // which requires validating rows in file order
+    let mut has_unique_combined = false;
+
     // parse and compile supplied JSON Schema
     let (schema_json, schema_compiled): (Value, Validator) =
         // safety: we know the schema is_some() because we checked above
@@ -1383,7 +2172,9 @@ Alternatively, transcode your data to UTF-8 first using `iconv` or `recode`."#
                 // Check for custom formats and keywords before parsing
                 let has_currency_format = s.contains(r#""format": "currency""#);
                 let has_dynamic_enum = s.contains("dynamicEnum");
-                let has_unique_combined = s.contains("uniqueCombinedWith");
+                has_unique_combined = s.contains("uniqueCombinedWith");
+                let has_column_comparisons = s.contains("columnComparisons");
+                let has_enum_case_insensitive = s.contains("enumCaseInsensitive");
 
                 // parse JSON string
                 let mut s_slice = s.as_bytes().to_vec();
@@ -1393,6 +2184,11 @@ Alternatively, transcode your data to UTF-8 first using `iconv` or `recode`."#
                         let mut validator_options = Validator::options()
                             .should_validate_formats(!args.flag_no_format_validation);
 
+                        // anchor for resolving the schema's non-absolute $refs
+                        if let Some(ref ref_base) = args.flag_ref_base {
+                            validator_options = validator_options.with_base_uri(ref_base_uri(ref_base));
+                        }
+
                         // Add custom validators based on pre-checked flags
                         if has_currency_format {
                             validator_options = validator_options.with_format("currency", currency_format_checker);
@@ -1406,6 +2202,14 @@ Alternatively, transcode your data to UTF-8 first using `iconv` or `recode`."#
                             validator_options = validator_options.with_keyword("uniqueCombinedWith", unique_combined_with_validator_factory);
                         }
 
+                        if has_column_comparisons {
+                            validator_options = validator_options.with_keyword("columnComparisons", column_comparisons_validator_factory);
+                        }
+
+                        if has_enum_case_insensitive {
+                            validator_options = validator_options.with_keyword("enumCaseInsensitive", enum_case_insensitive_validator_factory);
+                        }
+
                         if args.flag_fancy_regex {
                             let fancy_regex_options = PatternOptions::fancy_regex()
                                 .backtrack_limit(args.flag_backtrack_limit)
@@ -1448,7 +2252,7 @@ Try running `qsv validate schema {}` to check the JSON Schema file."#, args.arg_
     NULL_TYPE.set(Value::String("null".to_string())).unwrap();
 
     // get JSON types for each column in CSV file
-    let header_types = get_json_types(&headers, &schema_json)?;
+    let header_types = get_json_types(&headers, &schema_json, args.flag_schema_columns_only)?;
 
     // how many rows read and processed as batches
     let mut row_number: u64 = 0;
@@ -1467,9 +2271,39 @@ Try running `qsv validate schema {}` to check the JSON Schema file."#, args.arg_
     let mut batch_validation_results: Vec<Option<String>> = Vec::with_capacity(batch_size);
     let mut validation_error_messages: Vec<String> = Vec::with_capacity(50);
     let flag_trim = args.flag_trim;
+    let flag_coerce_booleans = args.flag_coerce_booleans;
+    // --relaxed-numbers: resolve the decimal mark hint upfront
+    let relaxed_numbers = match args.flag_relaxed_numbers.as_deref() {
+        None => None,
+        Some(".") => Some(b'.'),
+        Some(",") => Some(b','),
+        Some(mark) => {
+            return fail_incorrectusage_clierror!(
+                "Invalid --relaxed-numbers decimal mark \"{mark}\". Valid marks are \".\" and \
+                 \",\"."
+            );
+        },
+    };
     let flag_fail_fast = args.flag_fail_fast;
+    let flag_json_pointer_errors = args.flag_json_pointer_errors;
+    let report_slow_ms = args.flag_report_slow;
+    let max_record_bytes = args.flag_max_record_bytes;
+    // (row number, elapsed ms) of rows exceeding the --report-slow threshold,
+    // accumulated across parallel batches and reported after the batch loop
+    let slow_rows: std::sync::Mutex<Vec<(u64, u128)>> = std::sync::Mutex::new(Vec::new());
     let mut itoa_buffer = itoa::Buffer::new();
-    let batch_pariter_min_len = batch_size / num_jobs;
+    let batch_pariter_min_len = if has_unique_combined {
+        // uniqueCombinedWith tracks seen combinations across rows, so validate
+        // each batch as one Rayon task, in file order. This guarantees the
+        // FIRST occurrence of a combination passes and the later duplicates
+        // are the ones flagged, regardless of --jobs
+        batch_size
+    } else {
+        batch_size / num_jobs
+    };
+
+    // time the validation loop for the --output-stats run metrics
+    let validation_start = std::time::Instant::now();
 
     // main loop to read CSV and construct batches for parallel processing.
     // each batch is processed via Rayon parallel iterator.
@@ -1504,8 +2338,39 @@ Try running `qsv validate schema {}` to check the JSON Schema file."#, args.arg_
             .par_iter()
             .with_min_len(batch_pariter_min_len)
             .map(|record| {
+                // flag pathological records exceeding --max-record-bytes as
+                // invalid instead of attempting to validate them
+                if let Some(max_record_bytes) = max_record_bytes {
+                    // exclude the row number field we appended to the record
+                    let record_bytes =
+                        (record.as_slice().len() - record[header_len].len()) as u64;
+                    if record_bytes > max_record_bytes {
+                        // safety: row number was added as last column. We can do index
+                        // access, not use get(), and unwrap_unchecked safely since we
+                        // know its there
+                        let row_number_string = unsafe {
+                            simdutf8::basic::from_utf8(&record[header_len]).unwrap_unchecked()
+                        };
+                        let context_suffix = error_context_suffix(&context_columns, record);
+                        // record-level errors have no pointers, but the jsonl report
+                        // still expects the two pointer fields when they're enabled
+                        let pointer_suffix = if flag_json_pointer_errors { "\t\t" } else { "" };
+                        return Some(format!(
+                            "{row_number_string}\t<RECORD>\trecord size {record_bytes} bytes \
+                             exceeds --max-record-bytes \
+                             {max_record_bytes}{pointer_suffix}{context_suffix}"
+                        ));
+                    }
+                }
+
                 // convert CSV record to JSON instance
-                let json_instance = match to_json_instance(&header_types, header_len, record) {
+                let json_instance = match to_json_instance(
+                    &header_types,
+                    header_len,
+                    record,
+                    flag_coerce_booleans,
+                    relaxed_numbers,
+                ) {
                     Ok(obj) => obj,
                     Err(e) => {
                         // Only convert to string when we have an error
@@ -1514,12 +2379,35 @@ Try running `qsv validate schema {}` to check the JSON Schema file."#, args.arg_
                         let row_number_string = unsafe {
                             simdutf8::basic::from_utf8(&record[header_len]).unwrap_unchecked()
                         };
-                        return Some(format!("{row_number_string}\t<RECORD>\t{e}"));
+                        let context_suffix = error_context_suffix(&context_columns, record);
+                        // record-level errors have no pointers, but the jsonl report
+                        // still expects the two pointer fields when they're enabled
+                        let pointer_suffix = if flag_json_pointer_errors { "\t\t" } else { "" };
+                        return Some(format!(
+                            "{row_number_string}\t<RECORD>\t{e}{pointer_suffix}{context_suffix}"
+                        ));
                     },
                 };
 
-                // validate JSON instance against JSON Schema
-                match schema_compiled.apply(&json_instance).basic() {
+                // validate JSON instance against JSON Schema,
+                // timing the validation when --report-slow is set
+                let start_instant = report_slow_ms.map(|_| std::time::Instant::now());
+                let validation_output = schema_compiled.apply(&json_instance).basic();
+                if let Some(threshold_ms) = report_slow_ms
+                    && let Some(start_instant) = start_instant
+                {
+                    let elapsed_ms = start_instant.elapsed().as_millis();
+                    if elapsed_ms > u128::from(threshold_ms) {
+                        // safety: see safety comment above
+                        let row_number_string = unsafe {
+                            simdutf8::basic::from_utf8(&record[header_len]).unwrap_unchecked()
+                        };
+                        if let Ok(slow_row_number) = row_number_string.parse::<u64>() {
+                            slow_rows.lock().unwrap().push((slow_row_number, elapsed_ms));
+                        }
+                    }
+                }
+                match validation_output {
                     BasicOutput::Valid(_) => None,
                     BasicOutput::Invalid(errors) => {
                         // Only convert to string when we have validation errors
@@ -1527,6 +2415,7 @@ Try running `qsv validate schema {}` to check the JSON Schema file."#, args.arg_
                         let row_number_string = unsafe {
                             simdutf8::basic::from_utf8(&record[header_len]).unwrap_unchecked()
                         };
+                        let context_suffix = error_context_suffix(&context_columns, record);
 
                         // Preallocate the vector with the known size
                         let mut error_messages = Vec::with_capacity(errors.len());
@@ -1534,8 +2423,29 @@ Try running `qsv validate schema {}` to check the JSON Schema file."#, args.arg_
                         // there can be multiple validation errors for a single record,
                         // squash multiple errors into one long String with linebreaks
                         for e in errors {
+                            // surface which conditional branch failed, if any,
+                            // so `if`/`then`/`else` violations are self-explanatory
+                            let keyword_location = e.keyword_location().as_str();
+                            let branch = if keyword_location.contains("/then") {
+                                " (failed \"then\" branch)"
+                            } else if keyword_location.contains("/else") {
+                                " (failed \"else\" branch)"
+                            } else {
+                                ""
+                            };
+                            // the instance/schema pointers of the failing constraint,
+                            // appended as extra fields for the jsonl error report
+                            let pointer_suffix = if flag_json_pointer_errors {
+                                format!(
+                                    "\t{instance}\t{keyword_location}",
+                                    instance = e.instance_location().as_str()
+                                )
+                            } else {
+                                String::new()
+                            };
                             error_messages.push(format!(
-                                "{row_number_string}\t{field}\t{error}",
+                                "{row_number_string}\t{field}\t{error}{branch}{pointer_suffix}\
+                                 {context_suffix}",
                                 field = e.instance_location().as_str().trim_start_matches('/'),
                                 error = e.error_description()
                             ));
@@ -1571,6 +2481,26 @@ Try running `qsv validate schema {}` to check the JSON Schema file."#, args.arg_
         }
     } // end batch loop
 
+    if let Some(threshold_ms) = report_slow_ms {
+        // safety: the batch loop is done, nothing else holds the lock
+        let mut slow_rows = slow_rows.into_inner().unwrap();
+        if slow_rows.is_empty() {
+            winfo!("No rows took longer than {threshold_ms} ms to validate.");
+        } else {
+            slow_rows.sort_unstable();
+            for (slow_row_number, elapsed_ms) in &slow_rows {
+                wwarn!(
+                    "row {slow_row_number}: validation took {elapsed_ms} ms (threshold: \
+                     {threshold_ms} ms)"
+                );
+            }
+            wwarn!(
+                "{} row/s took longer than {threshold_ms} ms to validate.",
+                slow_rows.len()
+            );
+        }
+    }
+
     #[cfg(any(feature = "feature_capable", feature = "lite"))]
     if show_progress {
         progress.set_message(format!(
@@ -1580,7 +2510,90 @@ Try running `qsv validate schema {}` to check the JSON Schema file."#, args.arg_
         util::finish_progress(&progress);
     }
 
-    if invalid_count == 0 {
+    // write the run metrics now, before the valid/invalid branching below,
+    // as both branches can exit the command early
+    if let Some(output_stats) = &args.flag_output_stats {
+        let elapsed = validation_start.elapsed();
+        let elapsed_secs = elapsed.as_secs_f64();
+        // estimate peak memory with the process's current resident set size.
+        // the validation buffers are still live at this point, so this is a
+        // reasonable lower-bound estimate of the run's peak
+        let peak_memory_bytes = sysinfo::get_current_pid().ok().and_then(|pid| {
+            let mut sys = sysinfo::System::new();
+            sys.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), true);
+            sys.process(pid).map(sysinfo::Process::memory)
+        });
+        let run_stats = json!({
+            "record_count": row_number,
+            "invalid_count": invalid_count,
+            "elapsed_ms": elapsed.as_millis() as u64,
+            // elapsed is nonzero once any record has been read, but clamp the
+            // denominator anyway so a degenerate timer never yields inf/NaN
+            "rows_per_sec": row_number as f64 / elapsed_secs.max(f64::EPSILON),
+            "jobs": num_jobs,
+            "batch_size": batch_size,
+            "parallel": num_jobs > 1,
+            "fail_fast": flag_fail_fast,
+            "format_validation": !args.flag_no_format_validation,
+            "peak_memory_bytes": peak_memory_bytes,
+        });
+        let stats_json = serde_json::to_string_pretty(&run_stats).unwrap();
+        if output_stats == "-" {
+            let stdout = std::io::stdout();
+            let mut handle = stdout.lock();
+            handle.write_all(stats_json.as_bytes())?;
+            handle.flush()?;
+        } else {
+            let mut stats_file = File::create(output_stats)?;
+            stats_file.write_all(stats_json.as_bytes())?;
+            stats_file.flush()?;
+        }
+    }
+
+    // evaluate the dataset-level minRecords/maxRecords assertions now that the
+    // row stream is complete. Violations are reported with row_number 0.
+    // skipped when fail-fast stopped the stream early, as the record count
+    // is then incomplete
+    let mut dataset_error_count = 0_u64;
+    if !(flag_fail_fast && invalid_count > 0) {
+        if let Some(max_records) = schema_json.get("maxRecords").and_then(Value::as_u64)
+            && row_number > max_records
+        {
+            dataset_error_count += 1;
+            // dataset-level errors point at the whole document (empty pointer)
+            let pointer_suffix = if args.flag_json_pointer_errors {
+                "\t\t/maxRecords"
+            } else {
+                ""
+            };
+            validation_error_messages.insert(
+                0,
+                format!(
+                    "0\t\tnumber of records {row_number} is greater than maxRecords \
+                     {max_records}{pointer_suffix}"
+                ),
+            );
+        }
+        if let Some(min_records) = schema_json.get("minRecords").and_then(Value::as_u64)
+            && row_number < min_records
+        {
+            dataset_error_count += 1;
+            let pointer_suffix = if args.flag_json_pointer_errors {
+                "\t\t/minRecords"
+            } else {
+                ""
+            };
+            validation_error_messages.insert(
+                0,
+                format!(
+                    "0\t\tnumber of records {row_number} is less than minRecords \
+                     {min_records}{pointer_suffix}"
+                ),
+            );
+        }
+    }
+
+    if invalid_count == 0 && dataset_error_count == 0 {
         // no invalid records found
         // see if we need to pass all valid records to output
         if let Some(valid_output) = args.flag_valid_output {
@@ -1607,18 +2620,68 @@ Try running `qsv validate schema {}` to check the JSON Schema file."#, args.arg_
     } else {
         // there are invalid records. write out invalid/valid/errors output files.
         // if 100% invalid, valid file isn't needed, but this is rare so OK creating empty file.
-        woutinfo!("Writing invalid/valid/error files...");
+        if args.flag_invalid_output.as_deref() == Some("-") {
+            // stdout carries the invalid record stream, so keep messages on stderr
+            winfo!("Writing valid/error files & streaming invalid records to stdout...");
+        } else {
+            woutinfo!("Writing invalid/valid/error files...");
+        }
 
         let input_path = args
             .arg_input
             .clone()
             .unwrap_or_else(|| "stdin.csv".to_string());
 
-        write_error_report(&input_path, validation_error_messages)?;
+        // report an error-type histogram to stderr so the worst offenders can
+        // be triaged without grepping the error report. The specific value is
+        // normalized out of each message so identical error types group
+        // together. This only adds a summary block - the TSV/valid/invalid
+        // outputs are unchanged
+        if !args.flag_quiet {
+            let mut error_type_counts: HashMap<String, u64> = HashMap::new();
+            for error_msg in &validation_error_messages {
+                for line in error_msg.lines() {
+                    // the message format is "row_number\tfield\terror[\tcontext...]"
+                    let error = line.split('\t').nth(2).unwrap_or(line);
+                    // strip the leading quoted value, e.g.
+                    // `"Orange" is not a valid dynamicEnum value`
+                    // aggregates as `is not a valid dynamicEnum value`
+                    let normalized = if let Some(stripped) = error.strip_prefix('"')
+                        && let Some(quote_end) = stripped.find('"')
+                    {
+                        stripped[quote_end + 1..].trim_start()
+                    } else {
+                        error
+                    };
+                    *error_type_counts.entry(normalized.to_string()).or_insert(0) += 1;
+                }
+            }
+            let mut sorted_counts: Vec<(String, u64)> = error_type_counts.into_iter().collect();
+            // most frequent first, ties broken by message for deterministic output
+            sorted_counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            winfo!("Error type summary:");
+            for (error_type, count) in sorted_counts {
+                winfo!("  {error_type}: {count}");
+            }
+        }
+
+        write_error_report(
+            &input_path,
+            validation_error_messages,
+            &context_columns,
+            args.flag_errors_format == "jsonl",
+            args.flag_json_pointer_errors,
+        )?;
 
         let valid_suffix = args.flag_valid.unwrap_or_else(|| "valid".to_string());
         let invalid_suffix = args.flag_invalid.unwrap_or_else(|| "invalid".to_string());
 
+        // the valid/invalid outputs keep the input's delimiter (unless
+        // overridden) so TSV/SSV inputs round-trip losslessly
+        let out_delimiter = args
+            .flag_out_delimiter
+            .map_or_else(|| rconfig.get_delimiter(), Delimiter::as_byte);
+
         split_invalid_records(
             &rconfig,
             &valid_flags[..],
@@ -1626,6 +2689,8 @@ Try running `qsv validate schema {}` to check the JSON Schema file."#, args.arg_
             &input_path,
             &valid_suffix,
             &invalid_suffix,
+            args.flag_invalid_output.as_ref(),
+            out_delimiter,
         )?;
 
         // done with validation; print output
@@ -1638,8 +2703,14 @@ Try running `qsv validate schema {}` to check the JSON Schema file."#, args.arg_
             String::new()
         };
 
+        let dataset_msg = if dataset_error_count > 0 {
+            format!("{dataset_error_count} dataset-level error/s.\n")
+        } else {
+            String::new()
+        };
+
         return fail_clierror!(
-            "{fail_fast_msg}{} out of {} records invalid.",
+            "{fail_fast_msg}{dataset_msg}{} out of {} records invalid.",
             HumanCount(invalid_count),
             HumanCount(row_number)
         );
@@ -1651,6 +2722,169 @@ Try running `qsv validate schema {}` to check the JSON Schema file."#, args.arg_
     Ok(())
 }
 
+/// validate that each column listed in the --type-map spec has the expected
+/// inferred type, using the stats cache (computing stats if needed).
+/// This is a dataset-level type check - no per-row validation is done.
+fn validate_type_map(args: &Args, type_map_spec: &str) -> CliResult<()> {
+    const VALID_TYPES: [&str; 7] = [
+        "Integer", "Float", "String", "Date", "DateTime", "Boolean", "NULL",
+    ];
+
+    let schema_args = util::SchemaArgs {
+        flag_enum_threshold:  0,
+        flag_ignore_case:     false,
+        flag_strict_dates:    false,
+        flag_pattern_columns: crate::select::SelectColumns::parse("").unwrap(),
+        flag_dates_whitelist: String::new(),
+        flag_prefer_dmy:      false,
+        flag_force:           false,
+        flag_stdout:          false,
+        flag_jobs:            Some(util::njobs(args.flag_jobs)),
+        flag_polars:          false,
+        flag_no_headers:      args.flag_no_headers,
+        flag_delimiter:       args.flag_delimiter,
+        arg_input:            args.arg_input.clone(),
+        flag_memcheck:        false,
+    };
+    let (csv_fields, csv_stats, _) = util::get_stats_records(&schema_args, util::StatsMode::Schema)?;
+    if csv_fields.is_empty() || csv_stats.len() != csv_fields.len() {
+        return fail_clierror!(
+            "Cannot compile stats for the type check. Note that --type-map requires a file input, \
+             not stdin."
+        );
+    }
+
+    // map each column name to its inferred type
+    let inferred_types: HashMap<String, String> = csv_fields
+        .iter()
+        .zip(csv_stats.iter())
+        .map(|(field, stats_record)| {
+            (
+                String::from_utf8_lossy(field).to_string(),
+                stats_record.r#type.clone(),
+            )
+        })
+        .collect();
+
+    let mut checked_count = 0_u64;
+    let mut mismatch_count = 0_u64;
+    for pair in type_map_spec.split(',') {
+        let Some((col, expected_type)) = pair.split_once('=') else {
+            return fail_incorrectusage_clierror!(
+                "Invalid --type-map pair \"{pair}\". Expected the form <column>=<type>."
+            );
+        };
+        let col = col.trim();
+        let expected_type = expected_type.trim();
+        if !VALID_TYPES
+            .iter()
+            .any(|valid_type| valid_type.eq_ignore_ascii_case(expected_type))
+        {
+            return fail_incorrectusage_clierror!(
+                "Invalid --type-map type \"{expected_type}\" for column \"{col}\". Valid types \
+                 are: {}.",
+                VALID_TYPES.join(", ")
+            );
+        }
+        let Some(inferred_type) = inferred_types.get(col) else {
+            return fail_incorrectusage_clierror!("--type-map column \"{col}\" not found.");
+        };
+        checked_count += 1;
+        if !inferred_type.eq_ignore_ascii_case(expected_type) {
+            mismatch_count += 1;
+            werr!("column \"{col}\": expected type {expected_type}, got {inferred_type}");
+        }
+    }
+
+    if mismatch_count > 0 {
+        return fail_clierror!(
+            "{mismatch_count} out of {checked_count} column/s failed the type check."
+        );
+    }
+    if !args.flag_quiet {
+        winfo!("All {checked_count} column/s passed the type check.");
+    }
+    Ok(())
+}
+
+/// --cross-file-unique: check that the combined key of the given columns is
+/// unique across all validated files, reporting each collision with the file
+/// name and row number of both occurrences
+fn validate_cross_file_unique(args: &Args, cols: &str) -> CliResult<()> {
+    let tmpdir = tempfile::tempdir()?;
+    let inputs = util::process_input(
+        args.arg_input.iter().map(std::path::PathBuf::from).collect(),
+        &tmpdir,
+        "",
+    )?;
+
+    let sel_cols = match crate::select::SelectColumns::parse(cols) {
+        Ok(sel) => sel,
+        Err(e) => {
+            return fail_incorrectusage_clierror!("Invalid --cross-file-unique spec: {e}");
+        },
+    };
+
+    // combined key -> (file name, 1-based row number) of its first occurrence
+    let mut seen: HashMap<Vec<u8>, (String, u64)> = HashMap::new();
+    let mut checked_count = 0_u64;
+    let mut collision_count = 0_u64;
+
+    for path in &inputs {
+        let input = path.to_string_lossy().to_string();
+        let conf = Config::new(Some(&input))
+            .delimiter(args.flag_delimiter)
+            .no_headers(args.flag_no_headers)
+            .select(sel_cols.clone());
+        let mut rdr = conf.reader()?;
+        let byte_headers = rdr.byte_headers()?.clone();
+        let sel = conf.selection(&byte_headers)?;
+
+        let mut record = ByteRecord::new();
+        let mut row_number = 0_u64;
+        while rdr.read_byte_record(&mut record)? {
+            row_number += 1;
+            checked_count += 1;
+
+            // join the selected fields with an ASCII unit separator so
+            // multi-column keys cannot collide by concatenation
+            let mut key: Vec<u8> = Vec::with_capacity(32);
+            for (i, field) in sel.select(&record).enumerate() {
+                if i > 0 {
+                    key.push(b'\x1f');
+                }
+                key.extend_from_slice(field);
+            }
+
+            if let Some((first_file, first_row)) = seen.get(&key) {
+                collision_count += 1;
+                werr!(
+                    "duplicate key at \"{input}\" row {row_number}: first seen at \
+                     \"{first_file}\" row {first_row}"
+                );
+            } else {
+                seen.insert(key, (input.clone(), row_number));
+            }
+        }
+    }
+
+    if collision_count > 0 {
+        return fail_clierror!(
+            "{collision_count} out of {checked_count} row/s failed the cross-file uniqueness \
+             check across {} file/s.",
+            inputs.len()
+        );
+    }
+    if !args.flag_quiet {
+        winfo!(
+            "All {checked_count} row/s across {} file/s passed the cross-file uniqueness check.",
+            inputs.len()
+        );
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
 fn split_invalid_records(
     rconfig: &Config,
     valid_flags: &BitSlice,
@@ -1658,18 +2892,29 @@ fn split_invalid_records(
     input_path: &str,
     valid_suffix: &str,
     invalid_suffix: &str,
+    invalid_output: Option<&String>,
+    out_delimiter: u8,
 ) -> CliResult<()> {
     // track how many rows read for splitting into valid/invalid
     // should not exceed row_number when aborted early due to fail-fast
     let mut split_row_num: usize = 0;
 
     // prepare output writers
-    let mut valid_wtr =
-        Config::new(Some(input_path.to_owned() + "." + valid_suffix).as_ref()).writer()?;
+    let mut valid_wtr = Config::new(Some(input_path.to_owned() + "." + valid_suffix).as_ref())
+        .delimiter(Some(Delimiter(out_delimiter)))
+        .writer()?;
     valid_wtr.write_byte_record(headers)?;
 
-    let mut invalid_wtr =
-        Config::new(Some(input_path.to_owned() + "." + invalid_suffix).as_ref()).writer()?;
+    // --invalid-output streams invalid records to the given file
+    // ("-" for stdout) instead of the ".invalid" sidecar file
+    let invalid_path = match invalid_output {
+        Some(file) if file == "-" => None,
+        Some(file) => Some(file.clone()),
+        None => Some(input_path.to_owned() + "." + invalid_suffix),
+    };
+    let mut invalid_wtr = Config::new(invalid_path.as_ref())
+        .delimiter(Some(Delimiter(out_delimiter)))
+        .writer()?;
     invalid_wtr.write_byte_record(headers)?;
 
     let mut rdr = rconfig.reader()?;
@@ -1697,16 +2942,93 @@ fn split_invalid_records(
     Ok(())
 }
 
-fn write_error_report(input_path: &str, validation_error_messages: Vec<String>) -> CliResult<()> {
+/// build the tab-prefixed context column values appended to each error row
+/// returns an empty string when --error-context is not set
+#[inline]
+fn error_context_suffix(context_columns: &[(String, usize)], record: &ByteRecord) -> String {
+    let mut context_suffix = String::new();
+    for (_, idx) in context_columns {
+        context_suffix.push('\t');
+        context_suffix.push_str(&String::from_utf8_lossy(&record[*idx]));
+    }
+    context_suffix
+}
+
+fn write_error_report(
+    input_path: &str,
+    validation_error_messages: Vec<String>,
+    context_columns: &[(String, usize)],
+    jsonl: bool,
+    json_pointers: bool,
+) -> CliResult<()> {
     let wtr_capacitys = env::var("QSV_WTR_BUFFER_CAPACITY")
         .unwrap_or_else(|_| DEFAULT_WTR_BUFFER_CAPACITY.to_string());
     let wtr_buffer_size: usize = wtr_capacitys.parse().unwrap_or(DEFAULT_WTR_BUFFER_CAPACITY);
 
+    if jsonl {
+        // write each error as one JSON object per line, which is easier to
+        // consume programmatically than TSV, particularly when error messages
+        // contain tabs or newlines
+        let output_file = File::create(input_path.to_owned() + ".validation-errors.jsonl")?;
+        let mut output_writer = BufWriter::with_capacity(wtr_buffer_size, output_file);
+        let ctx_len = context_columns.len();
+        // --json-pointer-errors appends the instance & schema pointers as two
+        // extra fields between the error and the context column values
+        let ptr_len = if json_pointers { 2 } else { 0 };
+
+        for error_msg in validation_error_messages {
+            for error_line in error_msg.split('\n') {
+                let parts: Vec<&str> = error_line.split('\t').collect();
+                if parts.len() < 3 + ptr_len + ctx_len {
+                    continue;
+                }
+                let mut error_object = Map::with_capacity(3 + ptr_len + ctx_len);
+                error_object.insert(
+                    "row_number".to_string(),
+                    parts[0]
+                        .parse::<u64>()
+                        .map_or_else(|_| json!(parts[0]), |row_number| json!(row_number)),
+                );
+                error_object.insert("field".to_string(), json!(parts[1]));
+                // the error message itself may contain tabs - the pointer and context
+                // column values are the trailing fields, everything in between is the
+                // error
+                error_object.insert(
+                    "error".to_string(),
+                    json!(parts[2..parts.len() - ptr_len - ctx_len].join("\t")),
+                );
+                if json_pointers {
+                    error_object.insert(
+                        "instance_pointer".to_string(),
+                        json!(parts[parts.len() - ctx_len - 2]),
+                    );
+                    error_object.insert(
+                        "schema_pointer".to_string(),
+                        json!(parts[parts.len() - ctx_len - 1]),
+                    );
+                }
+                for (i, (col_name, _)) in context_columns.iter().enumerate() {
+                    error_object
+                        .insert(col_name.clone(), json!(parts[parts.len() - ctx_len + i]));
+                }
+                output_writer.write_all(Value::Object(error_object).to_string().as_bytes())?;
+                output_writer.write_all(b"\n")?;
+            }
+        }
+        output_writer.flush()?;
+        return Ok(());
+    }
+
     let output_file = File::create(input_path.to_owned() + ".validation-errors.tsv")?;
 
     let mut output_writer = BufWriter::with_capacity(wtr_buffer_size, output_file);
 
-    output_writer.write_all(b"row_number\tfield\terror\n")?;
+    output_writer.write_all(b"row_number\tfield\terror")?;
+    for (col_name, _) in context_columns {
+        output_writer.write_all(b"\t")?;
+        output_writer.write_all(col_name.as_bytes())?;
+    }
+    output_writer.write_all(b"\n")?;
 
     // write out error report
     for error_msg in validation_error_messages {
@@ -1728,12 +3050,17 @@ fn to_json_instance(
     header_types: &[(String, JSONtypes)],
     header_len: usize,
     record: &ByteRecord,
+    coerce_booleans: bool,
+    relaxed_numbers: Option<u8>,
 ) -> CliResult<Value> {
     let mut json_object_map = Map::with_capacity(header_len);
 
     let mut json_value;
 
     for ((key, json_type), value) in header_types.iter().zip(record.iter()) {
+        if matches!(json_type, JSONtypes::Skip) {
+            continue;
+        }
         if value.is_empty() {
             json_object_map.insert(key.clone(), Value::Null);
             continue;
@@ -1750,7 +3077,12 @@ fn to_json_instance(
                 }
             },
             JSONtypes::Number => {
-                if let Ok(float) = fast_float2::parse(value) {
+                let parse_result = if let Some(decimal_mark) = relaxed_numbers {
+                    fast_float2::parse(normalize_relaxed_number(value, decimal_mark))
+                } else {
+                    fast_float2::parse(value)
+                };
+                if let Ok(float) = parse_result {
                     Value::Number(Number::from_f64(float).unwrap_or_else(|| Number::from(0)))
                 } else {
                     return fail_clierror!(
@@ -1760,7 +3092,12 @@ fn to_json_instance(
                 }
             },
             JSONtypes::Integer => {
-                if let Ok(int) = atoi_simd::parse::<i64>(value) {
+                let parse_result = if let Some(decimal_mark) = relaxed_numbers {
+                    atoi_simd::parse::<i64>(&normalize_relaxed_number(value, decimal_mark))
+                } else {
+                    atoi_simd::parse::<i64>(value)
+                };
+                if let Ok(int) = parse_result {
                     Value::Number(Number::from(int))
                 } else {
                     return fail_clierror!(
@@ -1772,6 +3109,24 @@ fn to_json_instance(
             JSONtypes::Boolean => match value {
                 b"true" | b"1" => Value::Bool(true),
                 b"false" | b"0" => Value::Bool(false),
+                // --coerce-booleans: also accept common textual boolean
+                // representations, case-insensitively
+                _ if coerce_booleans
+                    && (value.eq_ignore_ascii_case(b"true")
+                        || value.eq_ignore_ascii_case(b"t")
+                        || value.eq_ignore_ascii_case(b"yes")
+                        || value.eq_ignore_ascii_case(b"y")) =>
+                {
+                    Value::Bool(true)
+                },
+                _ if coerce_booleans
+                    && (value.eq_ignore_ascii_case(b"false")
+                        || value.eq_ignore_ascii_case(b"f")
+                        || value.eq_ignore_ascii_case(b"no")
+                        || value.eq_ignore_ascii_case(b"n")) =>
+                {
+                    Value::Bool(false)
+                },
                 _ => {
                     return fail_clierror!(
                         "Can't cast to Boolean. key: {key}, value: {}",
@@ -1779,7 +3134,9 @@ fn to_json_instance(
                     );
                 },
             },
-            JSONtypes::Unsupported => unreachable!("we should never get an unsupported JSON type"),
+            JSONtypes::Unsupported | JSONtypes::Skip => {
+                unreachable!("we should never get an unsupported or skipped JSON type")
+            },
         };
 
         json_object_map.insert(key.clone(), json_value);
@@ -1788,10 +3145,27 @@ fn to_json_instance(
     Ok(Value::Object(json_object_map))
 }
 
+/// --relaxed-numbers: strip thousands separators and normalize the decimal
+/// mark to '.', so localized numbers like "1,234.56" and "1.234,56" can be
+/// coerced to JSON numbers
+#[inline]
+fn normalize_relaxed_number(value: &[u8], decimal_mark: u8) -> Vec<u8> {
+    let thousands_separator = if decimal_mark == b',' { b'.' } else { b',' };
+    value
+        .iter()
+        .filter(|&&b| b != thousands_separator)
+        .map(|&b| if b == decimal_mark { b'.' } else { b })
+        .collect()
+}
+
 /// get JSON types for each column in CSV file
 /// returns a Vector of tuples of column/header name (String) & JSON type (JSONtypes enum)
 #[inline]
-fn get_json_types(headers: &ByteRecord, schema: &Value) -> CliResult<Vec<(String, JSONtypes)>> {
+fn get_json_types(
+    headers: &ByteRecord,
+    schema: &Value,
+    schema_columns_only: bool,
+) -> CliResult<Vec<(String, JSONtypes)>> {
     // make sure schema has expected structure
     let Some(schema_properties) = schema.get("properties") else {
         return fail_clierror!("JSON Schema missing 'properties' object");
@@ -1813,6 +3187,27 @@ fn get_json_types(headers: &ByteRecord, schema: &Value) -> CliResult<Vec<(String
         };
 
         field_def = schema_properties.get(key).unwrap_or(&Value::Null);
+        if field_def.is_null() {
+            // the column may only be declared inside a draft 2020-12
+            // `if`/`then`/`else` conditional subschema, each of which carries
+            // its own "properties" - look there too for the column's type
+            for conditional_keyword in ["then", "else", "if"] {
+                if let Some(conditional_def) = schema
+                    .get(conditional_keyword)
+                    .and_then(|subschema| subschema.get("properties"))
+                    .and_then(|properties| properties.get(key))
+                {
+                    field_def = conditional_def;
+                    break;
+                }
+            }
+        }
+        if field_def.is_null() && schema_columns_only {
+            // the column is not declared anywhere in the schema, so don't
+            // map it into the per-row JSON object at all
+            header_types.push((key.to_owned(), JSONtypes::Skip));
+            continue;
+        }
         field_type_def = field_def.get("type").unwrap_or(&Value::Null);
 
         json_type = match field_type_def {
@@ -1851,6 +3246,25 @@ fn get_json_types(headers: &ByteRecord, schema: &Value) -> CliResult<Vec<(String
     Ok(header_types)
 }
 
+/// Convert the --ref-base value to a base URI for "$ref" resolution.
+/// Values with a scheme (e.g. https://...) are used as-is; anything else is
+/// treated as a local directory and converted to a file:// URI. A trailing
+/// slash is appended if missing so the base resolves as a directory.
+fn ref_base_uri(ref_base: &str) -> String {
+    let mut base_uri = if ref_base.contains("://") {
+        ref_base.to_string()
+    } else {
+        let abs_path = std::path::Path::new(ref_base)
+            .canonicalize()
+            .unwrap_or_else(|_| std::path::PathBuf::from(ref_base));
+        format!("file://{}", abs_path.display())
+    };
+    if !base_uri.ends_with('/') {
+        base_uri.push('/');
+    }
+    base_uri
+}
+
 fn load_json(uri: &str) -> Result<String, String> {
     let json_string = match uri {
         url if url.to_lowercase().starts_with("http") => {
@@ -1992,12 +3406,12 @@ mod tests_for_csv_to_json_conversion {
 
         let mut rdr = csv::Reader::from_reader(csv.as_bytes());
         let headers = rdr.byte_headers().unwrap().clone();
-        let header_types = get_json_types(&headers, &schema_json()).unwrap();
+        let header_types = get_json_types(&headers, &schema_json(), false).unwrap();
         let mut record = rdr.byte_records().next().unwrap().unwrap();
         record.trim();
 
         assert_eq!(
-            to_json_instance(&header_types, headers.len(), &record)
+            to_json_instance(&header_types, headers.len(), &record, false, None)
                 .expect("can't convert csv to json instance"),
             json!({
                 "A": "hello",
@@ -2016,6 +3430,33 @@ mod tests_for_csv_to_json_conversion {
         );
     }
 
+    #[test]
+    fn test_to_json_instance_relaxed_numbers() {
+        let _ = NULL_TYPE.get_or_init(|| Value::String("null".to_string()));
+
+        // "." decimal mark: "," is the thousands separator
+        let csv = "A,B,C,D,E,F,G,H\nhello,\"1,234.56\",\"300,000,000\",true,,,,";
+        let mut rdr = csv::Reader::from_reader(csv.as_bytes());
+        let headers = rdr.byte_headers().unwrap().clone();
+        let header_types = get_json_types(&headers, &schema_json(), false).unwrap();
+        let record = rdr.byte_records().next().unwrap().unwrap();
+
+        let instance =
+            to_json_instance(&header_types, headers.len(), &record, false, Some(b'.')).unwrap();
+        assert_eq!(instance["B"], json!(1234.56));
+        assert_eq!(instance["C"], json!(300_000_000));
+
+        // "," decimal mark: "." is the thousands separator
+        let csv = "A,B,C,D,E,F,G,H\nhello,\"1.234,56\",300.000.000,true,,,,";
+        let mut rdr = csv::Reader::from_reader(csv.as_bytes());
+        let record = rdr.byte_records().next().unwrap().unwrap();
+
+        let instance =
+            to_json_instance(&header_types, headers.len(), &record, false, Some(b',')).unwrap();
+        assert_eq!(instance["B"], json!(1234.56));
+        assert_eq!(instance["C"], json!(300_000_000));
+    }
+
     #[test]
     fn test_to_json_instance_cast_integer_error() {
         let _ = NULL_TYPE.get_or_init(|| Value::String("null".to_string()));
@@ -2024,12 +3465,14 @@ mod tests_for_csv_to_json_conversion {
 
         let mut rdr = csv::Reader::from_reader(csv.as_bytes());
         let headers = rdr.byte_headers().unwrap().clone();
-        let header_types = get_json_types(&headers, &schema_json()).unwrap();
+        let header_types = get_json_types(&headers, &schema_json(), false).unwrap();
 
         let result = to_json_instance(
             &header_types,
             headers.len(),
             &rdr.byte_records().next().unwrap().unwrap(),
+            false,
+            None,
         );
         assert!(&result.is_err());
         let error = result.err().unwrap().to_string();
@@ -2082,11 +3525,11 @@ mod tests_for_schema_validation {
 
         let mut rdr = csv::Reader::from_reader(csv.as_bytes());
         let headers = rdr.byte_headers().unwrap().clone();
-        let header_types = get_json_types(&headers, &schema_json()).unwrap();
+        let header_types = get_json_types(&headers, &schema_json(), false).unwrap();
 
         let record = &rdr.byte_records().next().unwrap().unwrap();
 
-        let instance = to_json_instance(&header_types, headers.len(), record).unwrap();
+        let instance = to_json_instance(&header_types, headers.len(), record, false, None).unwrap();
 
         let result = validate_json_instance(&instance, &compiled_schema());
 
@@ -2101,11 +3544,11 @@ mod tests_for_schema_validation {
 
         let mut rdr = csv::Reader::from_reader(csv.as_bytes());
         let headers = rdr.byte_headers().unwrap().clone();
-        let header_types = get_json_types(&headers, &schema_json()).unwrap();
+        let header_types = get_json_types(&headers, &schema_json(), false).unwrap();
 
         let record = &rdr.byte_records().next().unwrap().unwrap();
 
-        let instance = to_json_instance(&header_types, headers.len(), record).unwrap();
+        let instance = to_json_instance(&header_types, headers.len(), record, false, None).unwrap();
 
         let result = validate_json_instance(&instance, &compiled_schema());
 
@@ -2170,11 +3613,11 @@ fn test_validate_currency_email_dynamicenum_validator() {
 
     let mut rdr = csv::Reader::from_reader(csv.as_bytes());
     let headers = rdr.byte_headers().unwrap().clone();
-    let header_types = get_json_types(&headers, &schema_currency_json()).unwrap();
+    let header_types = get_json_types(&headers, &schema_currency_json(), false).unwrap();
 
     let record = &rdr.byte_records().next().unwrap().unwrap();
 
-    let instance = to_json_instance(&header_types, headers.len(), record).unwrap();
+    let instance = to_json_instance(&header_types, headers.len(), record, false, None).unwrap();
 
     let compiled_schema = Validator::options()
         .with_format("currency", currency_format_checker)
@@ -2199,11 +3642,11 @@ fn test_validate_currency_email_dynamicenum_validator() {
 
     let mut rdr = csv::Reader::from_reader(csv.as_bytes());
     let headers = rdr.byte_headers().unwrap().clone();
-    let header_types = get_json_types(&headers, &schema_currency_json()).unwrap();
+    let header_types = get_json_types(&headers, &schema_currency_json(), false).unwrap();
 
     let record = &rdr.byte_records().next().unwrap().unwrap();
 
-    let instance = to_json_instance(&header_types, headers.len(), record).unwrap();
+    let instance = to_json_instance(&header_types, headers.len(), record, false, None).unwrap();
 
     let compiled_schema = Validator::options()
         .with_format("currency", currency_format_checker)
@@ -2241,7 +3684,7 @@ fn test_validate_currency_email_dynamicenum_validator() {
 
     let mut rdr = csv::Reader::from_reader(csv.as_bytes());
     let headers = rdr.byte_headers().unwrap().clone();
-    let header_types = get_json_types(&headers, &schema_currency_json()).unwrap();
+    let header_types = get_json_types(&headers, &schema_currency_json(), false).unwrap();
 
     let compiled_schema = Validator::options()
         .with_format("currency", currency_format_checker)
@@ -2252,7 +3695,7 @@ fn test_validate_currency_email_dynamicenum_validator() {
 
     for (i, record) in rdr.byte_records().enumerate() {
         let record = record.unwrap();
-        let instance = to_json_instance(&header_types, headers.len(), &record).unwrap();
+        let instance = to_json_instance(&header_types, headers.len(), &record, false, None).unwrap();
 
         let result = validate_json_instance(&instance, &compiled_schema);
 
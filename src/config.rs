@@ -121,6 +121,7 @@ pub struct Config {
     pub write_buffer:      u32,
     pub skip_format_check: bool,
     pub format_error:      Option<String>,
+    bom:                   bool, // write a UTF-8 BOM at the start of the output
 }
 
 // Empty trait as an alias for Seek and Read that avoids auto trait errors
@@ -163,6 +164,9 @@ impl Config {
     /// - `QSV_RDR_BUFFER_CAPACITY`: Sets read buffer capacity.
     /// - `QSV_WTR_BUFFER_CAPACITY`: Sets write buffer capacity.
     /// - `QSV_SKIP_FORMAT_CHECK`: Set to skip file extension checking.
+    /// - `QSV_DEFAULT_QUOTE_STYLE`: Sets the default quote style when writing CSV (necessary,
+    ///   always, non-numeric or never).
+    /// - `QSV_CRLF`: Set to use '\r\n' line endings when writing CSV.
     pub fn new(path: Option<&String>) -> Config {
         let default_delim = match env::var("QSV_DEFAULT_DELIMITER") {
             Ok(delim) => Delimiter::decode_delimiter(&delim).unwrap().as_byte(),
@@ -281,9 +285,16 @@ impl Config {
             delimiter: delim,
             no_headers,
             flexible: false,
-            terminator: csv::Terminator::Any(b'\n'),
+            terminator: if util::get_envvar_flag("QSV_CRLF") {
+                csv::Terminator::CRLF
+            } else {
+                csv::Terminator::Any(b'\n')
+            },
             quote: b'"',
-            quote_style: csv::QuoteStyle::Necessary,
+            quote_style: match env::var("QSV_DEFAULT_QUOTE_STYLE") {
+                Ok(style) => parse_quote_style(&style).unwrap_or(csv::QuoteStyle::Necessary),
+                Err(_) => csv::QuoteStyle::Necessary,
+            },
             double_quote: true,
             escape: None,
             quoting: true,
@@ -306,6 +317,7 @@ impl Config {
                 .unwrap_or(DEFAULT_WTR_BUFFER_CAPACITY as u32),
             format_error,
             skip_format_check,
+            bom: false,
         }
     }
 
@@ -373,6 +385,11 @@ impl Config {
         self
     }
 
+    pub const fn bom(mut self, yes: bool) -> Config {
+        self.bom = yes;
+        self
+    }
+
     pub const fn double_quote(mut self, yes: bool) -> Config {
         self.double_quote = yes;
         self
@@ -725,7 +742,7 @@ impl Config {
 
     #[allow(clippy::wrong_self_convention)]
     pub fn from_writer<W: io::Write>(&self, mut wtr: W) -> csv::Writer<W> {
-        if util::get_envvar_flag("QSV_OUTPUT_BOM") {
+        if self.bom || util::get_envvar_flag("QSV_OUTPUT_BOM") {
             wtr.write_all("\u{FEFF}".as_bytes()).unwrap();
         }
 
@@ -796,6 +813,21 @@ pub fn get_delim_by_extension(path: &Path, default_delim: u8) -> (String, u8, bo
     (file_extension, delim, snappy)
 }
 
+/// Parses a `--quote-style` value or the `QSV_DEFAULT_QUOTE_STYLE` envvar into
+/// a `csv::QuoteStyle`. Valid styles are necessary, always, non-numeric & never.
+pub fn parse_quote_style(style: &str) -> Result<csv::QuoteStyle, String> {
+    match style.to_ascii_lowercase().as_str() {
+        "necessary" => Ok(csv::QuoteStyle::Necessary),
+        "always" => Ok(csv::QuoteStyle::Always),
+        "non-numeric" | "nonnumeric" => Ok(csv::QuoteStyle::NonNumeric),
+        "never" => Ok(csv::QuoteStyle::Never),
+        _ => Err(format!(
+            "Invalid quote style \"{style}\". Valid styles are: necessary, always, non-numeric & \
+             never."
+        )),
+    }
+}
+
 /// Determines if a file is a Parquet, Arrow IPC, JSONL, or compressed CSV file.
 ///
 /// # Arguments
@@ -1,8 +1,8 @@
-use std::{io, ops};
+use std::{fs, io, ops, path::Path};
 
 use csv_index::RandomAccessSimple;
 
-use crate::CliResult;
+use crate::{CliResult, util};
 
 /// Indexed composes a CSV reader with a simple random access index.
 pub struct Indexed<R, I> {
@@ -63,3 +63,11 @@ impl<R: io::Read + io::Seek, I: io::Read + io::Seek> Indexed<R, I> {
         Ok(())
     }
 }
+
+/// Returns true if the index at `idx_path` is stale relative to the CSV data
+/// at `data_path`, i.e. the data was modified after the index was built.
+pub fn is_stale(data_path: &Path, idx_path: &Path) -> io::Result<bool> {
+    let (data_modified, _) = util::file_metadata(&fs::metadata(data_path)?);
+    let (idx_modified, _) = util::file_metadata(&fs::metadata(idx_path)?);
+    Ok(data_modified > idx_modified)
+}
@@ -21,6 +21,7 @@ pub struct LookupTableOptions {
     pub ckan_api_url:   Option<String>,
     pub ckan_token:     Option<String>,
     pub timeout_secs:   u16,
+    pub force_refresh:  bool,
 }
 
 pub struct LookupTableResult {
@@ -65,6 +66,8 @@ pub fn set_qsv_cache_dir(cache_dir: &str) -> Result<String, CliError> {
 ///   - `ckan_api_url`: Optional CKAN API URL for CKAN resources
 ///   - `ckan_token`: Optional CKAN API token
 ///   - `timeout_secs`: Timeout in seconds for HTTP requests
+///   - `force_refresh`: Re-download remote files even if the cached copy is still within
+///     `cache_age_secs`
 ///
 /// # Returns
 ///
@@ -127,9 +130,10 @@ pub fn load_lookup_table(
             (false, 0, 0, None)
         };
 
-    // Use cached file if valid
+    // Use cached file if valid, unless a refresh is being forced
     if !lookup_table_is_file
         && cached_csv_exists
+        && !opts.force_refresh
         && cached_csv_age_secs <= opts.cache_age_secs
         && cached_csv_size > 0
     {
@@ -174,9 +178,21 @@ pub fn load_lookup_table(
                 &cached_csv_path,
                 lookup_ckan,
                 resource_search,
-                cache_csv_last_modified,
+                // forcing a refresh skips the If-Modified-Since header so the
+                // server returns a full body instead of a 304
+                if opts.force_refresh {
+                    None
+                } else {
+                    cache_csv_last_modified
+                },
                 opts,
             )?;
+            if cached_csv_exists {
+                info!(
+                    "Refreshed lookup table \"{}\" from {lookup_table_uri}",
+                    opts.name
+                );
+            }
             lookup_table_uri = cached_csv_path.to_string_lossy().to_string();
         }
     }
@@ -12,8 +12,9 @@ use serde::de::{Deserialize, Deserializer, Error};
 
 #[derive(Clone)]
 pub struct SelectColumns {
-    selectors: Vec<Selector>,
-    invert:    bool,
+    selectors:   Vec<Selector>,
+    invert:      bool,
+    allow_empty: bool,
 }
 
 impl SelectColumns {
@@ -29,9 +30,18 @@ impl SelectColumns {
         Ok(SelectColumns {
             selectors: SelectorParser::new(s).parse()?,
             invert,
+            allow_empty: false,
         })
     }
 
+    /// allow a regex selector matching no columns to resolve to an empty
+    /// selection instead of erroring
+    #[must_use]
+    pub const fn allow_empty(mut self, yes: bool) -> SelectColumns {
+        self.allow_empty = yes;
+        self
+    }
+
     pub fn selection(
         &self,
         first_record: &csv::ByteRecord,
@@ -48,7 +58,7 @@ impl SelectColumns {
 
         let mut map = vec![];
         for sel in &self.selectors {
-            let idxs = sel.indices(first_record, use_names);
+            let idxs = sel.indices(first_record, use_names, self.allow_empty);
             map.extend(idxs?);
         }
         if self.invert {
@@ -275,6 +285,7 @@ impl Selector {
         &self,
         first_record: &csv::ByteRecord,
         use_names: bool,
+        allow_empty: bool,
     ) -> Result<Vec<usize>, String> {
         match *self {
             Selector::One(ref sel) => sel.index(first_record, use_names).map(|i| vec![i]),
@@ -302,7 +313,7 @@ impl Selector {
                     .filter(|(_, h)| re.is_match(h))
                     .map(|(i, _)| i)
                     .collect();
-                if inds.is_empty() {
+                if inds.is_empty() && !allow_empty {
                     return fail_format!(
                         "Selector regex '{re}' does not match any columns in the CSV header."
                     );
@@ -437,6 +448,21 @@ impl Selection {
         NormalSelection(set)
     }
 
+    /// Collapse repeated column indices, keeping the first occurrence of each
+    /// in selection order, so "id,name,id" normalizes to "id,name".
+    /// Intentional column duplication is preserved unless this is called.
+    #[must_use]
+    pub fn deduped(&self) -> Selection {
+        let mut seen: HashSet<usize> = HashSet::default();
+        Selection(
+            self.0
+                .iter()
+                .copied()
+                .filter(|&index| seen.insert(index))
+                .collect(),
+        )
+    }
+
     pub const fn len(&self) -> usize {
         self.0.len()
     }
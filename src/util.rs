@@ -2183,6 +2183,81 @@ pub fn write_json(
     Ok(json_wtr.flush()?)
 }
 
+/// write CSV records as newline-delimited JSON (JSON Lines) - one object per
+/// row with the same key-value construction as `write_json`, but without an
+/// enclosing array, so the output can be consumed as a stream
+pub fn write_jsonl(
+    output: Option<&String>,
+    no_headers: bool,
+    headers: &csv::ByteRecord,
+    records: impl Iterator<Item = csv::ByteRecord>,
+) -> CliResult<()> {
+    let mut json_wtr = create_json_writer(output, config::DEFAULT_WTR_BUFFER_CAPACITY * 4)?;
+
+    let header_vec: Vec<String> = headers
+        .iter()
+        .enumerate()
+        .map(|(col_idx, b)| {
+            if no_headers {
+                col_idx.to_string()
+            } else if let Ok(val) = simdutf8::basic::from_utf8(b) {
+                val.to_owned()
+            } else {
+                String::from_utf8_lossy(b).to_string()
+            }
+        })
+        .collect();
+
+    let rec_len = header_vec.len().saturating_sub(1);
+    let mut temp_val;
+    let null_val = "null".to_string();
+    let mut json_string_val: serde_json::Value;
+
+    for record in records {
+        write!(json_wtr, "{{")?;
+        for (idx, b) in record.iter().enumerate() {
+            temp_val = if let Ok(val) = simdutf8::basic::from_utf8(b) {
+                val.to_owned()
+            } else {
+                String::from_utf8_lossy(b).to_string()
+            };
+            if temp_val.is_empty() {
+                temp_val.clone_from(&null_val);
+            } else {
+                // we round-trip the value to serde_json
+                // to escape the string properly per JSON spec
+                json_string_val = serde_json::Value::String(temp_val);
+                temp_val = json_string_val.to_string();
+            }
+            // safety: idx is always in bounds
+            // so we can get_unchecked here
+            if idx < rec_len {
+                unsafe {
+                    write!(
+                        &mut json_wtr,
+                        r#""{key}":{value},"#,
+                        key = header_vec.get_unchecked(idx),
+                        value = temp_val
+                    )?;
+                }
+            } else {
+                // last column in the JSON record, no comma
+                unsafe {
+                    write!(
+                        &mut json_wtr,
+                        r#""{key}":{value}"#,
+                        key = header_vec.get_unchecked(idx),
+                        value = temp_val
+                    )?;
+                }
+            }
+        }
+        writeln!(json_wtr, "}}")?;
+    }
+
+    Ok(json_wtr.flush()?)
+}
+
 /// write a single csv::ByteRecord to a JSON record writer
 /// if no_headers is true, the column index (0-based) is used as the key
 /// if no_headers is false, the header is used as the key
@@ -795,6 +795,178 @@ fn cat_cols_pad() {
     assert_eq!(got, expected);
 }
 
+#[test]
+fn cat_cols_pad_fill() {
+    let rows1 = vec![svec!["a", "b"]];
+    let rows2 = vec![svec!["y", "z"], svec!["y", "z"]];
+
+    let expected = vec![svec!["a", "b", "y", "z"], svec!["N/A", "N/A", "y", "z"]];
+    let got: Vec<Vec<String>> = run_cat("cat_cols_headers", "columns", rows1, rows2, |cmd| {
+        cmd.arg("--pad").args(["--fill", "N/A"]);
+    });
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn cat_rows_with_source() {
+    let wrk = Workdir::new("cat_rows_with_source");
+    wrk.create("in1.csv", vec![svec!["id", "name"], svec!["1", "a"]]);
+    wrk.create("in2.csv", vec![svec!["id", "name"], svec!["2", "b"]]);
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rows")
+        .args(["--with-source", "fname"])
+        .arg("in1.csv")
+        .arg("in2.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["file", "id", "name"],
+        svec!["in1.csv", "1", "a"],
+        svec!["in2.csv", "2", "b"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn cat_rows_with_source_name() {
+    let wrk = Workdir::new("cat_rows_with_source_name");
+    wrk.create("in1.csv", vec![svec!["id", "name"], svec!["1", "a"]]);
+    wrk.create("in2.csv", vec![svec!["id", "name"], svec!["2", "b"]]);
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rows")
+        .args(["--with-source", "fstem"])
+        .args(["--source-name", "source"])
+        .arg("in1.csv")
+        .arg("in2.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["source", "id", "name"],
+        svec!["in1", "1", "a"],
+        svec!["in2", "2", "b"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn cat_rows_with_source_no_headers() {
+    let wrk = Workdir::new("cat_rows_with_source_no_headers");
+    wrk.create("in1.csv", vec![svec!["1", "a"]]);
+    wrk.create("in2.csv", vec![svec!["2", "b"]]);
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rows")
+        .arg("--no-headers")
+        .args(["--with-source", "fname"])
+        .arg("in1.csv")
+        .arg("in2.csv");
+
+    // no header row is written, but every data row still gets its source
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["in1.csv", "1", "a"], svec!["in2.csv", "2", "b"]];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn cat_rows_with_source_invalid_kind() {
+    let wrk = Workdir::new("cat_rows_with_source_invalid_kind");
+    wrk.create("in.csv", vec![svec!["id"], svec!["1"]]);
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rows").args(["--with-source", "dirname"]).arg("in.csv");
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn cat_rowskey_rename() {
+    let wrk = Workdir::new("cat_rowskey_rename");
+    wrk.create("in1.csv", vec![svec!["id", "email"], svec!["1", "a@x.com"]]);
+    wrk.create("in2.csv", vec![svec!["id", "e-mail"], svec!["2", "b@x.com"]]);
+    wrk.create("in3.csv", vec![svec!["id", "Email"], svec!["3", "c@x.com"]]);
+    wrk.create(
+        "rename.csv",
+        vec![svec!["e-mail", "email"], svec!["Email", "email"]],
+    );
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rowskey")
+        .args(["--rename", "rename.csv"])
+        .arg("in1.csv")
+        .arg("in2.csv")
+        .arg("in3.csv");
+
+    // the three spellings are aliased to one "email" column
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["id", "email"],
+        svec!["1", "a@x.com"],
+        svec!["2", "b@x.com"],
+        svec!["3", "c@x.com"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn cat_rowskey_rename_ci() {
+    let wrk = Workdir::new("cat_rowskey_rename_ci");
+    wrk.create("in1.csv", vec![svec!["id", "Email"], svec!["1", "a@x.com"]]);
+    wrk.create("in2.csv", vec![svec!["id", "EMAIL"], svec!["2", "b@x.com"]]);
+    wrk.create("rename.csv", vec![svec!["email", "email"]]);
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rowskey")
+        .args(["--rename", "rename.csv"])
+        .arg("--rename-ci")
+        .arg("in1.csv")
+        .arg("in2.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["id", "email"],
+        svec!["1", "a@x.com"],
+        svec!["2", "b@x.com"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn cat_rename_rows_invalid() {
+    let wrk = Workdir::new("cat_rename_rows_invalid");
+    wrk.create("in.csv", vec![svec!["id", "email"], svec!["1", "a@x.com"]]);
+    wrk.create("rename.csv", vec![svec!["e-mail", "email"]]);
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rows").args(["--rename", "rename.csv"]).arg("in.csv");
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn cat_cols_dedup_headers() {
+    let rows1 = vec![svec!["id", "name"], svec!["1", "a"]];
+    let rows2 = vec![svec!["id", "name"], svec!["2", "b"]];
+
+    let expected = vec![
+        svec!["id", "name", "id_2", "name_2"],
+        svec!["1", "a", "2", "b"],
+    ];
+    let got: Vec<Vec<String>> = run_cat("cat_cols_dedup_headers", "columns", rows1, rows2, |cmd| {
+        cmd.arg("--dedup-headers");
+    });
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn cat_dedup_headers_rows_invalid() {
+    let wrk = Workdir::new("cat_dedup_headers_rows_invalid");
+    wrk.create("in.csv", vec![svec!["id", "name"], svec!["1", "a"]]);
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rows").arg("--dedup-headers").arg("in.csv");
+    wrk.assert_err(&mut cmd);
+}
+
 #[test]
 fn cat_rows_directory_skip_format_check() {
     let wrk = Workdir::new("cat_rows_directory_skip_format_check");
@@ -891,3 +1063,404 @@ fn cat_rows_directory_without_skip_format_check_fails() {
         stderr
     );
 }
+
+#[test]
+fn cat_rowskey_source_coverage() {
+    let wrk = Workdir::new("cat_rowskey_source_coverage");
+    wrk.create(
+        "complete.csv",
+        vec![
+            svec!["a", "b", "c"],
+            svec!["1", "2", "3"],
+            svec!["2", "3", "4"],
+        ],
+    );
+
+    wrk.create(
+        "sparse.csv",
+        vec![svec!["a", "c"], svec!["5", "6"], svec!["7", "8"]],
+    );
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rowskey")
+        .arg("--source-coverage")
+        .arg("complete.csv")
+        .arg("sparse.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["a", "b", "c", "source_coverage"],
+        svec!["1", "2", "3", "3"],
+        svec!["2", "3", "4", "3"],
+        svec!["5", "", "6", "2"],
+        svec!["7", "", "8", "2"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn cat_rows_drop_empty() {
+    let wrk = Workdir::new("cat_rows_drop_empty");
+
+    // header-only file - no data rows
+    wrk.create("empty.csv", vec![svec!["h1", "h2"]]);
+    wrk.create(
+        "populated.csv",
+        vec![svec!["h1", "h2"], svec!["1", "2"], svec!["3", "4"]],
+    );
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rows")
+        .arg("--drop-empty")
+        .arg("empty.csv")
+        .arg("populated.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["h1", "h2"], svec!["1", "2"], svec!["3", "4"]];
+    assert_eq!(got, expected);
+
+    // the skipped file is logged to stderr
+    let stderr = wrk.output_stderr(&mut cmd);
+    assert!(stderr.contains("Skipping file with no data rows"));
+    assert!(stderr.contains("empty.csv"));
+}
+
+#[test]
+fn cat_rowskey_drop_empty() {
+    let wrk = Workdir::new("cat_rowskey_drop_empty");
+
+    // the empty file's columns must not join the union of columns either
+    wrk.create("empty.csv", vec![svec!["h1", "h3"]]);
+    wrk.create(
+        "populated.csv",
+        vec![svec!["h1", "h2"], svec!["1", "2"], svec!["3", "4"]],
+    );
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rowskey")
+        .arg("--drop-empty")
+        .arg("empty.csv")
+        .arg("populated.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["h1", "h2"], svec!["1", "2"], svec!["3", "4"]];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn cat_rows_sample_rate() {
+    let wrk = Workdir::new("cat_rows_sample_rate");
+    let mut rows1 = vec![svec!["n"]];
+    let mut rows2 = Vec::new();
+    for i in 0..50 {
+        rows1.push(svec![i.to_string()]);
+        rows2.push(svec![(i + 50).to_string()]);
+    }
+    wrk.create("in1.csv", rows1);
+    let mut rows2_with_header = vec![svec!["n"]];
+    rows2_with_header.extend(rows2);
+    wrk.create("in2.csv", rows2_with_header);
+
+    let run_sampled = || {
+        let mut cmd = wrk.command("cat");
+        cmd.arg("rows")
+            .args(["--sample-rate", "0.5"])
+            .args(["--seed", "42"])
+            .arg("in1.csv")
+            .arg("in2.csv");
+        let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+        got
+    };
+
+    let got = run_sampled();
+
+    // a proper Bernoulli subsample - neither empty nor the full concatenation
+    assert!(got.len() > 1 && got.len() < 101);
+    assert_eq!(got[0], svec!["n"]);
+    // every sampled row comes from the concatenated inputs, in order
+    let sampled: Vec<usize> = got[1..]
+        .iter()
+        .map(|r| r[0].parse::<usize>().unwrap())
+        .collect();
+    assert!(sampled.windows(2).all(|w| w[0] < w[1]));
+    assert!(sampled.iter().all(|&n| n < 100));
+
+    // the same seed yields the same subsample
+    let again = run_sampled();
+    assert_eq!(got, again);
+}
+
+#[test]
+fn cat_rowskey_sample_rate() {
+    let wrk = Workdir::new("cat_rowskey_sample_rate");
+    let mut rows = vec![svec!["a", "b"]];
+    for i in 0..100 {
+        rows.push(svec![i.to_string(), (i * 2).to_string()]);
+    }
+    wrk.create("in1.csv", rows);
+
+    let run_sampled = || {
+        let mut cmd = wrk.command("cat");
+        cmd.arg("rowskey")
+            .args(["--sample-rate", "0.25"])
+            .args(["--seed", "7"])
+            .arg("in1.csv");
+        let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+        got
+    };
+
+    let got = run_sampled();
+    assert!(got.len() > 1 && got.len() < 101);
+    assert_eq!(got[0], svec!["a", "b"]);
+
+    let again = run_sampled();
+    assert_eq!(got, again);
+}
+
+#[test]
+fn cat_columns_sample_rate_invalid() {
+    let wrk = Workdir::new("cat_columns_sample_rate_invalid");
+    wrk.create("in1.csv", vec![svec!["a"], svec!["1"]]);
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("columns")
+        .args(["--sample-rate", "0.5"])
+        .arg("in1.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn cat_rows_dry_run() {
+    let wrk = Workdir::new("cat_rows_dry_run");
+    std::fs::create_dir_all(wrk.path("data")).unwrap();
+    wrk.create(
+        "data/a.csv",
+        vec![svec!["h1", "h2"], svec!["1", "2"], svec!["3", "4"]],
+    );
+    wrk.create(
+        "data/b.csv",
+        vec![svec!["h1", "h2", "h3"], svec!["5", "6", "7"]],
+    );
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rows").arg("--dry-run").arg(wrk.path("data"));
+    wrk.assert_success(&mut cmd);
+
+    // no concatenated data is written
+    let stdout: String = wrk.stdout(&mut cmd);
+    assert!(stdout.is_empty());
+
+    // both resolved inputs are reported with their counts
+    let stderr = wrk.output_stderr(&mut cmd);
+    assert!(
+        stderr.contains("a.csv\": delimiter: \",\" columns: 2 rows: 2"),
+        "stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains("b.csv\": delimiter: \",\" columns: 3 rows: 1"),
+        "stderr: {stderr}"
+    );
+}
+
+#[test]
+fn cat_columns_on() {
+    let rows1 = vec![
+        svec!["id", "name"],
+        svec!["1", "alice"],
+        svec!["2", "bob"],
+        svec!["3", "carol"],
+    ];
+    // same keys, different order, plus a key missing from in1.csv
+    let rows2 = vec![
+        svec!["id", "age"],
+        svec!["3", "29"],
+        svec!["1", "42"],
+        svec!["4", "17"],
+    ];
+
+    let got: Vec<Vec<String>> = run_cat("cat_columns_on", "columns", rows1, rows2, |cmd| {
+        cmd.args(["--on", "id"]);
+    });
+    let expected = vec![
+        svec!["id", "name", "id", "age"],
+        svec!["1", "alice", "1", "42"],
+        svec!["2", "bob", "", ""],
+        svec!["3", "carol", "3", "29"],
+        svec!["", "", "4", "17"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn cat_columns_on_fill() {
+    let rows1 = vec![svec!["id", "name"], svec!["1", "alice"], svec!["2", "bob"]];
+    let rows2 = vec![svec!["id", "age"], svec!["2", "29"]];
+
+    let got: Vec<Vec<String>> = run_cat("cat_columns_on_fill", "columns", rows1, rows2, |cmd| {
+        cmd.args(["--on", "id"]).args(["--fill", "N/A"]);
+    });
+    let expected = vec![
+        svec!["id", "name", "id", "age"],
+        svec!["1", "alice", "N/A", "N/A"],
+        svec!["2", "bob", "2", "29"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn cat_columns_on_missing_key_column() {
+    let wrk = Workdir::new("cat_columns_on_missing_key_column");
+    wrk.create("in1.csv", vec![svec!["id", "name"], svec!["1", "alice"]]);
+    wrk.create("in2.csv", vec![svec!["key", "age"], svec!["1", "42"]]);
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("columns")
+        .args(["--on", "id"])
+        .arg("in1.csv")
+        .arg("in2.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn cat_on_rows_invalid() {
+    let wrk = Workdir::new("cat_on_rows_invalid");
+    wrk.create("in.csv", vec![svec!["id"], svec!["1"]]);
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rows").args(["--on", "id"]).arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn cat_rowskey_columns() {
+    let wrk = Workdir::new("cat_rowskey_columns");
+    wrk.create(
+        "in1.csv",
+        vec![svec!["id", "name"], svec!["1", "alice"], svec!["2", "bob"]],
+    );
+    wrk.create("in2.csv", vec![svec!["city", "id"], svec!["berlin", "3"]]);
+    wrk.create_from_string("columns.txt", "id\ncity\n");
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rowskey")
+        .args(["--columns", "columns.txt"])
+        .arg("in1.csv")
+        .arg("in2.csv");
+
+    // rows stream into the fixed schema: absent columns are empty,
+    // columns not in the list (name) are dropped
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["id", "city"],
+        svec!["1", ""],
+        svec!["2", ""],
+        svec!["3", "berlin"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn cat_rowskey_columns_strict_unmatched() {
+    let wrk = Workdir::new("cat_rowskey_columns_strict_unmatched");
+    wrk.create("in1.csv", vec![svec!["id"], svec!["1"]]);
+    wrk.create_from_string("columns.txt", "id\nnonexistent\n");
+
+    // without --strict, the unmatched column is simply empty
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rowskey")
+        .args(["--columns", "columns.txt"])
+        .arg("in1.csv");
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["id", "nonexistent"], svec!["1", ""]];
+    assert_eq!(got, expected);
+
+    // with it, an unmatched column is an error
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rowskey")
+        .args(["--columns", "columns.txt"])
+        .arg("--strict")
+        .arg("in1.csv");
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn cat_columns_rows_invalid() {
+    let wrk = Workdir::new("cat_columns_rows_invalid");
+    wrk.create("in.csv", vec![svec!["id"], svec!["1"]]);
+    wrk.create_from_string("columns.txt", "id\n");
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rows")
+        .args(["--columns", "columns.txt"])
+        .arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn cat_rowskey_align_report() {
+    let wrk = Workdir::new("cat_rowskey_align_report");
+    wrk.create(
+        "in1.csv",
+        vec![svec!["a", "b"], svec!["1", "2"]],
+    );
+    wrk.create(
+        "in2.csv",
+        vec![svec!["b", "c"], svec!["3", "4"]],
+    );
+    wrk.create(
+        "in3.csv",
+        vec![svec!["a", "d"], svec!["5", "6"]],
+    );
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rowskey")
+        .args(["--align-report", "report.csv"])
+        .arg("in1.csv")
+        .arg("in2.csv")
+        .arg("in3.csv");
+
+    wrk.assert_success(&mut cmd);
+
+    let report: Vec<Vec<String>> = wrk.read_csv("report.csv");
+    let expected = vec![
+        svec!["a", "1", "0", "1"],
+        svec!["b", "1", "1", "0"],
+        svec!["c", "0", "1", "0"],
+        svec!["d", "0", "0", "1"],
+    ];
+    assert_eq!(report, expected);
+}
+
+#[test]
+fn cat_align_report_rows_invalid() {
+    let wrk = Workdir::new("cat_align_report_rows_invalid");
+    wrk.create("in1.csv", vec![svec!["a"], svec!["1"]]);
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rows")
+        .args(["--align-report", "report.csv"])
+        .arg("in1.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn cat_rows_crlf() {
+    let wrk = Workdir::new("cat_rows_crlf");
+    wrk.create("in1.csv", vec![svec!["h1", "h2"], svec!["a", "b"]]);
+    wrk.create("in2.csv", vec![svec!["h1", "h2"], svec!["c", "d"]]);
+
+    let mut cmd = wrk.command("cat");
+    cmd.arg("rows")
+        .arg("--crlf")
+        .arg("in1.csv")
+        .arg("in2.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = "h1,h2\r\na,b\r\nc,d";
+    assert_eq!(got, expected);
+}
@@ -1110,3 +1110,981 @@ fn frequency_json_vis_whitespace() {
         assert!((freqs[i]["percentage"].as_f64().unwrap() - *pct).abs() < 1e-5);
     }
 }
+
+#[test]
+fn frequency_explain() {
+    let wrk = Workdir::new("frequency_explain");
+    let testdata = wrk.load_test_file("boston311-100.csv");
+
+    // prime the stats cache so the all-unique short-circuit kicks in
+    let mut stats_cmd = wrk.command("stats");
+    stats_cmd
+        .arg(testdata.clone())
+        .arg("--cardinality")
+        .arg("--stats-jsonl");
+    wrk.assert_success(&mut stats_cmd);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--select", "1"]).arg("--explain").arg(testdata);
+
+    let got = wrk.output_stderr(&mut cmd);
+    assert!(got.contains("explain: stats cache used: true"));
+    assert!(
+        got.contains(
+            "explain: column \"case_enquiry_id\": all-unique, short-circuited with capacity 1"
+        )
+    );
+}
+
+#[test]
+fn frequency_find_duplicate_columns() {
+    let wrk = Workdir::new("frequency_find_duplicate_columns");
+    // col1 and col3 hold the same multiset of values, in different row order
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["col1", "col2", "col3"],
+            svec!["a", "x", "b"],
+            svec!["b", "y", "a"],
+            svec!["a", "x", "a"],
+            svec!["c", "z", "c"],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--find-duplicate-columns").arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["column_1", "column_2"], svec!["col1", "col3"]];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_coverage() {
+    let wrk = Workdir::new("frequency_coverage");
+    // skewed column: "a" covers 80% of rows on its own
+    let mut rows = vec![svec!["col1"]];
+    for _ in 0..8 {
+        rows.push(svec!["a"]);
+    }
+    rows.push(svec!["b"]);
+    rows.push(svec!["c"]);
+    wrk.create("in.csv", rows);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--coverage").arg("in.csv");
+
+    let got = wrk.output_stderr(&mut cmd);
+    assert!(got.contains("coverage: column \"col1\": 50% -> 1, 80% -> 1, 95% -> 3 of 3 distinct values"));
+}
+
+#[test]
+fn frequency_bounded() {
+    let wrk = Workdir::new("frequency_bounded");
+    let mut rows = vec![svec!["fruit"]];
+    for _ in 0..10 {
+        rows.push(svec!["apple"]);
+    }
+    for _ in 0..4 {
+        rows.push(svec!["banana"]);
+    }
+    for _ in 0..3 {
+        rows.push(svec!["cherry"]);
+    }
+    rows.push(svec!["date"]);
+    rows.push(svec!["elderberry"]);
+    rows.push(svec!["fig"]);
+    wrk.create("in.csv", rows);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--bounded", "4"]).arg("in.csv");
+
+    // with K = 4, the skewed top values are exact; the singleton tail values
+    // evict each other and are folded into the "Other" aggregate
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["field", "value", "count", "percentage"],
+        svec!["fruit", "apple", "10", "50"],
+        svec!["fruit", "banana", "4", "20"],
+        svec!["fruit", "cherry", "3", "15"],
+        svec!["fruit", "fig", "1", "5"],
+        svec!["fruit", "Other (2)", "2", "10"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_bounded_json_conflict() {
+    let wrk = Workdir::new("frequency_bounded_json_conflict");
+    wrk.create("in.csv", vec![svec!["h1"], svec!["a"], svec!["b"]]);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--bounded", "5"]).arg("--json").arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn frequency_tsv_output_quoting() {
+    let wrk = Workdir::new("frequency_tsv_output_quoting");
+    wrk.create(
+        "in.csv",
+        vec![svec!["h1"], svec!["a\tb"], svec!["a\tb"]],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--output", "out.tsv"]).arg("in.csv");
+    wrk.assert_success(&mut cmd);
+
+    // the value containing the output delimiter (a tab) must be quoted
+    // so the TSV output round-trips correctly
+    let got: String = wrk.from_str(&wrk.path("out.tsv"));
+    let expected = "field\tvalue\tcount\tpercentage\nh1\t\"a\tb\"\t2\t100\n";
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_bom() {
+    let wrk = Workdir::new("frequency_bom");
+    wrk.create("in.csv", vec![svec!["h1"], svec!["café"], svec!["café"]]);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--bom").args(["--output", "out.csv"]).arg("in.csv");
+    wrk.assert_success(&mut cmd);
+
+    let got: String = wrk.from_str(&wrk.path("out.csv"));
+    assert!(got.starts_with('\u{feff}'));
+    assert_eq!(
+        got.strip_prefix('\u{feff}').unwrap(),
+        "field,value,count,percentage\nh1,café,2,100\n"
+    );
+}
+
+#[test]
+fn frequency_no_bom_by_default() {
+    let wrk = Workdir::new("frequency_no_bom_by_default");
+    wrk.create("in.csv", vec![svec!["h1"], svec!["café"], svec!["café"]]);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--output", "out.csv"]).arg("in.csv");
+    wrk.assert_success(&mut cmd);
+
+    let got: String = wrk.from_str(&wrk.path("out.csv"));
+    assert!(!got.starts_with('\u{feff}'));
+}
+
+#[test]
+fn frequency_null_report() {
+    let wrk = Workdir::new("frequency_null_report");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["h1", "h2"],
+            svec!["a", ""],
+            svec!["", ""],
+            svec!["b", "x"],
+            svec!["a", ""],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--null-report", "nulls.csv"]).arg("in.csv");
+    wrk.assert_success(&mut cmd);
+
+    let got: Vec<Vec<String>> = wrk.read_csv("nulls.csv");
+    let expected = vec![
+        svec!["h1", "1", "25"],
+        svec!["h2", "3", "75"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_cumulative() {
+    let wrk = Workdir::new("frequency_cumulative");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["h1"],
+            svec!["a"],
+            svec!["a"],
+            svec!["a"],
+            svec!["a"],
+            svec!["b"],
+            svec!["b"],
+            svec!["b"],
+            svec!["c"],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--cumulative").arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["field", "value", "count", "percentage", "cumulative_percentage"],
+        svec!["h1", "a", "4", "50", "50"],
+        svec!["h1", "b", "3", "37.5", "87.5"],
+        svec!["h1", "c", "1", "12.5", "100"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_cumulative_asc() {
+    let wrk = Workdir::new("frequency_cumulative_asc");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["h1"],
+            svec!["a"],
+            svec!["a"],
+            svec!["a"],
+            svec!["a"],
+            svec!["b"],
+            svec!["b"],
+            svec!["b"],
+            svec!["c"],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--cumulative").arg("--asc").arg("in.csv");
+
+    // the running total follows the ascending output order
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["field", "value", "count", "percentage", "cumulative_percentage"],
+        svec!["h1", "c", "1", "12.5", "12.5"],
+        svec!["h1", "b", "3", "37.5", "50"],
+        svec!["h1", "a", "4", "50", "100"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_bins() {
+    let wrk = Workdir::new("frequency_bins");
+    let rows = vec![
+        svec!["n", "s"],
+        svec!["0", "a"],
+        svec!["1", "a"],
+        svec!["2", "a"],
+        svec!["3", "a"],
+        svec!["4", "a"],
+        svec!["5", "a"],
+        svec!["6", "a"],
+        svec!["7", "a"],
+        svec!["8", "a"],
+        svec!["9", "a"],
+    ];
+    wrk.create("in.csv", rows);
+
+    // prime the stats cache so --bins can pull each column's type and min/max
+    let mut stats_cmd = wrk.command("stats");
+    stats_cmd
+        .arg("in.csv")
+        .arg("--cardinality")
+        .arg("--stats-jsonl");
+    wrk.assert_success(&mut stats_cmd);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--bins", "2"]).arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["field", "value", "count", "percentage"],
+        svec!["n", "[0, 4.5)", "5", "50"],
+        svec!["n", "[4.5, 9]", "5", "50"],
+        svec!["s", "a", "10", "100"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_bins_json() {
+    let wrk = Workdir::new("frequency_bins_json");
+    let rows = vec![
+        svec!["n"],
+        svec!["0"],
+        svec!["2"],
+        svec!["4"],
+        svec!["6"],
+        svec!["8"],
+    ];
+    wrk.create("in.csv", rows);
+
+    let mut stats_cmd = wrk.command("stats");
+    stats_cmd
+        .arg("in.csv")
+        .arg("--cardinality")
+        .arg("--stats-jsonl");
+    wrk.assert_success(&mut stats_cmd);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--bins", "2"]).arg("--json").arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let v: Value = serde_json::from_str(&got).unwrap();
+    let bins = v["fields"][0]["bins"].as_array().unwrap();
+    assert_eq!(bins.len(), 2);
+    assert_eq!(bins[0]["value"], "[0, 4)");
+    assert_eq!(bins[0]["count"], 2);
+    assert_eq!(bins[1]["value"], "[4, 8]");
+    assert_eq!(bins[1]["count"], 3);
+}
+
+#[test]
+fn frequency_jsonl() {
+    let (wrk, mut cmd) = setup("frequency_jsonl");
+    cmd.args(["--limit", "0"]).arg("--jsonl");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let lines: Vec<&str> = got.lines().collect();
+    assert_eq!(lines.len(), 2);
+
+    // each line independently parses to a per-field object, in field order
+    let h1: Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(h1["field"], "h1");
+    assert!(h1["frequencies"].as_array().unwrap().len() >= 2);
+
+    let h2: Value = serde_json::from_str(lines[1]).unwrap();
+    assert_eq!(h2["field"], "h2");
+    let frequencies = h2["frequencies"].as_array().unwrap();
+    assert_eq!(frequencies[0]["value"], "z");
+    assert_eq!(frequencies[0]["count"], 3);
+}
+
+#[test]
+fn frequency_jsonl_with_json_conflict() {
+    let (wrk, mut cmd) = setup("frequency_jsonl_with_json_conflict");
+    cmd.arg("--json").arg("--jsonl");
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn frequency_weight() {
+    let wrk = Workdir::new("frequency_weight");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["fruit", "count"],
+            svec!["apple", "3"],
+            svec!["banana", "1"],
+            svec!["apple", "2"],
+            svec!["cherry", "4"],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--select", "fruit"])
+        .args(["--weight", "count"])
+        .args(["--limit", "0"])
+        .arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["field", "value", "count", "percentage"],
+        svec!["fruit", "apple", "5", "50"],
+        svec!["fruit", "cherry", "4", "40"],
+        svec!["fruit", "banana", "1", "10"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_weight_non_numeric() {
+    let wrk = Workdir::new("frequency_weight_non_numeric");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["fruit", "count"],
+            svec!["apple", "3"],
+            svec!["banana", "lots"],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--select", "fruit"])
+        .args(["--weight", "count"])
+        .arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+    let got = wrk.output_stderr(&mut cmd);
+    assert!(got.contains("is not a non-negative integer"));
+}
+
+#[test]
+fn frequency_weight_column_not_found() {
+    let wrk = Workdir::new("frequency_weight_column_not_found");
+    wrk.create(
+        "in.csv",
+        vec![svec!["fruit", "count"], svec!["apple", "3"]],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--weight", "nonexistent"]).arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn frequency_combine() {
+    let wrk = Workdir::new("frequency_combine");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["dept", "status"],
+            svec!["sales", "open"],
+            svec!["sales", "open"],
+            svec!["sales", "closed"],
+            svec!["hr", "open"],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--combine").args(["--limit", "0"]).arg("in.csv");
+
+    let mut got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    got.sort_unstable();
+    let expected = vec![
+        svec!["dept\tstatus", "hr\topen", "1", "25"],
+        svec!["dept\tstatus", "sales\tclosed", "1", "25"],
+        svec!["dept\tstatus", "sales\topen", "2", "50"],
+        svec!["field", "value", "count", "percentage"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_combine_json() {
+    let wrk = Workdir::new("frequency_combine_json");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["dept", "status"],
+            svec!["sales", "open"],
+            svec!["sales", "open"],
+            svec!["hr", "open"],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--combine")
+        .args(["--limit", "0"])
+        .arg("--json")
+        .arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let v: Value = serde_json::from_str(&got).unwrap();
+    assert_eq!(v["fieldcount"], 1);
+    assert_eq!(v["fields"][0]["field"], "dept\tstatus");
+    let frequencies = v["fields"][0]["frequencies"].as_array().unwrap();
+    assert_eq!(frequencies[0]["value"], "sales\topen");
+    assert_eq!(frequencies[0]["count"], 2);
+}
+
+#[test]
+fn frequency_combine_single_column() {
+    // with a single selected column, --combine is a no-op
+    let (wrk, mut cmd) = setup("frequency_combine_single_column");
+    cmd.arg("--combine")
+        .args(["--limit", "0"])
+        .args(["--select", "h2"]);
+
+    let mut got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    got.sort_unstable();
+    let expected = vec![
+        svec!["field", "value", "count", "percentage"],
+        svec!["h2", "Y", "1", "14.28571"],
+        svec!["h2", "x", "1", "14.28571"],
+        svec!["h2", "y", "2", "28.57143"],
+        svec!["h2", "z", "3", "42.85714"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_json_entropy() {
+    let wrk = Workdir::new("frequency_json_entropy");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["h1", "h2"],
+            svec!["a", "x"],
+            svec!["a", "x"],
+            svec!["b", "x"],
+            svec!["b", "x"],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--limit", "0"]).arg("--json").arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let v: Value = serde_json::from_str(&got).unwrap();
+
+    // h1 is a 50/50 split of two values - exactly 1 bit of entropy,
+    // which is also the maximum for a cardinality of 2
+    assert_eq!(v["fields"][0]["entropy"], 1.0);
+    assert_eq!(v["fields"][0]["normalized_entropy"], 1.0);
+
+    // h2 is constant - no dispersion at all
+    assert_eq!(v["fields"][1]["entropy"], 0.0);
+    assert_eq!(v["fields"][1]["normalized_entropy"], 0.0);
+}
+
+#[test]
+fn frequency_json_entropy_all_unique() {
+    let wrk = Workdir::new("frequency_json_entropy_all_unique");
+    let testdata = wrk.load_test_file("boston311-100.csv");
+
+    let mut stats_cmd = wrk.command("stats");
+    stats_cmd
+        .arg(testdata.clone())
+        .arg("--cardinality")
+        .arg("--stats-jsonl");
+    wrk.assert_success(&mut stats_cmd);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--select", "1"]).arg("--json").arg(testdata);
+
+    let got: String = wrk.stdout(&mut cmd);
+    let v: Value = serde_json::from_str(&got).unwrap();
+
+    // the all-unique ID column is short-circuited, so entropy is null
+    assert_eq!(v["fields"][0]["field"], "case_enquiry_id");
+    assert!(v["fields"][0]["entropy"].is_null());
+    assert!(v["fields"][0]["normalized_entropy"].is_null());
+}
+
+#[test]
+fn frequency_normalize_to_max() {
+    let wrk = Workdir::new("frequency_normalize_to_max");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["h1"],
+            svec!["a"],
+            svec!["a"],
+            svec!["a"],
+            svec!["a"],
+            svec!["b"],
+            svec!["b"],
+            svec!["c"],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--normalize-to-max").arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["field", "value", "count", "percentage", "normalized"],
+        svec!["h1", "a", "4", "57.14286", "100"],
+        svec!["h1", "b", "2", "28.57143", "50"],
+        svec!["h1", "c", "1", "14.28571", "25"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_md() {
+    let wrk = Workdir::new("frequency_md");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["h1", "h2"],
+            svec!["a", "z"],
+            svec!["a", "z"],
+            svec!["a", "z"],
+            svec!["b", "y"],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--md").arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = "\
+### h1
+
+| field | value | count | percentage |
+|---|---|---|---|
+| h1 | a | 3 | 75 |
+| h1 | b | 1 | 25 |
+
+### h2
+
+| field | value | count | percentage |
+|---|---|---|---|
+| h2 | z | 3 | 75 |
+| h2 | y | 1 | 25 |";
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_md_single_field() {
+    let wrk = Workdir::new("frequency_md_single_field");
+    wrk.create(
+        "in.csv",
+        vec![svec!["h1"], svec!["a"], svec!["a"], svec!["b"]],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--md").arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    // a single field gets no "###" heading
+    let expected = "\
+| field | value | count | percentage |
+|---|---|---|---|
+| h1 | a | 2 | 66.66667 |
+| h1 | b | 1 | 33.33333 |";
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_md_with_json_conflict() {
+    let wrk = Workdir::new("frequency_md_with_json_conflict");
+    wrk.create("in.csv", vec![svec!["h1"], svec!["a"]]);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--md").arg("--json").arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn frequency_split_output() {
+    let wrk = Workdir::new("frequency_split_output");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["h1", "h 1"],
+            svec!["a", "z"],
+            svec!["a", "z"],
+            svec!["a", "z"],
+            svec!["b", "y"],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--split-output", "freqs"]).arg("in.csv");
+    wrk.assert_success(&mut cmd);
+
+    let got = wrk.read_to_string("freqs/h1.csv").unwrap();
+    let expected = "value,count,percentage\na,3,75\nb,1,25\n";
+    assert_eq!(got, expected);
+
+    // "h 1" sanitizes to "h1" too, so it gets a _1 suffix
+    let got = wrk.read_to_string("freqs/h1_1.csv").unwrap();
+    let expected = "value,count,percentage\nz,3,75\ny,1,25\n";
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_split_output_json() {
+    let wrk = Workdir::new("frequency_split_output_json");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["h1", "h2"],
+            svec!["a", "z"],
+            svec!["a", "z"],
+            svec!["b", "y"],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--split-output", "freqs"])
+        .arg("--json")
+        .arg("in.csv");
+    wrk.assert_success(&mut cmd);
+
+    let got = wrk.read_to_string("freqs/h1.json").unwrap();
+    let v: Value = serde_json::from_str(&got).unwrap();
+    assert_eq!(v["field"], "h1");
+    assert_eq!(v["frequencies"][0]["value"], "a");
+    assert_eq!(v["frequencies"][0]["count"], 2);
+
+    let got = wrk.read_to_string("freqs/h2.json").unwrap();
+    let v: Value = serde_json::from_str(&got).unwrap();
+    assert_eq!(v["field"], "h2");
+    assert_eq!(v["frequencies"][0]["value"], "z");
+}
+
+#[test]
+fn frequency_split_output_with_jsonl_conflict() {
+    let wrk = Workdir::new("frequency_split_output_with_jsonl_conflict");
+    wrk.create("in.csv", vec![svec!["h1"], svec!["a"]]);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--split-output", "freqs"])
+        .arg("--jsonl")
+        .arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn frequency_outliers() {
+    let wrk = Workdir::new("frequency_outliers");
+    let mut rows = vec![svec!["id", "status"]];
+    for i in 0..11 {
+        rows.push(svec![i.to_string(), "active"]);
+    }
+    for i in 11..16 {
+        rows.push(svec![i.to_string(), "inactive"]);
+    }
+    rows.push(svec!["16", "unknown"]);
+    rows.push(svec!["17", "unknown"]);
+    // long-tail data-entry errors
+    rows.push(svec!["18", "activ"]);
+    rows.push(svec!["19", "actve"]);
+    wrk.create("in.csv", rows);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--outliers", "2"]).arg("in.csv");
+
+    // only the rare values are reported, rarest first; the all-unique
+    // id column is skipped entirely
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["field", "value", "count", "percentage"],
+        svec!["status", "activ", "1", "5"],
+        svec!["status", "actve", "1", "5"],
+        svec!["status", "unknown", "2", "10"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_outliers_with_json_conflict() {
+    let wrk = Workdir::new("frequency_outliers_with_json_conflict");
+    wrk.create("in.csv", vec![svec!["h1"], svec!["a"]]);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--outliers", "1"]).arg("--json").arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn frequency_first_seen_order() {
+    let wrk = Workdir::new("frequency_first_seen_order");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["status"],
+            svec![""],
+            svec!["pending"],
+            svec!["active"],
+            svec!["active"],
+            svec!["done"],
+            svec!["active"],
+            svec!["done"],
+        ],
+    );
+
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--first-seen-order").arg("in.csv");
+
+    // values appear in first-appearance order, not descending count order
+    // (which would be active, done, (NULL), pending)
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["field", "value", "count", "percentage"],
+        svec!["status", "(NULL)", "1", "14.28571"],
+        svec!["status", "pending", "1", "14.28571"],
+        svec!["status", "active", "3", "42.85714"],
+        svec!["status", "done", "2", "28.57143"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_first_seen_order_with_asc_conflict() {
+    let wrk = Workdir::new("frequency_first_seen_order_with_asc_conflict");
+    wrk.create("in.csv", vec![svec!["h1"], svec!["a"]]);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--first-seen-order").arg("--asc").arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn frequency_two_pass() {
+    let (wrk, mut single_pass_cmd) = setup("frequency_two_pass");
+    let mut got: Vec<Vec<String>> = wrk.read_stdout(&mut single_pass_cmd);
+    got.sort_unstable();
+
+    // --two-pass only changes how the frequency hashmaps are sized;
+    // the frequency tables themselves must be identical
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--two-pass").arg("in.csv");
+    let mut two_pass_got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    two_pass_got.sort_unstable();
+
+    assert_eq!(two_pass_got, got);
+}
+
+#[test]
+fn frequency_two_pass_stdin() {
+    let wrk = Workdir::new("frequency_two_pass_stdin");
+    wrk.create("in.csv", vec![svec!["h1"], svec!["a"]]);
+
+    // stdin cannot be read twice
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--two-pass");
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn frequency_sample_estimates_distribution() {
+    let wrk = Workdir::new("frequency_sample_estimates_distribution");
+    // 200 rows with a fixed 60/30/10 percent distribution
+    let mut rows = vec![svec!["status"]];
+    for i in 0..200 {
+        rows.push(svec![match i % 10 {
+            0..=5 => "active",
+            6..=8 => "done",
+            _ => "pending",
+        }]);
+    }
+    wrk.create("in.csv", rows);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--sample", "100"])
+        .args(["--seed", "42"])
+        .arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    assert_eq!(got[0], svec!["field", "value", "count", "percentage"]);
+
+    // the sample is exactly half the file, so the scale factor is exactly 2
+    // and the scaled counts must sum back to the full row count
+    let total: u64 = got[1..]
+        .iter()
+        .map(|row| row[2].parse::<u64>().unwrap())
+        .sum();
+    assert_eq!(total, 200);
+
+    // with a seeded sample of half the file, the estimated proportions
+    // should approximate the real 60/30/10 split
+    for (value, expected_pct) in [("active", 60.0), ("done", 30.0), ("pending", 10.0)] {
+        let pct: f64 = got[1..]
+            .iter()
+            .find(|row| row[1] == value)
+            .unwrap_or_else(|| panic!("{value} missing from sampled frequency table"))[3]
+            .parse()
+            .unwrap();
+        assert!(
+            (pct - expected_pct).abs() < 10.0,
+            "{value}: estimated {pct}% not within 10% of {expected_pct}%"
+        );
+    }
+}
+
+#[test]
+fn frequency_sample_seed_deterministic() {
+    let wrk = Workdir::new("frequency_sample_seed_deterministic");
+    let mut rows = vec![svec!["h1"]];
+    for i in 0..100 {
+        rows.push(svec![if i % 3 == 0 { "x" } else { "y" }]);
+    }
+    wrk.create("in.csv", rows);
+
+    let mut first_cmd = wrk.command("frequency");
+    first_cmd
+        .args(["--sample", "20"])
+        .args(["--seed", "42"])
+        .arg("in.csv");
+    let first: Vec<Vec<String>> = wrk.read_stdout(&mut first_cmd);
+
+    // the same seed always draws the same reservoir
+    let mut second_cmd = wrk.command("frequency");
+    second_cmd
+        .args(["--sample", "20"])
+        .args(["--seed", "42"])
+        .arg("in.csv");
+    let second: Vec<Vec<String>> = wrk.read_stdout(&mut second_cmd);
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn frequency_sample_with_two_pass_conflict() {
+    let wrk = Workdir::new("frequency_sample_with_two_pass_conflict");
+    wrk.create("in.csv", vec![svec!["h1"], svec!["a"]]);
+
+    let mut cmd = wrk.command("frequency");
+    cmd.args(["--sample", "10"]).arg("--two-pass").arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn frequency_collapse_whitespace() {
+    let wrk = Workdir::new("frequency_collapse_whitespace");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["city"],
+            svec!["New  York"],
+            svec!["New York"],
+            svec!["New\tYork"],
+            svec!["Boston"],
+        ],
+    );
+
+    // without the flag, the whitespace variants count separately
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("in.csv");
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    assert_eq!(got.len(), 5);
+
+    // with it, they group as one value
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--collapse-whitespace").arg("in.csv");
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["field", "value", "count", "percentage"],
+        svec!["city", "New York", "3", "75"],
+        svec!["city", "Boston", "1", "25"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn frequency_strip_bom_values() {
+    let wrk = Workdir::new("frequency_strip_bom_values");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["city"],
+            svec!["\u{feff}Boston"],
+            svec!["Boston"],
+            svec!["Boston"],
+            svec!["Chicago"],
+        ],
+    );
+
+    // without the flag, the BOM-contaminated value counts separately
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("in.csv");
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    assert_eq!(got.len(), 4);
+
+    // with it, it groups with its clean counterparts
+    let mut cmd = wrk.command("frequency");
+    cmd.arg("--strip-bom-values").arg("in.csv");
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["field", "value", "count", "percentage"],
+        svec!["city", "Boston", "3", "75"],
+        svec!["city", "Chicago", "1", "25"],
+    ];
+    assert_eq!(got, expected);
+}
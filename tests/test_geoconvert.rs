@@ -103,3 +103,434 @@ POLYGON((-...,4,Ackerly,0,0
 POLYGON((-...,5,Addison,0,0"#;
     assert_eq!(got, expected);
 }
+
+#[test]
+fn geoconvert_geojson_to_csv_flatten_properties() {
+    let wrk = Workdir::new("geoconvert_geojson_to_csv_flatten_properties");
+    wrk.create_from_string(
+        "data.geojson",
+        r#"{
+  "type": "Feature",
+  "geometry": {
+    "type": "Point",
+    "coordinates": [125.6, 10.1]
+  },
+  "properties": {
+    "name": "Dinagat Islands",
+    "address": {
+      "city": "San Jose",
+      "zip": "7105"
+    }
+  }
+}"#,
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.geojson")
+        .arg("geojson")
+        .arg("csv")
+        .arg("--flatten-properties");
+
+    wrk.assert_success(&mut cmd);
+
+    // the nested address object is flattened into dotted-key columns
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let headers = &got[0];
+    let city_idx = headers
+        .iter()
+        .position(|h| h == "address.city")
+        .expect("flattened address.city column");
+    assert_eq!(got[1][city_idx], "San Jose");
+    let zip_idx = headers
+        .iter()
+        .position(|h| h == "address.zip")
+        .expect("flattened address.zip column");
+    assert_eq!(got[1][zip_idx], "7105");
+    let name_idx = headers
+        .iter()
+        .position(|h| h == "name")
+        .expect("scalar name column");
+    assert_eq!(got[1][name_idx], "Dinagat Islands");
+}
+
+#[test]
+fn geoconvert_flatten_properties_invalid_output() {
+    let wrk = Workdir::new("geoconvert_flatten_properties_invalid_output");
+    wrk.create_from_string(
+        "data.geojson",
+        r#"{
+  "type": "Feature",
+  "geometry": {
+    "type": "Point",
+    "coordinates": [125.6, 10.1]
+  },
+  "properties": {
+    "name": "Dinagat Islands"
+  }
+}"#,
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.geojson")
+        .arg("geojson")
+        .arg("svg")
+        .arg("--flatten-properties");
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn geoconvert_geojsonl_to_csv() {
+    let wrk = Workdir::new("geoconvert_geojsonl_to_csv");
+    wrk.create_from_string(
+        "data.geojsonl",
+        r#"{"type":"Feature","geometry":{"type":"Point","coordinates":[125.6,10.1]},"properties":{"name":"Dinagat Islands"}}
+{"type":"Feature","geometry":{"type":"Point","coordinates":[-74.0,40.7]},"properties":{"name":"New York"}}
+"#,
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.geojsonl").arg("geojsonl").arg("csv");
+
+    wrk.assert_success(&mut cmd);
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["geometry", "name"],
+        svec!["POINT(125.6 10.1)", "Dinagat Islands"],
+        svec!["POINT(-74 40.7)", "New York"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn geoconvert_geojsonl_to_geojson() {
+    let wrk = Workdir::new("geoconvert_geojsonl_to_geojson");
+    wrk.create_from_string(
+        "data.geojsonl",
+        r#"{"type":"Feature","geometry":{"type":"Point","coordinates":[125.6,10.1]},"properties":{"name":"Dinagat Islands"}}
+{"type":"Feature","geometry":{"type":"Point","coordinates":[-74.0,40.7]},"properties":{"name":"New York"}}
+"#,
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.geojsonl").arg("geojsonl").arg("geojson");
+
+    wrk.assert_success(&mut cmd);
+
+    let got: String = wrk.stdout(&mut cmd);
+    assert!(got.contains(r#""type": "FeatureCollection""#) || got.contains("FeatureCollection"));
+    assert!(got.contains("Dinagat Islands"));
+    assert!(got.contains("New York"));
+}
+
+#[test]
+fn geoconvert_geojsonl_to_geojsonl_invalid() {
+    let wrk = Workdir::new("geoconvert_geojsonl_to_geojsonl_invalid");
+    wrk.create_from_string(
+        "data.geojsonl",
+        r#"{"type":"Feature","geometry":{"type":"Point","coordinates":[125.6,10.1]},"properties":{"name":"Dinagat Islands"}}
+"#,
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.geojsonl").arg("geojsonl").arg("geojsonl");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn geoconvert_csv_latlon_reproject_osgb36_to_wgs84() {
+    let wrk = Workdir::new("geoconvert_csv_latlon_reproject_osgb36_to_wgs84");
+    // adur-area easting/northing in OSGB36 (EPSG:27700)
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["easting", "northing", "name"],
+            svec!["518000", "103000", "Shoreham"],
+        ],
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.csv")
+        .arg("csv")
+        .arg("geojson")
+        .args(["--latitude", "northing"])
+        .args(["--longitude", "easting"])
+        .args(["--from-crs", "EPSG:27700"])
+        .args(["--to-crs", "EPSG:4326"]);
+
+    wrk.assert_success(&mut cmd);
+
+    let got: String = wrk.stdout(&mut cmd);
+    // EPSG:27700 (518000, 103000) is roughly (-0.32, 50.8) in WGS84
+    assert!(got.contains("50.8"), "expected WGS84 latitude in {got}");
+    assert!(got.contains("-0.3"), "expected WGS84 longitude in {got}");
+    assert!(!got.contains("518000"), "easting should have been reprojected: {got}");
+    assert!(got.contains("Shoreham"));
+}
+
+#[test]
+fn geoconvert_reproject_same_crs_noop() {
+    let wrk = Workdir::new("geoconvert_reproject_same_crs_noop");
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["lat", "lon", "name"],
+            svec!["10.1", "125.6", "Dinagat Islands"],
+        ],
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.csv")
+        .arg("csv")
+        .arg("geojson")
+        .args(["--latitude", "lat"])
+        .args(["--longitude", "lon"])
+        .args(["--from-crs", "EPSG:4326"])
+        .args(["--to-crs", "EPSG:4326"]);
+
+    wrk.assert_success(&mut cmd);
+
+    let got: String = wrk.stdout(&mut cmd);
+    assert!(got.contains("10.1"));
+    assert!(got.contains("125.6"));
+}
+
+#[test]
+fn geoconvert_reproject_unknown_epsg_code() {
+    let wrk = Workdir::new("geoconvert_reproject_unknown_epsg_code");
+    wrk.create(
+        "data.csv",
+        vec![svec!["lat", "lon"], svec!["10.1", "125.6"]],
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.csv")
+        .arg("csv")
+        .arg("geojson")
+        .args(["--latitude", "lat"])
+        .args(["--longitude", "lon"])
+        .args(["--from-crs", "EPSG:99999"])
+        .args(["--to-crs", "EPSG:4326"]);
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn geoconvert_reproject_requires_both_flags() {
+    let wrk = Workdir::new("geoconvert_reproject_requires_both_flags");
+    wrk.create(
+        "data.csv",
+        vec![svec!["lat", "lon"], svec!["10.1", "125.6"]],
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.csv")
+        .arg("csv")
+        .arg("geojson")
+        .args(["--latitude", "lat"])
+        .args(["--longitude", "lon"])
+        .args(["--from-crs", "EPSG:27700"]);
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn geoconvert_geojson_to_kml() {
+    let wrk = Workdir::new("geoconvert_geojson_to_kml");
+    wrk.create_from_string(
+        "file.geojson",
+        r#"{
+  "type": "FeatureCollection",
+  "features": [
+    {
+      "type": "Feature",
+      "geometry": {
+        "type": "Point",
+        "coordinates": [125.6, 10.1]
+      },
+      "properties": {
+        "name": "Dinagat Islands"
+      }
+    }
+  ]
+}"#,
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("file.geojson").arg("geojson").arg("kml");
+
+    wrk.assert_success(&mut cmd);
+
+    let got: String = wrk.stdout(&mut cmd);
+    assert!(got.contains(r#"<kml xmlns="http://www.opengis.net/kml/2.2"><Document>"#));
+    assert!(got.contains("<Placemark>"));
+    assert!(got.contains("<Point><coordinates>125.6,10.1</coordinates></Point>"));
+    assert!(
+        got.contains(r#"<Data name="name"><value>Dinagat Islands</value></Data>"#),
+        "properties should be preserved as ExtendedData: {got}"
+    );
+    assert!(got.contains("</Placemark>"));
+    assert!(got.contains("</Document></kml>"));
+}
+
+#[test]
+fn geoconvert_csv_latlon_to_kml() {
+    let wrk = Workdir::new("geoconvert_csv_latlon_to_kml");
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["lat", "lon", "name"],
+            svec!["10.1", "125.6", "Dinagat Islands"],
+        ],
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.csv")
+        .arg("csv")
+        .arg("kml")
+        .args(["--latitude", "lat"])
+        .args(["--longitude", "lon"]);
+
+    wrk.assert_success(&mut cmd);
+
+    let got: String = wrk.stdout(&mut cmd);
+    assert!(got.contains("<Placemark>"));
+    assert!(got.contains(r#"<Data name="name"><value>Dinagat Islands</value></Data>"#));
+}
+
+#[test]
+fn geoconvert_csv_geometry_by_index() {
+    let wrk = Workdir::new("geoconvert_csv_geometry_by_index");
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "name", "pop", "wkt"],
+            svec!["1", "Dinagat Islands", "128117", "POINT(125.6 10.1)"],
+        ],
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.csv")
+        .arg("csv")
+        .arg("geojson")
+        .args(["--geometry", "3"]);
+
+    wrk.assert_success(&mut cmd);
+
+    let got: String = wrk.stdout(&mut cmd);
+    assert!(got.contains(r#""coordinates":[125.6,10.1]"#) || got.contains("125.6"));
+    assert!(got.contains("Dinagat Islands"));
+}
+
+#[test]
+fn geoconvert_csv_geometry_index_out_of_range() {
+    let wrk = Workdir::new("geoconvert_csv_geometry_index_out_of_range");
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["name", "wkt"],
+            svec!["Dinagat Islands", "POINT(125.6 10.1)"],
+        ],
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.csv")
+        .arg("csv")
+        .arg("geojson")
+        .args(["--geometry", "5"]);
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn geoconvert_csv_latlon_geojson_coordinate_order() {
+    let wrk = Workdir::new("geoconvert_csv_latlon_geojson_coordinate_order");
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["lat", "lon", "name"],
+            svec!["10.1", "125.6", "Dinagat Islands"],
+        ],
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.csv")
+        .arg("csv")
+        .arg("geojson")
+        .args(["--latitude", "lat"])
+        .args(["--longitude", "lon"]);
+
+    wrk.assert_success(&mut cmd);
+
+    let got: String = wrk.stdout(&mut cmd);
+    // GeoJSON positions are [longitude, latitude]
+    assert!(
+        got.contains(r#""coordinates":[125.6,10.1]"#),
+        "expected lon,lat ordering in {got}"
+    );
+}
+
+#[test]
+fn geoconvert_csv_latlon_geojson_elevation() {
+    let wrk = Workdir::new("geoconvert_csv_latlon_geojson_elevation");
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["lat", "lon", "alt", "name"],
+            svec!["10.1", "125.6", "42.5", "Dinagat Islands"],
+        ],
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.csv")
+        .arg("csv")
+        .arg("geojson")
+        .args(["--latitude", "lat"])
+        .args(["--longitude", "lon"])
+        .args(["--elevation", "alt"]);
+
+    wrk.assert_success(&mut cmd);
+
+    let got: String = wrk.stdout(&mut cmd);
+    assert!(
+        got.contains(r#""coordinates":[125.6,10.1,42.5]"#),
+        "expected lon,lat,z ordering in {got}"
+    );
+    // the elevation column feeds the geometry, not the properties
+    assert!(!got.contains(r#""alt""#));
+}
+
+#[test]
+fn geoconvert_elevation_requires_latlon() {
+    let wrk = Workdir::new("geoconvert_elevation_requires_latlon");
+    wrk.create(
+        "data.csv",
+        vec![svec!["wkt", "alt"], svec!["POINT(125.6 10.1)", "42.5"]],
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.csv")
+        .arg("csv")
+        .arg("geojson")
+        .args(["--geometry", "wkt"])
+        .args(["--elevation", "alt"]);
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn geoconvert_csv_latlon_geojson_streaming_output() {
+    let wrk = Workdir::new("geoconvert_csv_latlon_geojson_streaming_output");
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["lat", "lon", "name"],
+            svec!["10.1", "125.6", "Dinagat Islands"],
+            svec!["40.7", "-74.0", "New York"],
+        ],
+    );
+    let mut cmd = wrk.command("geoconvert");
+    cmd.arg("data.csv")
+        .arg("csv")
+        .arg("geojson")
+        .args(["--latitude", "lat"])
+        .args(["--longitude", "lon"]);
+
+    wrk.assert_success(&mut cmd);
+
+    // byte-for-byte the same document the buffered implementation produced
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = concat!(
+        r#"{"type":"FeatureCollection","features":["#,
+        r#"{"type":"Feature","geometry":{"type":"Point","coordinates":[125.6,10.1]},"#,
+        r#""properties":{"name":"Dinagat Islands"}},"#,
+        r#"{"type":"Feature","geometry":{"type":"Point","coordinates":[-74.0,40.7]},"#,
+        r#""properties":{"name":"New York"}}]}"#
+    );
+    assert_eq!(got, expected);
+}
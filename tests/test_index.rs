@@ -362,3 +362,62 @@ fn future_time(ft: FileTime) -> FileTime {
     let secs = ft.unix_seconds();
     FileTime::from_unix_time(secs + 10_000, 0)
 }
+
+#[test]
+fn index_describe_current() {
+    let wrk = Workdir::new("index_describe_current");
+    wrk.create_indexed(
+        "in.csv",
+        vec![
+            svec!["letter", "number"],
+            svec!["a", "1"],
+            svec!["b", "2"],
+            svec!["c", "3"],
+        ],
+    );
+
+    let mut cmd = wrk.command("index");
+    cmd.arg("describe").arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    assert!(got.contains("records: 3"));
+    assert!(got.ends_with("index is current"));
+}
+
+#[test]
+fn index_describe_stale() {
+    let wrk = Workdir::new("index_describe_stale");
+    wrk.create_indexed(
+        "in.csv",
+        vec![
+            svec!["letter", "number"],
+            svec!["a", "1"],
+            svec!["b", "2"],
+        ],
+    );
+
+    let md = fs::metadata(wrk.path("in.csv.idx")).unwrap();
+    set_file_times(
+        wrk.path("in.csv"),
+        future_time(FileTime::from_last_access_time(&md)),
+        future_time(FileTime::from_last_modification_time(&md)),
+    )
+    .unwrap();
+
+    // describe must exit non-zero when the index is stale so CI can gate on it
+    let mut cmd = wrk.command("index");
+    cmd.arg("describe").arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn index_describe_missing_index() {
+    let wrk = Workdir::new("index_describe_missing_index");
+    wrk.create("in.csv", vec![svec!["letter"], svec!["a"]]);
+
+    let mut cmd = wrk.command("index");
+    cmd.arg("describe").arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
@@ -400,3 +400,95 @@ fn test_select_robustness() {
     let expected = vec![svec!["h2"], svec!["b"]];
     assert_eq!(got, expected);
 }
+
+select_test!(
+    select_regex_prefix,
+    "/^h1/",
+    "1,5",
+    ["h1", "h1"],
+    ["a", "e"]
+);
+
+select_test!(
+    select_regex_case_insensitive,
+    "/(?i)H[24]/",
+    "2,4",
+    ["h2", "h4"],
+    ["b", "d"]
+);
+
+#[test]
+fn select_regex_nomatch_allow_empty() {
+    let wrk = Workdir::new("select_regex_nomatch_allow_empty");
+    wrk.create("data.csv", data(true));
+    let mut cmd = wrk.command("select");
+    cmd.arg("--allow-empty").arg("/nomatch/").arg("data.csv");
+    wrk.assert_success(&mut cmd);
+}
+
+#[test]
+fn select_not_regex_nomatch_allow_empty() {
+    let wrk = Workdir::new("select_not_regex_nomatch_allow_empty");
+    wrk.create("data.csv", data(true));
+    let mut cmd = wrk.command("select");
+    cmd.arg("--allow-empty").arg("!/nomatch/").arg("data.csv");
+
+    // inverting a regex that matched nothing selects every column
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    assert_eq!(got, data(true));
+}
+
+select_test!(
+    select_not_name_list,
+    "!h2,h4",
+    "!2,4",
+    ["h1", "h[]3", "h1"],
+    ["a", "c", "e"]
+);
+
+select_test!(
+    select_not_multi_range,
+    "!h1-h2,h4",
+    "!1-2,4",
+    ["h[]3", "h1"],
+    ["c", "e"]
+);
+
+#[test]
+fn select_dedup_select() {
+    let wrk = Workdir::new("select_dedup_select");
+    wrk.create("data.csv", data(true));
+    let mut cmd = wrk.command("select");
+    cmd.arg("--dedup-select").arg("h1,h2,h1[0]").arg("data.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["h1", "h2"], svec!["a", "b"]];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn select_dedup_select_no_headers() {
+    let wrk = Workdir::new("select_dedup_select_no_headers");
+    wrk.create("data.csv", data(false));
+    let mut cmd = wrk.command("select");
+    cmd.arg("--dedup-select")
+        .arg("--no-headers")
+        .arg("1,2,1")
+        .arg("data.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["a", "b"]];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn select_duplicates_kept_by_default() {
+    let wrk = Workdir::new("select_duplicates_kept_by_default");
+    wrk.create("data.csv", data(true));
+    let mut cmd = wrk.command("select");
+    cmd.arg("h2,h2").arg("data.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["h2", "h2"], svec!["b", "b"]];
+    assert_eq!(got, expected);
+}
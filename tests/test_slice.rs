@@ -1112,3 +1112,443 @@ fn slice_from_jsonl_with_decimal_precision_vs_float() {
     assert!(!float_output.contains("2.7182818284590452353602874"));
     assert!(!float_output.contains("1.4142135623730950488016887"));
 }
+
+fn test_slice_repeat(name: &str, use_index: bool) {
+    let (wrk, mut cmd) = setup(name, true, use_index);
+    cmd.args(["--start", "1"])
+        .args(["--len", "2"])
+        .args(["--repeat", "3"]);
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["header"],
+        svec!["b"],
+        svec!["c"],
+        svec!["b"],
+        svec!["c"],
+        svec!["b"],
+        svec!["c"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_repeat_no_index() {
+    test_slice_repeat("slice_repeat_no_index", false);
+}
+
+#[test]
+fn slice_repeat_index() {
+    test_slice_repeat("slice_repeat_index", true);
+}
+
+#[test]
+fn slice_repeat_zero() {
+    let (wrk, mut cmd) = setup("slice_repeat_zero", true, false);
+    cmd.args(["--start", "1"])
+        .args(["--len", "2"])
+        .args(["--repeat", "0"]);
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn slice_neg_index_2_no_index() {
+    // resolved in a single streaming pass with a ring buffer,
+    // as there is no index to count rows with
+    test_index("slice_neg_index_2_no_index", -2, "d", true, false);
+}
+#[test]
+fn slice_neg_index_2_no_headers_no_index() {
+    test_index("slice_neg_index_2_no_headers_no_index", -2, "d", false, false);
+}
+
+fn test_slice_every(name: &str, use_index: bool) {
+    let (wrk, mut cmd) = setup(name, true, use_index);
+    cmd.args(["--every", "2"]);
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["header"], svec!["a"], svec!["c"], svec!["e"]];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_every_no_index() {
+    test_slice_every("slice_every_no_index", false);
+}
+
+#[test]
+fn slice_every_index() {
+    test_slice_every("slice_every_index", true);
+}
+
+fn test_slice_every_with_range(name: &str, use_index: bool) {
+    let (wrk, mut cmd) = setup(name, true, use_index);
+    cmd.args(["--start", "1"])
+        .args(["--len", "3"])
+        .args(["--every", "2"]);
+
+    // the stride starts at the first row of the slice
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["header"], svec!["b"], svec!["d"]];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_every_with_range_no_index() {
+    test_slice_every_with_range("slice_every_with_range_no_index", false);
+}
+
+#[test]
+fn slice_every_with_range_index() {
+    test_slice_every_with_range("slice_every_with_range_index", true);
+}
+
+fn test_slice_every_invert(name: &str, use_index: bool) {
+    let (wrk, mut cmd) = setup(name, true, use_index);
+    cmd.args(["--every", "2"]).arg("--invert");
+
+    // --invert keeps the rows NOT selected by the stride
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["header"], svec!["b"], svec!["d"]];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_every_invert_no_index() {
+    test_slice_every_invert("slice_every_invert_no_index", false);
+}
+
+#[test]
+fn slice_every_invert_index() {
+    test_slice_every_invert("slice_every_invert_index", true);
+}
+
+fn test_slice_every_offset(name: &str, use_index: bool) {
+    let (wrk, mut cmd) = setup(name, true, use_index);
+    cmd.args(["--every", "2"]).args(["--every-offset", "1"]);
+
+    // the stride starts on the 2nd row of the slice, selecting the
+    // odd-positioned rows
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["header"], svec!["b"], svec!["d"]];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_every_offset_no_index() {
+    test_slice_every_offset("slice_every_offset_no_index", false);
+}
+
+#[test]
+fn slice_every_offset_index() {
+    test_slice_every_offset("slice_every_offset_index", true);
+}
+
+fn test_slice_every_offset_invert(name: &str, use_index: bool) {
+    let (wrk, mut cmd) = setup(name, true, use_index);
+    cmd.args(["--every", "2"])
+        .args(["--every-offset", "1"])
+        .arg("--invert");
+
+    // --invert keeps the rows NOT selected by the phased stride
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["header"], svec!["a"], svec!["c"], svec!["e"]];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_every_offset_invert_no_index() {
+    test_slice_every_offset_invert("slice_every_offset_invert_no_index", false);
+}
+
+#[test]
+fn slice_every_offset_invert_index() {
+    test_slice_every_offset_invert("slice_every_offset_invert_index", true);
+}
+
+#[test]
+fn slice_every_offset_too_large() {
+    let (wrk, mut cmd) = setup("slice_every_offset_too_large", true, false);
+    cmd.args(["--every", "2"]).args(["--every-offset", "2"]);
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn slice_every_zero() {
+    let (wrk, mut cmd) = setup("slice_every_zero", true, false);
+    cmd.args(["--every", "0"]);
+    wrk.assert_err(&mut cmd);
+}
+
+fn test_slice_ranges(name: &str, use_index: bool) {
+    let (wrk, mut cmd) = setup(name, true, use_index);
+    cmd.args(["--ranges", "0-2,3-"]);
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["header"], svec!["a"], svec!["b"], svec!["d"], svec!["e"]];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_ranges_no_index() {
+    test_slice_ranges("slice_ranges_no_index", false);
+}
+
+#[test]
+fn slice_ranges_index() {
+    test_slice_ranges("slice_ranges_index", true);
+}
+
+fn test_slice_ranges_overlap(name: &str, use_index: bool) {
+    let (wrk, mut cmd) = setup(name, true, use_index);
+    // overlapping ranges are merged, so each row is emitted exactly once
+    cmd.args(["--ranges", "2-5,1-3"]);
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["header"], svec!["b"], svec!["c"], svec!["d"], svec!["e"]];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_ranges_overlap_no_index() {
+    test_slice_ranges_overlap("slice_ranges_overlap_no_index", false);
+}
+
+#[test]
+fn slice_ranges_overlap_index() {
+    test_slice_ranges_overlap("slice_ranges_overlap_index", true);
+}
+
+fn test_slice_ranges_json(name: &str, use_index: bool) {
+    let (wrk, mut cmd) = setup(name, true, use_index);
+    cmd.args(["--ranges", "0-1,2-4"]).arg("--json");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = r#"[{"header":"a"},{"header":"c"},{"header":"d"}]"#;
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_ranges_json_no_index() {
+    test_slice_ranges_json("slice_ranges_json_no_index", false);
+}
+
+#[test]
+fn slice_ranges_json_index() {
+    test_slice_ranges_json("slice_ranges_json_index", true);
+}
+
+#[test]
+fn slice_ranges_with_start_conflict() {
+    let (wrk, mut cmd) = setup("slice_ranges_with_start_conflict", true, false);
+    cmd.args(["--ranges", "0-2"]).args(["--start", "1"]);
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn slice_ranges_invalid_spec() {
+    let (wrk, mut cmd) = setup("slice_ranges_invalid_spec", true, false);
+    cmd.args(["--ranges", "0-2,oops"]);
+    wrk.assert_err(&mut cmd);
+}
+
+fn test_slice_jsonl(name: &str, use_index: bool) {
+    let (wrk, mut cmd) = setup(name, true, use_index);
+    cmd.args(["--start", "1"]).args(["--len", "2"]).arg("--jsonl");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = "{\"header\":\"b\"}\n{\"header\":\"c\"}";
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_jsonl_no_index() {
+    test_slice_jsonl("slice_jsonl_no_index", false);
+}
+
+#[test]
+fn slice_jsonl_index() {
+    test_slice_jsonl("slice_jsonl_index", true);
+}
+
+#[test]
+fn slice_jsonl_no_headers() {
+    let (wrk, mut cmd) = setup("slice_jsonl_no_headers", false, false);
+    cmd.args(["--start", "0"])
+        .args(["--len", "2"])
+        .arg("--jsonl")
+        .arg("--no-headers");
+
+    // with --no-headers, the keys are the zero-based column indices
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = "{\"0\":\"a\"}\n{\"0\":\"b\"}";
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_jsonl_ranges() {
+    let (wrk, mut cmd) = setup("slice_jsonl_ranges", true, false);
+    cmd.args(["--ranges", "0-1,3-4"]).arg("--jsonl");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = "{\"header\":\"a\"}\n{\"header\":\"d\"}";
+    assert_eq!(got, expected);
+}
+
+fn quote_style_data() -> Vec<Vec<String>> {
+    vec![
+        svec!["name", "notes", "count"],
+        svec!["apple", "red, ripe", "3"],
+        svec!["banana", "yellow", "12"],
+    ]
+}
+
+#[test]
+fn slice_quote_style_always() {
+    let wrk = Workdir::new("slice_quote_style_always");
+    wrk.create("in.csv", quote_style_data());
+
+    let mut cmd = wrk.command("slice");
+    cmd.args(["--quote-style", "always"]).arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = concat!(
+        "\"name\",\"notes\",\"count\"\n",
+        "\"apple\",\"red, ripe\",\"3\"\n",
+        "\"banana\",\"yellow\",\"12\""
+    );
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_quote_style_non_numeric() {
+    let wrk = Workdir::new("slice_quote_style_non_numeric");
+    wrk.create("in.csv", quote_style_data());
+
+    let mut cmd = wrk.command("slice");
+    cmd.args(["--quote-style", "non-numeric"]).arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = concat!(
+        "\"name\",\"notes\",\"count\"\n",
+        "\"apple\",\"red, ripe\",3\n",
+        "\"banana\",\"yellow\",12"
+    );
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_quote_style_never() {
+    let wrk = Workdir::new("slice_quote_style_never");
+    wrk.create("in.csv", quote_style_data());
+
+    let mut cmd = wrk.command("slice");
+    cmd.args(["--quote-style", "never"]).arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = concat!(
+        "name,notes,count\n",
+        "apple,red, ripe,3\n",
+        "banana,yellow,12"
+    );
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_quote_style_necessary_is_default() {
+    let wrk = Workdir::new("slice_quote_style_necessary_is_default");
+    wrk.create("in.csv", quote_style_data());
+
+    let mut cmd = wrk.command("slice");
+    cmd.args(["--quote-style", "necessary"]).arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = concat!(
+        "name,notes,count\n",
+        "apple,\"red, ripe\",3\n",
+        "banana,yellow,12"
+    );
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_quote_style_env_default() {
+    let wrk = Workdir::new("slice_quote_style_env_default");
+    wrk.create("in.csv", quote_style_data());
+
+    let mut cmd = wrk.command("slice");
+    cmd.env("QSV_DEFAULT_QUOTE_STYLE", "always").arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = concat!(
+        "\"name\",\"notes\",\"count\"\n",
+        "\"apple\",\"red, ripe\",\"3\"\n",
+        "\"banana\",\"yellow\",\"12\""
+    );
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_quote_style_invalid() {
+    let wrk = Workdir::new("slice_quote_style_invalid");
+    wrk.create("in.csv", quote_style_data());
+
+    let mut cmd = wrk.command("slice");
+    cmd.args(["--quote-style", "sometimes"]).arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn slice_crlf() {
+    let wrk = Workdir::new("slice_crlf");
+    wrk.create(
+        "in.csv",
+        vec![svec!["name", "count"], svec!["apple", "3"], svec![
+            "banana", "12"
+        ]],
+    );
+
+    let mut cmd = wrk.command("slice");
+    cmd.arg("--crlf").arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = "name,count\r\napple,3\r\nbanana,12";
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_crlf_env() {
+    let wrk = Workdir::new("slice_crlf_env");
+    wrk.create(
+        "in.csv",
+        vec![svec!["name", "count"], svec!["apple", "3"], svec![
+            "banana", "12"
+        ]],
+    );
+
+    let mut cmd = wrk.command("slice");
+    cmd.env("QSV_CRLF", "1").arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = "name,count\r\napple,3\r\nbanana,12";
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn slice_lf_is_default() {
+    let wrk = Workdir::new("slice_lf_is_default");
+    wrk.create(
+        "in.csv",
+        vec![svec!["name", "count"], svec!["apple", "3"], svec![
+            "banana", "12"
+        ]],
+    );
+
+    let mut cmd = wrk.command("slice");
+    cmd.arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = "name,count\napple,3\nbanana,12";
+    assert_eq!(got, expected);
+}
@@ -319,6 +319,37 @@ fn qsv_sniff_tab_delimiter_env() {
     assert_eq!(&*got, EXPECTED_CSV)
 }
 
+#[test]
+fn qsv_sniff_comma_delimiter_env() {
+    let wrk = Workdir::new("qsv_sniff_comma_delimiter_env");
+    wrk.create_with_delim("in.file", data(), b',');
+
+    let mut cmd = wrk.command("input");
+    cmd.env("QSV_SNIFF_DELIMITER", "1");
+    cmd.arg("in.file");
+
+    let got: String = wrk.stdout(&mut cmd);
+    assert_eq!(&*got, EXPECTED_CSV)
+}
+
+#[test]
+fn qsv_sniff_delimiter_env_is_opt_in() {
+    // without QSV_SNIFF_DELIMITER, a semicolon file with no recognized
+    // extension parses as a single comma-delimited column
+    let wrk = Workdir::new("qsv_sniff_delimiter_env_is_opt_in");
+    wrk.create_with_delim("in.file", data(), b';');
+
+    let mut cmd = wrk.command("input");
+    cmd.arg("in.file");
+
+    let got: String = wrk.stdout(&mut cmd);
+    let expected = "\
+h1;h2;h3
+abcdefg;1;a
+a;2;z";
+    assert_eq!(&*got, expected)
+}
+
 #[test]
 fn sniff_json() {
     let wrk = Workdir::new("sniff_json");
@@ -751,3 +751,483 @@ where
         }
     }
 }
+
+#[test]
+fn sort_merge() {
+    let wrk = Workdir::new("sort_merge");
+    wrk.create(
+        "a.csv",
+        vec![
+            svec!["name", "value"],
+            svec!["apple", "1"],
+            svec!["mango", "3"],
+            svec!["pear", "5"],
+        ],
+    );
+    wrk.create(
+        "b.csv",
+        vec![
+            svec!["name", "value"],
+            svec!["banana", "2"],
+            svec!["orange", "4"],
+        ],
+    );
+
+    let mut cmd = wrk.command("sort");
+    cmd.arg("--merge").arg("a.csv").arg("b.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["name", "value"],
+        svec!["apple", "1"],
+        svec!["banana", "2"],
+        svec!["mango", "3"],
+        svec!["orange", "4"],
+        svec!["pear", "5"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn sort_merge_numeric_reverse() {
+    let wrk = Workdir::new("sort_merge_numeric_reverse");
+    wrk.create(
+        "a.csv",
+        vec![svec!["n"], svec!["10"], svec!["3"], svec!["1"]],
+    );
+    wrk.create("b.csv", vec![svec!["n"], svec!["7"], svec!["2"]]);
+
+    let mut cmd = wrk.command("sort");
+    cmd.args(["--merge", "--numeric", "--reverse"])
+        .arg("a.csv")
+        .arg("b.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["n"],
+        svec!["10"],
+        svec!["7"],
+        svec!["3"],
+        svec!["2"],
+        svec!["1"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn sort_merge_unsorted_input() {
+    let wrk = Workdir::new("sort_merge_unsorted_input");
+    wrk.create(
+        "a.csv",
+        vec![svec!["name"], svec!["mango"], svec!["apple"]],
+    );
+    wrk.create("b.csv", vec![svec!["name"], svec!["banana"]]);
+
+    let mut cmd = wrk.command("sort");
+    cmd.arg("--merge").arg("a.csv").arg("b.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn sort_with_row_index() {
+    let wrk = Workdir::new("sort_with_row_index");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["name", "number"],
+            svec!["charlie", "30"],
+            svec!["alpha", "10"],
+            svec!["bravo", "3"],
+        ],
+    );
+
+    let mut cmd = wrk.command("sort");
+    cmd.arg("--with-row-index").arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["name", "number", "row_index"],
+        svec!["alpha", "10", "1"],
+        svec!["bravo", "3", "2"],
+        svec!["charlie", "30", "0"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn sort_with_row_index_numeric_select() {
+    let wrk = Workdir::new("sort_with_row_index_numeric_select");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["name", "number"],
+            svec!["charlie", "30"],
+            svec!["alpha", "10"],
+            svec!["bravo", "3"],
+        ],
+    );
+
+    let mut cmd = wrk.command("sort");
+    cmd.args(["--select", "number"])
+        .arg("--numeric")
+        .arg("--with-row-index")
+        .arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["name", "number", "row_index"],
+        svec!["bravo", "3", "2"],
+        svec!["alpha", "10", "1"],
+        svec!["charlie", "30", "0"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn sort_with_row_index_merge_conflict() {
+    let wrk = Workdir::new("sort_with_row_index_merge_conflict");
+    wrk.create("in.csv", vec![svec!["n"], svec!["1"]]);
+
+    let mut cmd = wrk.command("sort");
+    cmd.arg("--merge").arg("--with-row-index").arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn sort_numeric_decimal_comma() {
+    let wrk = Workdir::new("sort_numeric_decimal_comma");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["N", "S"],
+            svec!["3,14", "a"],
+            svec!["10,5", "b"],
+            svec!["2,0", "c"],
+            svec!["1.234,56", "d"],
+        ],
+    );
+
+    let mut cmd = wrk.command("sort");
+    cmd.arg("-N").arg("--decimal-comma").arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["N", "S"],
+        svec!["2,0", "c"],
+        svec!["3,14", "a"],
+        svec!["10,5", "b"],
+        svec!["1.234,56", "d"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn sort_decimal_comma_requires_numeric() {
+    let wrk = Workdir::new("sort_decimal_comma_requires_numeric");
+    wrk.create("in.csv", vec![svec!["N"], svec!["1"]]);
+
+    let mut cmd = wrk.command("sort");
+    cmd.arg("--decimal-comma").arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn sort_external() {
+    let wrk = Workdir::new("sort_external");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["N", "S"],
+            svec!["10", "e"],
+            svec!["2", "a"],
+            svec!["7", "d"],
+            svec!["1", "b"],
+            svec!["4", "c"],
+        ],
+    );
+
+    // --mem-limit 0 forces a spilled run per row, exercising the k-way merge
+    let mut cmd = wrk.command("sort");
+    cmd.arg("-N")
+        .arg("--external")
+        .args(["--mem-limit", "0"])
+        .arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["N", "S"],
+        svec!["1", "b"],
+        svec!["2", "a"],
+        svec!["4", "c"],
+        svec!["7", "d"],
+        svec!["10", "e"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn sort_external_matches_in_memory() {
+    let wrk = Workdir::new("sort_external_matches_in_memory");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["name"],
+            svec!["Delta"],
+            svec!["alpha"],
+            svec!["Bravo"],
+            svec!["charlie"],
+            svec!["alpha"],
+            svec!["Bravo"],
+        ],
+    );
+
+    let mut in_memory_cmd = wrk.command("sort");
+    in_memory_cmd
+        .arg("--ignore-case")
+        .arg("--reverse")
+        .arg("--unique")
+        .arg("in.csv");
+    let in_memory: String = wrk.stdout(&mut in_memory_cmd);
+
+    let mut external_cmd = wrk.command("sort");
+    external_cmd
+        .arg("--ignore-case")
+        .arg("--reverse")
+        .arg("--unique")
+        .arg("--external")
+        .args(["--mem-limit", "0"])
+        .arg("in.csv");
+    let external: String = wrk.stdout(&mut external_cmd);
+
+    assert_eq!(external, in_memory);
+}
+
+#[test]
+fn sort_external_conflicts_with_random() {
+    let wrk = Workdir::new("sort_external_conflicts_with_random");
+    wrk.create("in.csv", vec![svec!["N"], svec!["1"]]);
+
+    let mut cmd = wrk.command("sort");
+    cmd.arg("--external").arg("--random").arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn sort_keys_num_desc_then_str_asc() {
+    let wrk = Workdir::new("sort_keys_num_desc_then_str_asc");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["name", "score"],
+            svec!["delta", "10"],
+            svec!["alpha", "2"],
+            svec!["charlie", "10"],
+            svec!["bravo", "2"],
+        ],
+    );
+
+    let mut cmd = wrk.command("sort");
+    cmd.args(["--keys", "2:num:desc,1:str:asc"]).arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["name", "score"],
+        svec!["charlie", "10"],
+        svec!["delta", "10"],
+        svec!["alpha", "2"],
+        svec!["bravo", "2"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn sort_keys_column_names() {
+    let wrk = Workdir::new("sort_keys_column_names");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["name", "score"],
+            svec!["delta", "10"],
+            svec!["alpha", "2"],
+        ],
+    );
+
+    let mut cmd = wrk.command("sort");
+    cmd.args(["--keys", "score:num"]).arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["name", "score"],
+        svec!["alpha", "2"],
+        svec!["delta", "10"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn sort_keys_invalid_modifier() {
+    let wrk = Workdir::new("sort_keys_invalid_modifier");
+    wrk.create("in.csv", vec![svec!["N"], svec!["1"]]);
+
+    let mut cmd = wrk.command("sort");
+    cmd.args(["--keys", "1:int"]).arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn sort_natural_zero_padded() {
+    let wrk = Workdir::new("sort_natural_zero_padded");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["file"],
+            svec!["file10"],
+            svec!["file002"],
+            svec!["file1"],
+            svec!["file01"],
+            svec!["file2"],
+        ],
+    );
+
+    let mut cmd = wrk.command("sort");
+    cmd.arg("--natural").arg("in.csv");
+
+    // equal numeric runs compare by value, with the shorter (unpadded)
+    // spelling first
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["file"],
+        svec!["file1"],
+        svec!["file01"],
+        svec!["file2"],
+        svec!["file002"],
+        svec!["file10"],
+    ];
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn sort_unique_duplicates_output() {
+    let wrk = Workdir::new("sort_unique_duplicates_output");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["key", "n"],
+            svec!["b", "1"],
+            svec!["a", "2"],
+            svec!["b", "3"],
+            svec!["a", "4"],
+            svec!["c", "5"],
+        ],
+    );
+
+    let mut cmd = wrk.command("sort");
+    cmd.args(["--select", "key"])
+        .arg("--unique")
+        .args(["--duplicates-output", "dups.csv"])
+        .arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["key", "n"],
+        svec!["a", "2"],
+        svec!["b", "1"],
+        svec!["c", "5"],
+    ];
+    assert_eq!(got, expected);
+
+    let dups = wrk.read_csv("dups.csv");
+    assert_eq!(dups, vec![svec!["a", "4"], svec!["b", "3"]]);
+
+    // the union of the deduped output and the duplicates file is the input
+    let mut union: Vec<Vec<String>> = got[1..].to_vec();
+    union.extend(dups);
+    union.sort();
+    let mut input_rows = vec![
+        svec!["b", "1"],
+        svec!["a", "2"],
+        svec!["b", "3"],
+        svec!["a", "4"],
+        svec!["c", "5"],
+    ];
+    input_rows.sort();
+    assert_eq!(union, input_rows);
+}
+
+#[test]
+fn sort_unique_duplicates_output_faster() {
+    let wrk = Workdir::new("sort_unique_duplicates_output_faster");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["key"],
+            svec!["b"],
+            svec!["a"],
+            svec!["b"],
+            svec!["a"],
+        ],
+    );
+
+    let mut cmd = wrk.command("sort");
+    cmd.arg("--unique")
+        .arg("--faster")
+        .args(["--duplicates-output", "dups.csv"])
+        .arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![svec!["key"], svec!["a"], svec!["b"]];
+    assert_eq!(got, expected);
+
+    let dups = wrk.read_csv("dups.csv");
+    assert_eq!(dups, vec![svec!["a"], svec!["b"]]);
+}
+
+#[test]
+fn sort_duplicates_output_requires_unique() {
+    let wrk = Workdir::new("sort_duplicates_output_requires_unique");
+    wrk.create("in.csv", vec![svec!["N"], svec!["1"]]);
+
+    let mut cmd = wrk.command("sort");
+    cmd.args(["--duplicates-output", "dups.csv"]).arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn sort_stable_preserves_tie_order() {
+    let wrk = Workdir::new("sort_stable_preserves_tie_order");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["key", "seq"],
+            svec!["b", "1"],
+            svec!["a", "2"],
+            svec!["b", "3"],
+            svec!["a", "4"],
+            svec!["b", "5"],
+        ],
+    );
+
+    // --stable overrides --faster, so equal keys keep their input order
+    let mut cmd = wrk.command("sort");
+    cmd.args(["--select", "key"])
+        .arg("--faster")
+        .arg("--stable")
+        .arg("in.csv");
+
+    let got: Vec<Vec<String>> = wrk.read_stdout(&mut cmd);
+    let expected = vec![
+        svec!["key", "seq"],
+        svec!["a", "2"],
+        svec!["a", "4"],
+        svec!["b", "1"],
+        svec!["b", "3"],
+        svec!["b", "5"],
+    ];
+    assert_eq!(got, expected);
+}
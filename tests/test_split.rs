@@ -856,6 +856,272 @@ fn split_kbsize_boston_5k_no_headers() {
     assert!(wrk.path("93.csv").exists());
 }
 
+#[test]
+fn split_kbsize_max_rows() {
+    let wrk = Workdir::new("split_kbsize_max_rows");
+    let mut rows = vec![svec!["h1", "h2"]];
+    for i in 0..10 {
+        rows.push(vec![format!("a{i}"), format!("b{i}")]);
+    }
+    wrk.create("in.csv", rows);
+
+    // the byte budget is generous, so every rollover is forced by the row cap
+    let mut cmd = wrk.command("split");
+    cmd.args(["--kb-size", "100"])
+        .args(["--max-rows", "3"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.run(&mut cmd);
+
+    assert!(wrk.path("0.csv").exists());
+    assert!(wrk.path("2.csv").exists());
+    assert!(wrk.path("5.csv").exists());
+    assert!(wrk.path("8.csv").exists());
+
+    let contents = wrk.read_to_string("0.csv").unwrap();
+    assert_eq!(contents, "h1,h2\na0,b0\na1,b1\na2,b2\n");
+    let contents = wrk.read_to_string("8.csv").unwrap();
+    assert_eq!(contents, "h1,h2\na9,b9\n");
+}
+
+#[test]
+fn split_max_chunks() {
+    let wrk = Workdir::new("split_max_chunks");
+    wrk.create("in.csv", data(true));
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "1"])
+        .args(["--max-chunks", "3"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.assert_err(&mut cmd);
+
+    // the first 3 chunks were written before the limit kicked in;
+    // they are left in place
+    assert!(wrk.path("0.csv").exists());
+    assert!(wrk.path("1.csv").exists());
+    assert!(wrk.path("2.csv").exists());
+    assert!(!wrk.path("3.csv").exists());
+}
+
+#[test]
+fn split_max_chunks_indexed() {
+    let wrk = Workdir::new("split_max_chunks_indexed");
+    wrk.create_indexed("in.csv", data(true));
+
+    // with an index the chunk count is known upfront,
+    // so nothing is written at all
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "1"])
+        .args(["--max-chunks", "3"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.assert_err(&mut cmd);
+
+    assert!(!wrk.path("0.csv").exists());
+}
+
+#[test]
+fn split_max_chunks_kbsize() {
+    let wrk = Workdir::new("split_max_chunks_kbsize");
+
+    let mut rows = vec![svec!["id", "name", "value"]];
+    for i in 0..200 {
+        rows.push(svec![
+            format!("{i}"),
+            format!("item_{i}"),
+            format!("value_{i}")
+        ]);
+    }
+    wrk.create("in.csv", rows);
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--kb-size", "1"])
+        .args(["--max-chunks", "2"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.assert_err(&mut cmd);
+
+    // the first 2 chunks were written before the limit kicked in
+    assert!(wrk.path("0.csv").exists());
+    assert_eq!(
+        std::fs::read_dir(wrk.path("."))
+            .unwrap()
+            .filter(|entry| {
+                entry
+                    .as_ref()
+                    .unwrap()
+                    .file_name()
+                    .to_string_lossy()
+                    .ends_with(".csv")
+                    && entry.as_ref().unwrap().file_name() != "in.csv"
+            })
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn split_kbsize_indexed_matches_sequential() {
+    let wrk = Workdir::new("split_kbsize_indexed_matches_sequential");
+
+    let mut rows = vec![svec!["id", "name", "value"]];
+    for i in 0..200 {
+        rows.push(svec![
+            format!("{i}"),
+            format!("item_{i}"),
+            format!("value_{i}")
+        ]);
+    }
+    wrk.create("seq.csv", rows.clone());
+    wrk.create_indexed("idx.csv", rows);
+
+    // without an index, kb-size splitting takes the sequential path;
+    // with an index it takes the parallel path - the chunks must be identical
+    let mut cmd = wrk.command("split");
+    cmd.args(["--kb-size", "1"])
+        .arg(&wrk.path("seq"))
+        .arg("seq.csv");
+    wrk.run(&mut cmd);
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--kb-size", "1"])
+        .arg(&wrk.path("par"))
+        .arg("idx.csv");
+    wrk.run(&mut cmd);
+
+    let list_chunks = |dir: &str| -> Vec<String> {
+        let mut files: Vec<String> = std::fs::read_dir(wrk.path(dir))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort_unstable();
+        files
+    };
+    let seq_files = list_chunks("seq");
+    assert!(seq_files.len() > 1);
+    assert_eq!(seq_files, list_chunks("par"));
+    for chunk in &seq_files {
+        let seq = std::fs::read(wrk.path("seq").join(chunk)).unwrap();
+        let par = std::fs::read(wrk.path("par").join(chunk)).unwrap();
+        assert_eq!(seq, par, "chunk {chunk} differs");
+    }
+
+    // same check with a --max-rows cap in play
+    let mut cmd = wrk.command("split");
+    cmd.args(["--kb-size", "1"])
+        .args(["--max-rows", "7"])
+        .arg(&wrk.path("seq_capped"))
+        .arg("seq.csv");
+    wrk.run(&mut cmd);
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--kb-size", "1"])
+        .args(["--max-rows", "7"])
+        .arg(&wrk.path("par_capped"))
+        .arg("idx.csv");
+    wrk.run(&mut cmd);
+
+    let seq_files = list_chunks("seq_capped");
+    assert!(seq_files.len() > 1);
+    assert_eq!(seq_files, list_chunks("par_capped"));
+    for chunk in &seq_files {
+        let seq = std::fs::read(wrk.path("seq_capped").join(chunk)).unwrap();
+        let par = std::fs::read(wrk.path("par_capped").join(chunk)).unwrap();
+        assert_eq!(seq, par, "chunk {chunk} differs");
+    }
+}
+
+#[test]
+fn split_max_rows_requires_kbsize() {
+    let wrk = Workdir::new("split_max_rows_requires_kbsize");
+    wrk.create("in.csv", data(true));
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .args(["--max-rows", "3"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+    let got = wrk.output_stderr(&mut cmd);
+    let expected = "usage error: --max-rows is only valid with --kb-size.\n";
+    assert_eq!(got, expected);
+}
+
+fn filter_count_cmd(wrk: &Workdir, size: &str, input: &str) -> std::process::Command {
+    // an appending filter counts invocations: one line per run
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", size])
+        .arg("--filter")
+        .arg(if cfg!(windows) {
+            "cmd /C echo run >> {}.count"
+        } else {
+            "echo run >> {}.count"
+        })
+        .arg(&wrk.path("."))
+        .arg(input);
+    cmd
+}
+
+#[test]
+fn split_filter_exact_multiple() {
+    let wrk = Workdir::new("split_filter_exact_multiple");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["h1", "h2"],
+            svec!["a", "b"],
+            svec!["c", "d"],
+            svec!["e", "f"],
+            svec!["g", "h"],
+        ],
+    );
+
+    // 4 rows / size 2 = 2 chunks; the filter must run exactly once per chunk
+    // even though the row count is an exact multiple of the chunk size
+    let mut cmd = filter_count_cmd(&wrk, "2", "in.csv");
+    wrk.assert_success(&mut cmd);
+
+    assert_eq!(wrk.read_to_string("0.count").unwrap().lines().count(), 1);
+    assert_eq!(wrk.read_to_string("2.count").unwrap().lines().count(), 1);
+    assert!(!wrk.path("4.count").exists());
+}
+
+#[test]
+fn split_filter_single_chunk_exact() {
+    let wrk = Workdir::new("split_filter_single_chunk_exact");
+    wrk.create(
+        "in.csv",
+        vec![
+            svec!["h1", "h2"],
+            svec!["a", "b"],
+            svec!["c", "d"],
+            svec!["e", "f"],
+            svec!["g", "h"],
+        ],
+    );
+
+    // 4 rows / size 4 = 1 chunk; the filter runs exactly once
+    let mut cmd = filter_count_cmd(&wrk, "4", "in.csv");
+    wrk.assert_success(&mut cmd);
+
+    assert_eq!(wrk.read_to_string("0.count").unwrap().lines().count(), 1);
+    assert!(!wrk.path("4.count").exists());
+}
+
+#[test]
+fn split_filter_no_data_rows() {
+    let wrk = Workdir::new("split_filter_no_data_rows");
+    wrk.create("in.csv", vec![svec!["h1", "h2"]]);
+
+    // a header-only input still writes (and filters) chunk 0 exactly once
+    let mut cmd = filter_count_cmd(&wrk, "2", "in.csv");
+    wrk.assert_success(&mut cmd);
+
+    assert_eq!(wrk.read_to_string("0.count").unwrap().lines().count(), 1);
+}
+
 #[test]
 fn split_filter_basic() {
     let wrk = Workdir::new("split_filter_basic");
@@ -1659,3 +1925,540 @@ id,name,value
 "
     );
 }
+
+#[test]
+fn split_compress_level() {
+    let wrk = Workdir::new("split_compress_level");
+
+    let mut rows = vec![svec!["id", "name", "value"]];
+    for i in 0..1000 {
+        rows.push(svec![
+            format!("{i}"),
+            format!("item_{i}"),
+            format!("value_{i}")
+        ]);
+    }
+    wrk.create("in.csv", rows.clone());
+
+    // gzip level 0 is stored (no compression), level 9 is best compression,
+    // so the level 0 chunk must be strictly larger for the same data
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "1000"])
+        .args(["--compress", "gzip"])
+        .args(["--compress-level", "0"])
+        .arg(&wrk.path("big"))
+        .arg("in.csv");
+    wrk.run(&mut cmd);
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "1000"])
+        .args(["--compress", "gzip"])
+        .args(["--compress-level", "9"])
+        .arg(&wrk.path("small"))
+        .arg("in.csv");
+    wrk.run(&mut cmd);
+
+    let big_len = std::fs::metadata(wrk.path("big").join("0.csv.gz"))
+        .unwrap()
+        .len();
+    let small_len = std::fs::metadata(wrk.path("small").join("0.csv.gz"))
+        .unwrap()
+        .len();
+    assert!(big_len > small_len);
+
+    // same check for zstd levels 1 and 19
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "1000"])
+        .args(["--compress", "zstd"])
+        .args(["--compress-level", "1"])
+        .arg(&wrk.path("zbig"))
+        .arg("in.csv");
+    wrk.run(&mut cmd);
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "1000"])
+        .args(["--compress", "zstd"])
+        .args(["--compress-level", "19"])
+        .arg(&wrk.path("zsmall"))
+        .arg("in.csv");
+    wrk.run(&mut cmd);
+
+    let zbig_len = std::fs::metadata(wrk.path("zbig").join("0.csv.zst"))
+        .unwrap()
+        .len();
+    let zsmall_len = std::fs::metadata(wrk.path("zsmall").join("0.csv.zst"))
+        .unwrap()
+        .len();
+    assert!(zbig_len >= zsmall_len);
+}
+
+#[test]
+fn split_compress_level_invalid() {
+    let wrk = Workdir::new("split_compress_level_invalid");
+    wrk.create("in.csv", data(true));
+
+    // gzip only supports levels 0-9
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .args(["--compress", "gzip"])
+        .args(["--compress-level", "10"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.assert_err(&mut cmd);
+
+    // --compress-level requires --compress
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .args(["--compress-level", "5"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn split_gzip_shorthand() {
+    let wrk = Workdir::new("split_gzip_shorthand");
+    wrk.create("in.csv", data(true));
+
+    // --gzip/--gzip-level must produce the same chunks as --compress gzip
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .arg("--gzip")
+        .args(["--gzip-level", "9"])
+        .arg(&wrk.path("short"))
+        .arg("in.csv");
+    wrk.run(&mut cmd);
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .args(["--compress", "gzip"])
+        .args(["--compress-level", "9"])
+        .arg(&wrk.path("long"))
+        .arg("in.csv");
+    wrk.run(&mut cmd);
+
+    for chunk in ["0.csv.gz", "2.csv.gz"] {
+        let short = std::fs::read(wrk.path("short").join(chunk)).unwrap();
+        let long = std::fs::read(wrk.path("long").join(chunk)).unwrap();
+        assert!(!short.is_empty());
+        assert_eq!(short, long);
+    }
+    assert!(!wrk.path("short").join("0.csv").exists());
+}
+
+#[test]
+fn split_gzip_shorthand_invalid() {
+    let wrk = Workdir::new("split_gzip_shorthand_invalid");
+    wrk.create("in.csv", data(true));
+
+    // --gzip conflicts with --compress
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .arg("--gzip")
+        .args(["--compress", "zstd"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.assert_err(&mut cmd);
+
+    // --gzip-level requires --gzip
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .args(["--gzip-level", "5"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.assert_err(&mut cmd);
+
+    // --gzip-level goes through the gzip level validation
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .arg("--gzip")
+        .args(["--gzip-level", "10"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn split_rename_header() {
+    let wrk = Workdir::new("split_rename_header");
+    wrk.create("in.csv", data(true));
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .args(["--rename-header", "h1=first,h2=second"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.run(&mut cmd);
+
+    split_eq!(
+        wrk,
+        "0.csv",
+        "\
+first,second
+a,b
+c,d
+"
+    );
+    split_eq!(
+        wrk,
+        "4.csv",
+        "\
+first,second
+i,j
+k,l
+"
+    );
+}
+
+#[test]
+fn split_rename_header_unknown_column() {
+    let wrk = Workdir::new("split_rename_header_unknown_column");
+    wrk.create("in.csv", data(true));
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .args(["--rename-header", "nonexistent=new"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+#[cfg(not(windows))]
+fn split_filter_retries() {
+    let wrk = Workdir::new("split_filter_retries");
+    wrk.create("in.csv", data(true));
+
+    // the filter fails on its first invocation for each chunk (creating a
+    // marker file), then succeeds on the retry
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "6"])
+        .arg("--filter")
+        .arg("[ -f {}.marker ] || { touch {}.marker; exit 1; }; cp $FILE {}.bak")
+        .args(["--filter-retries", "2"])
+        .args(["--filter-retry-delay", "0"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.run(&mut cmd);
+    wrk.assert_success(&mut cmd);
+
+    assert!(wrk.path("0.csv").exists());
+    assert!(wrk.path("0.marker").exists());
+    split_eq!(
+        wrk,
+        "0.bak",
+        "\
+h1,h2
+a,b
+c,d
+e,f
+g,h
+i,j
+k,l
+"
+    );
+}
+
+#[test]
+#[cfg(not(windows))]
+fn split_filter_retries_exhausted() {
+    let wrk = Workdir::new("split_filter_retries_exhausted");
+    wrk.create("in.csv", data(true));
+
+    // the filter always fails - the retries are exhausted and split errors out
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "6"])
+        .arg("--filter")
+        .arg("exit 1")
+        .args(["--filter-retries", "2"])
+        .args(["--filter-retry-delay", "0"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn split_chunks_precount_stdin() {
+    let wrk = Workdir::new("split_chunks_precount_stdin");
+
+    // 9 data rows piped via stdin should land in 3 balanced chunks
+    let mut rows = vec![svec!["id", "name"]];
+    for i in 0..9 {
+        rows.push(vec![i.to_string(), format!("item_{}", i)]);
+    }
+    wrk.create("stdin_data.csv", rows);
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--chunks", "3"])
+        .arg("--precount")
+        .arg(&wrk.path("."))
+        .arg("--quiet")
+        .arg("-");
+
+    let stdin_data = wrk.read_to_string("stdin_data.csv").unwrap();
+    cmd.stdin(std::process::Stdio::piped());
+
+    let mut child = cmd.spawn().unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    std::thread::spawn(move || {
+        stdin.write_all(stdin_data.as_bytes()).unwrap();
+    });
+
+    let status = child.wait().unwrap();
+    assert!(status.success());
+
+    assert!(wrk.path("0.csv").exists());
+    assert!(wrk.path("3.csv").exists());
+    assert!(wrk.path("6.csv").exists());
+    assert!(!wrk.path("9.csv").exists());
+
+    split_eq!(
+        wrk,
+        "3.csv",
+        "\
+id,name
+3,item_3
+4,item_4
+5,item_5
+"
+    );
+}
+
+#[test]
+fn split_precount_without_chunks() {
+    let wrk = Workdir::new("split_precount_without_chunks");
+    wrk.create("in.csv", data(true));
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .arg("--precount")
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn split_manifest() {
+    let wrk = Workdir::new("split_manifest");
+    wrk.create("in.csv", data(true));
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .args(["--manifest", "manifest.json"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.run(&mut cmd);
+
+    let manifest: serde_json::Value =
+        serde_json::from_str(&wrk.read_to_string("manifest.json").unwrap()).unwrap();
+    let entries = manifest.as_array().unwrap();
+    assert_eq!(entries.len(), 3);
+    for (entry, (file, start_row, rows)) in entries
+        .iter()
+        .zip([("0.csv", 0, 2), ("2.csv", 2, 2), ("4.csv", 4, 2)])
+    {
+        assert_eq!(entry["file"], file);
+        assert_eq!(entry["start_row"], start_row);
+        assert_eq!(entry["rows"], rows);
+        assert_eq!(
+            entry["bytes"].as_u64().unwrap(),
+            std::fs::metadata(wrk.path(file)).unwrap().len()
+        );
+    }
+}
+
+#[test]
+fn split_manifest_indexed() {
+    let wrk = Workdir::new("split_manifest_indexed");
+    wrk.create_indexed("in.csv", data(true));
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .args(["--manifest", "manifest.json"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.run(&mut cmd);
+
+    // the parallel splitter collects per-chunk results and sorts them by
+    // start row, so the manifest matches the sequential one exactly
+    let manifest: serde_json::Value =
+        serde_json::from_str(&wrk.read_to_string("manifest.json").unwrap()).unwrap();
+    let entries = manifest.as_array().unwrap();
+    assert_eq!(entries.len(), 3);
+    for (entry, (file, start_row, rows)) in entries
+        .iter()
+        .zip([("0.csv", 0, 2), ("2.csv", 2, 2), ("4.csv", 4, 2)])
+    {
+        assert_eq!(entry["file"], file);
+        assert_eq!(entry["start_row"], start_row);
+        assert_eq!(entry["rows"], rows);
+        assert_eq!(
+            entry["bytes"].as_u64().unwrap(),
+            std::fs::metadata(wrk.path(file)).unwrap().len()
+        );
+    }
+}
+
+#[test]
+fn split_manifest_kbsize() {
+    let wrk = Workdir::new("split_manifest_kbsize");
+
+    // 20 rows of ~100 bytes each should land in multiple 1KB chunks
+    let mut rows = vec![svec!["id", "data"]];
+    for i in 0..20 {
+        rows.push(vec![i.to_string(), "x".repeat(100)]);
+    }
+    wrk.create("in.csv", rows);
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--kb-size", "1"])
+        .args(["--manifest", "manifest.json"])
+        .arg(&wrk.path("."))
+        .arg("in.csv");
+    wrk.run(&mut cmd);
+
+    let manifest: serde_json::Value =
+        serde_json::from_str(&wrk.read_to_string("manifest.json").unwrap()).unwrap();
+    let entries = manifest.as_array().unwrap();
+    assert!(entries.len() > 1);
+
+    // the chunks partition the input: contiguous start rows, rows summing
+    // to the record count & byte sizes matching the files on disk
+    let mut next_start = 0;
+    for entry in entries {
+        assert_eq!(entry["start_row"].as_u64().unwrap(), next_start);
+        next_start += entry["rows"].as_u64().unwrap();
+        assert_eq!(
+            entry["bytes"].as_u64().unwrap(),
+            std::fs::metadata(wrk.path(entry["file"].as_str().unwrap()))
+                .unwrap()
+                .len()
+        );
+    }
+    assert_eq!(next_start, 20);
+}
+
+#[test]
+fn split_chunks_balanced() {
+    let wrk = Workdir::new("split_chunks_balanced");
+
+    // 100 rows into 7 chunks: the remainder is spread one extra row across
+    // the first 2 chunks, so sizes are 15,15,14,14,14,14,14
+    let mut rows = vec![svec!["id", "name"]];
+    for i in 0..100 {
+        rows.push(vec![i.to_string(), format!("item_{}", i)]);
+    }
+    wrk.create("in.csv", rows);
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--chunks", "7"]).arg(&wrk.path(".")).arg("in.csv");
+    wrk.run(&mut cmd);
+
+    for (file, rows) in [
+        ("0.csv", 15),
+        ("15.csv", 15),
+        ("30.csv", 14),
+        ("44.csv", 14),
+        ("58.csv", 14),
+        ("72.csv", 14),
+        ("86.csv", 14),
+    ] {
+        let contents = wrk.read_to_string(file).unwrap();
+        // lines() also counts the header row
+        assert_eq!(contents.lines().count(), rows + 1, "{file}");
+    }
+    assert!(!wrk.path("100.csv").exists());
+}
+
+#[test]
+fn split_chunks_balanced_idx() {
+    let wrk = Workdir::new("split_chunks_balanced_idx");
+
+    let mut rows = vec![svec!["id", "name"]];
+    for i in 0..100 {
+        rows.push(vec![i.to_string(), format!("item_{}", i)]);
+    }
+    wrk.create_indexed("in.csv", rows);
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--chunks", "7"]).arg(&wrk.path(".")).arg("in.csv");
+    wrk.run(&mut cmd);
+
+    for (file, rows) in [
+        ("0.csv", 15),
+        ("15.csv", 15),
+        ("30.csv", 14),
+        ("44.csv", 14),
+        ("58.csv", 14),
+        ("72.csv", 14),
+        ("86.csv", 14),
+    ] {
+        let contents = wrk.read_to_string(file).unwrap();
+        // lines() also counts the header row
+        assert_eq!(contents.lines().count(), rows + 1, "{file}");
+    }
+    assert!(!wrk.path("100.csv").exists());
+}
+
+#[test]
+fn split_count() {
+    let wrk = Workdir::new("split_count");
+    wrk.create("in.csv", data(true));
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--size", "2"])
+        .arg("--count")
+        .arg(wrk.path("counted"))
+        .arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    assert_eq!(got, "3");
+
+    // nothing is written - not even the output directory
+    assert!(!wrk.path("counted").exists());
+}
+
+#[test]
+fn split_count_chunks() {
+    let wrk = Workdir::new("split_count_chunks");
+    wrk.create("in.csv", data(true));
+
+    let mut cmd = wrk.command("split");
+    cmd.args(["--chunks", "4"])
+        .arg("--count")
+        .arg(wrk.path("counted"))
+        .arg("in.csv");
+
+    let got: String = wrk.stdout(&mut cmd);
+    assert_eq!(got, "4");
+}
+
+#[test]
+fn split_count_kb_size() {
+    let wrk = Workdir::new("split_count_kb_size");
+    let mut rows = vec![svec!["h1", "h2"]];
+    for i in 0..20 {
+        rows.push(vec![format!("value{i}"), "x".repeat(100)]);
+    }
+    wrk.create("in.csv", rows.clone());
+
+    let mut count_cmd = wrk.command("split");
+    count_cmd
+        .args(["--kb-size", "1"])
+        .arg("--count")
+        .arg(wrk.path("counted"))
+        .arg("in.csv");
+    let got: String = wrk.stdout(&mut count_cmd);
+
+    // the planned count matches the number of chunks an actual split writes
+    let mut split_cmd = wrk.command("split");
+    split_cmd
+        .args(["--kb-size", "1"])
+        .arg(wrk.path("out"))
+        .arg("in.csv");
+    wrk.run(&mut split_cmd);
+    let written = std::fs::read_dir(wrk.path("out")).unwrap().count();
+
+    assert_eq!(got, written.to_string());
+}
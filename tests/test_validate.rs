@@ -1653,3 +1653,1842 @@ fn validate_with_fancy_regex() {
     ];
     assert_eq!(invalid_records, expected_invalid);
 }
+
+#[test]
+fn validate_error_context() {
+    let wrk = Workdir::new("validate_error_context");
+
+    // Create test data with an id column we can use as error context
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "name", "age"],
+            svec!["r001", "Alice", "32"],
+            svec!["r002", "", "41"],
+            svec!["r003", "Carol", "45"],
+            svec!["r004", "", "29"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "name": { "type": "string" },
+                "age": { "type": "integer" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv")
+        .arg("schema.json")
+        .args(["--error-context", "id"]);
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    // each error row should be self-locating, carrying the offending record's id
+    let validation_errors: String = wrk.from_str(&wrk.path("data.csv.validation-errors.tsv"));
+    let expected_errors = r#"row_number	field	error	id
+2	name	null is not of type "string"	r002
+4	name	null is not of type "string"	r004
+"#;
+    assert_eq!(validation_errors, expected_errors);
+}
+
+#[test]
+fn validate_error_context_unknown_column() {
+    let wrk = Workdir::new("validate_error_context_unknown_column");
+
+    wrk.create(
+        "data.csv",
+        vec![svec!["id", "age"], svec!["r001", "32"]],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "age": { "type": "integer" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv")
+        .arg("schema.json")
+        .args(["--error-context", "nonexistent"]);
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_inline_enum() {
+    let wrk = Workdir::new("validate_inline_enum");
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "fruit"],
+            svec!["1", "apple"],
+            svec!["2", "durian"],
+            svec!["3", "banana"],
+            svec!["4", "rambutan"],
+            svec!["5", "cherry"],
+        ],
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").args(["--enum", "fruit=apple,banana,cherry"]);
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    // check valid records
+    let valid_records: Vec<Vec<String>> = wrk.read_csv("data.csv.valid");
+    let expected_valid = vec![
+        svec!["1", "apple"],
+        svec!["3", "banana"],
+        svec!["5", "cherry"],
+    ];
+    assert_eq!(valid_records, expected_valid);
+
+    // check invalid records
+    let invalid_records: Vec<Vec<String>> = wrk.read_csv("data.csv.invalid");
+    let expected_invalid = vec![svec!["2", "durian"], svec!["4", "rambutan"]];
+    assert_eq!(invalid_records, expected_invalid);
+}
+
+#[test]
+fn validate_inline_enum_invalid_spec() {
+    let wrk = Workdir::new("validate_inline_enum_invalid_spec");
+
+    wrk.create("data.csv", vec![svec!["id", "fruit"], svec!["1", "apple"]]);
+
+    // missing the '=' separator
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").args(["--enum", "fruit:apple,banana"]);
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_autofix_lengths() {
+    let wrk = Workdir::new("validate_autofix_lengths").flexible(true);
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["title", "name", "age"],
+            svec!["Professor", "Xaviers", "60"],
+            svec!["Magneto", "90",],
+            svec!["Doctor", "Strange", "42", "extra"],
+            svec!["First Class Student", "Iceman", "14"],
+        ],
+    );
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("--autofix-lengths");
+    wrk.assert_success(&mut cmd);
+
+    // the repair count is reported to stderr
+    let got: String = wrk.output_stderr(&mut cmd);
+    assert!(got.contains("Repaired 2 ragged record/s out of 4."));
+
+    // the fixed output must have uniform record lengths
+    let fixed: Vec<Vec<String>> = wrk.read_csv("data.csv.fixed.csv");
+    assert!(fixed.iter().all(|r| r.len() == 3));
+    let expected = vec![
+        svec!["Professor", "Xaviers", "60"],
+        svec!["Magneto", "90", ""],
+        svec!["Doctor", "Strange", "42"],
+        svec!["First Class Student", "Iceman", "14"],
+    ];
+    assert_eq!(fixed, expected);
+}
+
+#[test]
+fn validate_type_map() {
+    let wrk = Workdir::new("validate_type_map");
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "name"],
+            svec!["1", "alice"],
+            svec!["2", "bob"],
+        ],
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.args(["--type-map", "id=Integer,name=String"])
+        .arg("data.csv");
+
+    wrk.assert_success(&mut cmd);
+}
+
+#[test]
+fn validate_type_map_mismatch() {
+    let wrk = Workdir::new("validate_type_map_mismatch");
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "name"],
+            svec!["not-a-number", "alice"],
+            svec!["also-not", "bob"],
+        ],
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.args(["--type-map", "id=Integer,name=String"])
+        .arg("data.csv");
+
+    let got = wrk.output_stderr(&mut cmd);
+    assert!(got.contains("column \"id\": expected type Integer, got String"));
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_errors_format_jsonl() {
+    let wrk = Workdir::new("validate_errors_format_jsonl");
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "name", "age"],
+            svec!["r001", "Alice", "32"],
+            svec!["r002", "", "41"],
+            svec!["r003", "Carol", "45"],
+            svec!["r004", "", "29"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "name": { "type": "string" },
+                "age": { "type": "integer" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv")
+        .arg("schema.json")
+        .args(["--errors-format", "jsonl"]);
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    let validation_errors: String = wrk.from_str(&wrk.path("data.csv.validation-errors.jsonl"));
+    let expected_errors = r#"{"row_number":2,"field":"name","error":"null is not of type \"string\""}
+{"row_number":4,"field":"name","error":"null is not of type \"string\""}
+"#;
+    assert_eq!(validation_errors, expected_errors);
+}
+
+#[test]
+fn validate_errors_format_jsonl_rfc4180() {
+    let wrk = Workdir::new("validate_errors_format_jsonl_rfc4180");
+
+    // the third data row is ragged, with an extra field
+    wrk.create_from_string(
+        "data.csv",
+        "id,name\n1,Alice\n2,Bob\n3,Carol,extra\n",
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").args(["--errors-format", "jsonl"]);
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    let validation_errors: String = wrk.from_str(&wrk.path("data.csv.validation-errors.jsonl"));
+    assert!(validation_errors.starts_with(r#"{"row_number":3,"field":null,"error":""#));
+}
+
+#[test]
+fn validate_errors_format_invalid() {
+    let wrk = Workdir::new("validate_errors_format_invalid");
+    wrk.create("data.csv", vec![svec!["a"], svec!["1"]]);
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").args(["--errors-format", "xml"]);
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_conditional_if_then_else() {
+    let wrk = Workdir::new("validate_conditional_if_then_else");
+
+    // mirrors the adur public-toilets dataset - RADARKeyNeeded must be "Yes"
+    // only when Category is "Accessible"
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["Category", "RADARKeyNeeded"],
+            svec!["Accessible", "Yes"],
+            svec!["Accessible", "No"],
+            svec!["Standard", "No"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "Category": { "type": "string" },
+                "RADARKeyNeeded": { "type": "string" }
+            },
+            "if": {
+                "properties": { "Category": { "const": "Accessible" } }
+            },
+            "then": {
+                "properties": { "RADARKeyNeeded": { "const": "Yes" } }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    // only the row violating the conditional rule is invalid
+    let invalid: Vec<Vec<String>> = wrk.read_csv("data.csv.invalid");
+    assert_eq!(invalid, vec![svec!["Accessible", "No"]]);
+    let valid: Vec<Vec<String>> = wrk.read_csv("data.csv.valid");
+    assert_eq!(
+        valid,
+        vec![svec!["Accessible", "Yes"], svec!["Standard", "No"]]
+    );
+
+    // the error report names the offending row/field and the failing branch
+    let validation_errors: String = wrk.from_str(&wrk.path("data.csv.validation-errors.tsv"));
+    assert!(validation_errors.contains("2\tRADARKeyNeeded\t"));
+    assert!(validation_errors.contains("(failed \"then\" branch)"));
+}
+
+#[test]
+fn validate_schema_columns_only() {
+    let wrk = Workdir::new("validate_schema_columns_only");
+
+    // a wide file where the schema only describes two of the five columns
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["a", "b", "c", "d", "e"],
+            svec!["1", "x", "foo", "bar", "baz"],
+            svec!["", "y", "foo", "bar", "baz"],
+            svec!["3", "z", "foo", "bar", "baz"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "a": { "type": "integer" },
+                "b": { "type": "string" }
+            },
+            "additionalProperties": false
+        }"#,
+    );
+
+    // without the flag, every row is invalid as the undeclared columns
+    // trip "additionalProperties": false
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.output(&mut cmd);
+    wrk.assert_err(&mut cmd);
+    let invalid: Vec<Vec<String>> = wrk.read_csv("data.csv.invalid");
+    assert_eq!(invalid.len(), 3);
+
+    // with the flag, only the schema-declared columns are mapped, so just
+    // the row violating the "a" integer constraint is invalid
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv")
+        .arg("schema.json")
+        .arg("--schema-columns-only");
+    wrk.output(&mut cmd);
+    wrk.assert_err(&mut cmd);
+
+    let invalid: Vec<Vec<String>> = wrk.read_csv("data.csv.invalid");
+    assert_eq!(invalid, vec![svec!["", "y", "foo", "bar", "baz"]]);
+    let valid: Vec<Vec<String>> = wrk.read_csv("data.csv.valid");
+    assert_eq!(valid.len(), 2);
+}
+
+#[test]
+fn validate_column_comparisons() {
+    let wrk = Workdir::new("validate_column_comparisons");
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "GeoX", "GeoY"],
+            svec!["r1", "1.5", "2.5"],
+            svec!["r2", "9.0", "3.0"],
+            svec!["r3", "not-a-number", "3.0"],
+            svec!["r4", "4", "7"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "GeoX": { "type": "string" },
+                "GeoY": { "type": "string" }
+            },
+            "columnComparisons": [{"left": "GeoX", "op": "<", "right": "GeoY"}]
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    // the rows violating the comparison or with a non-numeric value are invalid
+    let invalid: Vec<Vec<String>> = wrk.read_csv("data.csv.invalid");
+    assert_eq!(
+        invalid,
+        vec![
+            svec!["r2", "9.0", "3.0"],
+            svec!["r3", "not-a-number", "3.0"]
+        ]
+    );
+
+    // errors are attributed to the left column of the comparison
+    let validation_errors: String = wrk.from_str(&wrk.path("data.csv.validation-errors.tsv"));
+    assert!(validation_errors.contains("2\tGeoX\tGeoX < GeoY is not satisfied (9 < 3)"));
+    assert!(
+        validation_errors.contains("3\tGeoX\tvalue \"not-a-number\" of column \"GeoX\" is not numeric")
+    );
+}
+
+#[test]
+fn validate_invalid_output() {
+    let wrk = Workdir::new("validate_invalid_output");
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "name"],
+            svec!["r1", "Alice"],
+            svec!["r2", ""],
+            svec!["r3", "Carol"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "name": { "type": "string" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv")
+        .arg("schema.json")
+        .args(["--invalid-output", "bad.csv"]);
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    // invalid records are streamed to the given file and
+    // the ".invalid" sidecar is suppressed
+    let invalid: Vec<Vec<String>> = wrk.read_csv("bad.csv");
+    assert_eq!(invalid, vec![svec!["r2", ""]]);
+    assert!(!wrk.path("data.csv.invalid").exists());
+    let valid: Vec<Vec<String>> = wrk.read_csv("data.csv.valid");
+    assert_eq!(valid.len(), 2);
+}
+
+#[test]
+fn validate_invalid_output_stdout_conflict() {
+    let wrk = Workdir::new("validate_invalid_output_stdout_conflict");
+    wrk.create("data.csv", vec![svec!["a"], svec!["1"]]);
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv")
+        .args(["--valid-output", "-"])
+        .args(["--invalid-output", "-"]);
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_dynenum_ci() {
+    let wrk = Workdir::new("validate_dynenum_ci").flexible(true);
+
+    // Create lookup file with mixed-case values
+    wrk.create(
+        "lookup.csv",
+        vec![
+            svec!["code", "name", "category"],
+            svec!["A1", "Apple", "fruit"],
+            svec!["B2", "BANANA", "fruit"],
+            svec!["C3", "Carrot", "vegetable"],
+        ],
+    );
+
+    // Create test data whose casing differs from the lookup table
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "product", "type"],
+            svec!["1", "apple", "fruit"],
+            svec!["2", "Banana", "fruit"],
+            svec!["3", "ORANGE", "fruit"], // Invalid - not in lookup
+            svec!["4", "CARROT", "vegetable"],
+        ],
+    );
+
+    // Create schema using dynamicEnum with a column selector and the
+    // trailing "ci" case-insensitive modifier
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "product": { 
+                    "type": "string",
+                    "dynamicEnum": "lookup.csv|name|ci"
+                },
+                "type": { "type": "string" }
+            }
+        }"#,
+    );
+
+    // Run validate command
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    // Check validation-errors.tsv - only the value missing from the lookup
+    // table should be flagged, regardless of casing
+    let validation_errors: String = wrk.from_str(&wrk.path("data.csv.validation-errors.tsv"));
+
+    let expected_errors = r#"row_number	field	error
+3	product	"ORANGE" is not a valid dynamicEnum value
+"#;
+    assert_eq!(validation_errors, expected_errors);
+
+    // Check valid records
+    let valid_records: Vec<Vec<String>> = wrk.read_csv("data.csv.valid");
+    let expected_valid = vec![
+        svec!["1", "apple", "fruit"],
+        svec!["2", "Banana", "fruit"],
+        svec!["4", "CARROT", "vegetable"],
+    ];
+    assert_eq!(valid_records, expected_valid);
+}
+
+#[test]
+fn validate_report_slow() {
+    let wrk = Workdir::new("validate_report_slow");
+
+    // an artificially slow row - the pattern has to scan a multi-megabyte
+    // value, which comfortably exceeds the generous 1 ms threshold
+    let slow_value = "a".repeat(8_000_000);
+    wrk.create(
+        "data.csv",
+        vec![svec!["id", "value"], svec!["1", slow_value]],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "value": { "type": "string", "pattern": "^a*$" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.args(["--report-slow", "1"])
+        .arg("data.csv")
+        .arg("schema.json");
+
+    let got = wrk.output_stderr(&mut cmd);
+    assert!(got.contains("row 1: validation took"));
+    assert!(got.contains("1 row/s took longer than 1 ms to validate."));
+}
+
+#[test]
+fn validate_report_slow_none() {
+    let wrk = Workdir::new("validate_report_slow_none");
+
+    wrk.create(
+        "data.csv",
+        vec![svec!["id", "value"], svec!["1", "a"]],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "value": { "type": "string", "pattern": "^a*$" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.args(["--report-slow", "100000"])
+        .arg("data.csv")
+        .arg("schema.json");
+
+    let got = wrk.output_stderr(&mut cmd);
+    assert!(got.contains("No rows took longer than 100000 ms to validate."));
+}
+
+#[test]
+#[cfg(feature = "polars")]
+fn validate_dynenum_parquet_lookup() {
+    let wrk = Workdir::new("validate_dynenum_parquet_lookup").flexible(true);
+
+    // Create the lookup table as CSV, then convert it to Parquet with sqlp
+    wrk.create(
+        "lookup.csv",
+        vec![
+            svec!["code", "name", "category"],
+            svec!["A1", "Apple", "fruit"],
+            svec!["B2", "Banana", "fruit"],
+            svec!["C3", "Carrot", "vegetable"],
+        ],
+    );
+
+    let mut cmd = wrk.command("sqlp");
+    cmd.arg("lookup.csv")
+        .arg("select * from lookup")
+        .args(["--format", "parquet"])
+        .args(["--output", "lookup.parquet"]);
+    wrk.assert_success(&mut cmd);
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "product", "type"],
+            svec!["1", "Apple", "fruit"],
+            svec!["2", "Banana", "fruit"],
+            svec!["3", "Orange", "fruit"], // Invalid - not in lookup
+        ],
+    );
+
+    // Create schema using a Parquet dynamicEnum with a column selector
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "product": { 
+                    "type": "string",
+                    "dynamicEnum": "lookup.parquet|name"
+                },
+                "type": { "type": "string" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    let validation_errors: String = wrk.from_str(&wrk.path("data.csv.validation-errors.tsv"));
+
+    let expected_errors = r#"row_number	field	error
+3	product	"Orange" is not a valid dynamicEnum value
+"#;
+    assert_eq!(validation_errors, expected_errors);
+
+    let valid_records: Vec<Vec<String>> = wrk.read_csv("data.csv.valid");
+    let expected_valid = vec![svec!["1", "Apple", "fruit"], svec!["2", "Banana", "fruit"]];
+    assert_eq!(valid_records, expected_valid);
+}
+
+#[test]
+#[cfg(not(feature = "polars"))]
+fn validate_dynenum_parquet_lookup_requires_polars() {
+    let wrk = Workdir::new("validate_dynenum_parquet_lookup_requires_polars").flexible(true);
+
+    wrk.create(
+        "data.csv",
+        vec![svec!["id", "product"], svec!["1", "Apple"]],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "product": { 
+                    "type": "string",
+                    "dynamicEnum": "lookup.parquet|name"
+                }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+
+    let got = wrk.output_stderr(&mut cmd);
+    assert!(got.contains("requires the polars feature"));
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_error_type_summary() {
+    let wrk = Workdir::new("validate_error_type_summary");
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "value"],
+            svec!["1", "aaa"],
+            svec!["2", "bbb"],
+            svec!["3", "ccc"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "value": { "type": "string", "pattern": "^a+$" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+
+    // the histogram normalizes the specific value out of each message,
+    // so both pattern violations aggregate under one error type
+    let got = wrk.output_stderr(&mut cmd);
+    assert!(got.contains("Error type summary:"));
+    assert!(got.contains(r#"does not match "^a+$": 2"#));
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_error_type_summary_quiet() {
+    let wrk = Workdir::new("validate_error_type_summary_quiet");
+
+    wrk.create(
+        "data.csv",
+        vec![svec!["id", "value"], svec!["1", "bbb"]],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "value": { "type": "string", "pattern": "^a+$" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("--quiet").arg("data.csv").arg("schema.json");
+
+    let got = wrk.output_stderr(&mut cmd);
+    assert!(!got.contains("Error type summary:"));
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_unique_combined_with_jobs() {
+    let wrk = Workdir::new("validate_unique_combined_with_jobs").flexible(true);
+
+    // duplicates must be flagged deterministically - the FIRST occurrence
+    // passes and the later duplicate is flagged - even when validating
+    // with multiple jobs and several small batches
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "name", "email"],
+            svec!["1", "John Doe", "john@example.com"],
+            svec!["2", "Jane Smith", "jane@example.com"],
+            svec!["3", "Bob Wilson", "bob@example.com"],
+            svec!["4", "John Doe", "john@example.com"], // Duplicate of row 1
+            svec!["5", "Ann Cruz", "ann@example.com"],
+            svec!["6", "Jane Smith", "jane@example.com"], // Duplicate of row 2
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "name": { "type": "string" },
+                "email": { "type": "string" }
+            },
+            "uniqueCombinedWith": ["name", "email"]
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.args(["--jobs", "4"])
+        .args(["--batch", "2"])
+        .arg("data.csv")
+        .arg("schema.json");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    let validation_errors = wrk
+        .read_to_string("data.csv.validation-errors.tsv")
+        .unwrap();
+    let expected_errors = r#"row_number	field	error
+4		Combination of values for columns name, email is not unique
+6		Combination of values for columns name, email is not unique
+"#;
+    assert_eq!(validation_errors, expected_errors);
+
+    let valid_records: Vec<Vec<String>> = wrk.read_csv("data.csv.valid");
+    let expected_valid = vec![
+        svec!["1", "John Doe", "john@example.com"],
+        svec!["2", "Jane Smith", "jane@example.com"],
+        svec!["3", "Bob Wilson", "bob@example.com"],
+        svec!["5", "Ann Cruz", "ann@example.com"],
+    ];
+    assert_eq!(valid_records, expected_valid);
+}
+
+#[test]
+fn validate_min_records_empty_file() {
+    let wrk = Workdir::new("validate_min_records_empty_file").flexible(true);
+
+    // header-only file - zero data rows
+    wrk.create("data.csv", vec![svec!["id", "name"]]);
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "name": { "type": "string" }
+            },
+            "minRecords": 1
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    let validation_errors: String = wrk.from_str(&wrk.path("data.csv.validation-errors.tsv"));
+    assert!(validation_errors.contains("0\t\tnumber of records 0 is less than minRecords 1"));
+}
+
+#[test]
+fn validate_min_records_under_min() {
+    let wrk = Workdir::new("validate_min_records_under_min").flexible(true);
+
+    wrk.create(
+        "data.csv",
+        vec![svec!["id", "name"], svec!["1", "a"], svec!["2", "b"]],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "name": { "type": "string" }
+            },
+            "minRecords": 5
+        }"#,
+    );
+
+    // every individual row is valid, but the dataset-level assertion
+    // must still fail the run
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    let validation_errors: String = wrk.from_str(&wrk.path("data.csv.validation-errors.tsv"));
+    assert!(validation_errors.contains("0\t\tnumber of records 2 is less than minRecords 5"));
+}
+
+#[test]
+fn validate_max_records_over_max() {
+    let wrk = Workdir::new("validate_max_records_over_max").flexible(true);
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "name"],
+            svec!["1", "a"],
+            svec!["2", "b"],
+            svec!["3", "c"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "name": { "type": "string" }
+            },
+            "maxRecords": 2
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    let validation_errors: String = wrk.from_str(&wrk.path("data.csv.validation-errors.tsv"));
+    assert!(validation_errors.contains("0\t\tnumber of records 3 is greater than maxRecords 2"));
+}
+
+#[test]
+fn validate_min_max_records_within_bounds() {
+    let wrk = Workdir::new("validate_min_max_records_within_bounds").flexible(true);
+
+    wrk.create(
+        "data.csv",
+        vec![svec!["id", "name"], svec!["1", "a"], svec!["2", "b"]],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "name": { "type": "string" }
+            },
+            "minRecords": 1,
+            "maxRecords": 10000
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.assert_success(&mut cmd);
+}
+
+#[test]
+fn validate_max_record_bytes() {
+    let wrk = Workdir::new("validate_max_record_bytes").flexible(true);
+
+    let oversized_value = "x".repeat(500);
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "value"],
+            svec!["1", "ok"],
+            svec!["2", oversized_value],
+            svec!["3", "also ok"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "value": { "type": "string" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.args(["--max-record-bytes", "100"])
+        .arg("data.csv")
+        .arg("schema.json");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    // only the oversized record is flagged, with the size-limit error
+    let validation_errors: String = wrk.from_str(&wrk.path("data.csv.validation-errors.tsv"));
+    assert!(
+        validation_errors
+            .contains("2\t<RECORD>\trecord size 501 bytes exceeds --max-record-bytes 100")
+    );
+
+    let valid_records: Vec<Vec<String>> = wrk.read_csv("data.csv.valid");
+    let expected_valid = vec![svec!["1", "ok"], svec!["3", "also ok"]];
+    assert_eq!(valid_records, expected_valid);
+}
+
+#[test]
+fn validate_enum_case_insensitive() {
+    let wrk = Workdir::new("validate_enum_case_insensitive").flexible(true);
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["name", "gender"],
+            svec!["John", "male"],
+            svec!["Jane", "Female"],
+            svec!["Sam", "MALE"],
+            svec!["Alex", "unknown"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "gender": { "type": "string", "enumCaseInsensitive": ["Male", "Female"] }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    // only the value outside the allowed variants fails, regardless of casing
+    let validation_errors = wrk
+        .read_to_string("data.csv.validation-errors.tsv")
+        .unwrap();
+    assert!(validation_errors.contains("4\tgender\t\"unknown\" is not a valid enumCaseInsensitive value"));
+
+    let valid = wrk.read_to_string("data.csv.valid").unwrap();
+    assert_eq!(valid, "name,gender\nJohn,male\nJane,Female\nSam,MALE\n");
+}
+
+#[test]
+fn validate_enum_case_insensitive_vs_plain_enum() {
+    let wrk = Workdir::new("validate_enum_case_insensitive_vs_plain_enum").flexible(true);
+
+    wrk.create(
+        "data.csv",
+        vec![svec!["name", "gender"], svec!["John", "male"]],
+    );
+
+    // the same value fails a plain case-sensitive enum
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "gender": { "type": "string", "enum": ["Male", "Female"] }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_ref_base_local_dir() {
+    let wrk = Workdir::new("validate_ref_base_local_dir").flexible(true);
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["name", "title"],
+            svec!["John", "Manager"],
+            svec!["x", "Engineer"],
+        ],
+    );
+
+    // a shared definition in a local "registry" directory,
+    // referenced by the schema via a non-absolute $ref
+    std::fs::create_dir_all(wrk.path("registry")).unwrap();
+    wrk.create_from_string(
+        "registry/person.json",
+        r#"{
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "minLength": 2 }
+            }
+        }"#,
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$ref": "person.json"
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv")
+        .arg("schema.json")
+        .args(["--ref-base", "registry"]);
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    // the shared minLength constraint resolved via --ref-base kicks in
+    let validation_errors = wrk
+        .read_to_string("data.csv.validation-errors.tsv")
+        .unwrap();
+    assert!(validation_errors.contains("2\tname\t"));
+
+    let valid = wrk.read_to_string("data.csv.valid").unwrap();
+    assert_eq!(valid, "name,title\nJohn,Manager\n");
+}
+
+#[test]
+fn validate_ref_base_unresolvable_without_it() {
+    let wrk = Workdir::new("validate_ref_base_unresolvable_without_it").flexible(true);
+
+    wrk.create("data.csv", vec![svec!["name"], svec!["John"]]);
+
+    std::fs::create_dir_all(wrk.path("registry")).unwrap();
+    wrk.create_from_string(
+        "registry/person.json",
+        r#"{ "type": "object" }"#,
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$ref": "person.json"
+        }"#,
+    );
+
+    // without --ref-base, the non-absolute $ref cannot be resolved
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_coerce_booleans() {
+    let wrk = Workdir::new("validate_coerce_booleans").flexible(true);
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["name", "active"],
+            svec!["John", "Y"],
+            svec!["Jane", "n"],
+            svec!["Sam", "TRUE"],
+            svec!["Alex", "0"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "active": { "type": "boolean" }
+            }
+        }"#,
+    );
+
+    // without coercion, Y/n are not castable to boolean
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.assert_err(&mut cmd);
+
+    // with coercion, all rows validate
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json").arg("--coerce-booleans");
+    wrk.assert_success(&mut cmd);
+}
+
+#[test]
+fn validate_relaxed_numbers() {
+    let wrk = Workdir::new("validate_relaxed_numbers").flexible(true);
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["item", "amount"],
+            svec!["widget", "1,234.56"],
+            svec!["gadget", "987.65"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "item": { "type": "string" },
+                "amount": { "type": "number", "minimum": 0, "maximum": 10000 }
+            }
+        }"#,
+    );
+
+    // without the relaxed flag, "1,234.56" is not castable to a number
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.assert_err(&mut cmd);
+
+    // with it, the thousands separators are stripped and the numeric
+    // range constraints apply
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv")
+        .arg("schema.json")
+        .args(["--relaxed-numbers", "."]);
+    wrk.assert_success(&mut cmd);
+}
+
+#[test]
+fn validate_relaxed_numbers_eu_decimal_mark() {
+    let wrk = Workdir::new("validate_relaxed_numbers_eu_decimal_mark").flexible(true);
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["item", "amount"],
+            svec!["widget", "1.234,56"],
+            svec!["gadget", "987,65"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "item": { "type": "string" },
+                "amount": { "type": "number", "minimum": 0, "maximum": 10000 }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv")
+        .arg("schema.json")
+        .args(["--relaxed-numbers", ","]);
+    wrk.assert_success(&mut cmd);
+
+    // an invalid decimal mark hint is rejected upfront
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv")
+        .arg("schema.json")
+        .args(["--relaxed-numbers", ";"]);
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_cross_file_unique() {
+    let wrk = Workdir::new("validate_cross_file_unique");
+    wrk.create(
+        "a.csv",
+        vec![
+            svec!["id", "name"],
+            svec!["1", "John"],
+            svec!["2", "Jane"],
+        ],
+    );
+    wrk.create(
+        "b.csv",
+        vec![
+            svec!["id", "name"],
+            svec!["3", "Jill"],
+            svec!["1", "Jack"],
+        ],
+    );
+    wrk.create_from_string("files.infile-list", "a.csv\nb.csv\n");
+
+    // id 1 appears in both files, so the cross-file check fails,
+    // reporting both occurrences
+    let mut cmd = wrk.command("validate");
+    cmd.args(["--cross-file-unique", "id"]).arg("files.infile-list");
+    wrk.assert_err(&mut cmd);
+
+    let stderr = wrk.output_stderr(&mut cmd);
+    assert!(stderr.contains("b.csv\" row 2"), "stderr: {stderr}");
+    assert!(stderr.contains("a.csv\" row 1"), "stderr: {stderr}");
+    assert!(
+        stderr.contains("1 out of 4 row/s failed the cross-file uniqueness check"),
+        "stderr: {stderr}"
+    );
+}
+
+#[test]
+fn validate_cross_file_unique_no_duplicates() {
+    let wrk = Workdir::new("validate_cross_file_unique_no_duplicates");
+    wrk.create(
+        "a.csv",
+        vec![
+            svec!["id", "name"],
+            svec!["1", "John"],
+            svec!["2", "Jane"],
+        ],
+    );
+    wrk.create(
+        "b.csv",
+        vec![
+            svec!["id", "name"],
+            svec!["3", "Jill"],
+            svec!["4", "Jack"],
+        ],
+    );
+    wrk.create_from_string("files.infile-list", "a.csv\nb.csv\n");
+
+    let mut cmd = wrk.command("validate");
+    cmd.args(["--cross-file-unique", "id"]).arg("files.infile-list");
+    wrk.assert_success(&mut cmd);
+}
+
+#[test]
+fn validate_cross_file_unique_with_schema_conflict() {
+    let wrk = Workdir::new("validate_cross_file_unique_with_schema_conflict");
+    wrk.create("a.csv", vec![svec!["id"], svec!["1"]]);
+    wrk.create_from_string("schema.json", r#"{"type": "object"}"#);
+
+    let mut cmd = wrk.command("validate");
+    cmd.args(["--cross-file-unique", "id"])
+        .arg("a.csv")
+        .arg("schema.json");
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_tsv_invalid_output_keeps_input_delimiter() {
+    let wrk = Workdir::new("validate_tsv_invalid_output_keeps_input_delimiter").flexible(true);
+
+    wrk.create_from_string(
+        "data.tsv",
+        "name\tage\nalice\t42\nbob\tnot_a_number\ncarol\t29\n",
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "age": { "type": "integer" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.tsv").arg("schema.json");
+    wrk.output(&mut cmd);
+
+    // the rejects file round-trips in the input's delimiter, not comma
+    let invalid_output: String = wrk.from_str(&wrk.path("data.tsv.invalid"));
+    assert_eq!(invalid_output, "name\tage\nbob\tnot_a_number\n");
+    let valid_output: String = wrk.from_str(&wrk.path("data.tsv.valid"));
+    assert_eq!(valid_output, "name\tage\nalice\t42\ncarol\t29\n");
+}
+
+#[test]
+fn validate_tsv_invalid_output_out_delimiter_override() {
+    let wrk = Workdir::new("validate_tsv_invalid_output_out_delimiter_override").flexible(true);
+
+    wrk.create_from_string("data.tsv", "name\tage\nalice\t42\nbob\tnot_a_number\n");
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "age": { "type": "integer" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.tsv")
+        .arg("schema.json")
+        .args(["--out-delimiter", ","]);
+    wrk.output(&mut cmd);
+
+    let invalid_output: String = wrk.from_str(&wrk.path("data.tsv.invalid"));
+    assert_eq!(invalid_output, "name,age\nbob,not_a_number\n");
+}
+
+#[test]
+fn validate_json_pointer_errors() {
+    let wrk = Workdir::new("validate_json_pointer_errors");
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["product", "price"],
+            svec!["widget", "1.50"],
+            svec!["", "2.75"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "product": { "type": "string" },
+                "price": { "type": "number" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv")
+        .arg("schema.json")
+        .args(["--errors-format", "jsonl"])
+        .arg("--json-pointer-errors");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    let validation_errors: String = wrk.from_str(&wrk.path("data.csv.validation-errors.jsonl"));
+    let expected_errors = r#"{"row_number":2,"field":"product","error":"null is not of type \"string\"","instance_pointer":"/product","schema_pointer":"/properties/product/type"}
+"#;
+    assert_eq!(validation_errors, expected_errors);
+}
+
+#[test]
+fn validate_json_pointer_errors_requires_jsonl() {
+    let wrk = Workdir::new("validate_json_pointer_errors_requires_jsonl");
+    wrk.create("data.csv", vec![svec!["a"], svec!["1"]]);
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("--json-pointer-errors");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_output_stats() {
+    let wrk = Workdir::new("validate_output_stats");
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["name", "age"],
+            svec!["alice", "42"],
+            svec!["bob", "not_a_number"],
+            svec!["carol", "7"],
+        ],
+    );
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "age": { "type": "integer" }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv")
+        .arg("schema.json")
+        .args(["--jobs", "1"])
+        .args(["--output-stats", "run-stats.json"]);
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    let stats_json: String = wrk.from_str(&wrk.path("run-stats.json"));
+    let stats: serde_json::Value = serde_json::from_str(&stats_json).unwrap();
+    assert_eq!(stats["record_count"], 3);
+    assert_eq!(stats["invalid_count"], 1);
+    assert!(stats["rows_per_sec"].as_f64().unwrap() > 0.0);
+    assert_eq!(stats["jobs"], 1);
+    assert_eq!(stats["parallel"], false);
+    assert_eq!(stats["fail_fast"], false);
+    assert_eq!(stats["format_validation"], true);
+}
+
+fn setup_dynenum_cache(wrk: &Workdir) {
+    std::fs::create_dir_all(wrk.path("qsv-cache")).unwrap();
+    wrk.create(
+        "qsv-cache/product_cache.csv",
+        vec![
+            svec!["code", "name"],
+            svec!["A1", "Apple"],
+            svec!["B2", "Banana"],
+        ],
+    );
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "product"],
+            svec!["1", "Apple"],
+            svec!["2", "Banana"],
+        ],
+    );
+
+    // the lookup URL is deliberately unreachable so any attempt to re-fetch
+    // the lookup table fails loudly instead of silently using the cache
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "product": {
+                    "type": "string",
+                    "dynamicEnum": "product_cache;3600|http://127.0.0.1:1/lookup.csv|name"
+                }
+            }
+        }"#,
+    );
+}
+
+#[test]
+fn validate_dynenum_fresh_cache_skips_refetch() {
+    let wrk = Workdir::new("validate_dynenum_fresh_cache_skips_refetch").flexible(true);
+    setup_dynenum_cache(&wrk);
+
+    // the cache entry is within its TTL, so the unreachable URL is never hit
+    let mut cmd = wrk.command("validate");
+    cmd.env("QSV_CACHE_DIR", wrk.path("qsv-cache"))
+        .arg("data.csv")
+        .arg("schema.json");
+
+    wrk.assert_success(&mut cmd);
+}
+
+#[test]
+fn validate_dynenum_past_ttl_refetches() {
+    let wrk = Workdir::new("validate_dynenum_past_ttl_refetches").flexible(true);
+    setup_dynenum_cache(&wrk);
+
+    // age the cache entry well past its 3600 second TTL; validate must then
+    // try to re-fetch from the unreachable URL and fail
+    use filetime::{FileTime, set_file_times};
+    let past = FileTime::from_unix_time(FileTime::now().unix_seconds() - 10_000, 0);
+    set_file_times(wrk.path("qsv-cache/product_cache.csv"), past, past).unwrap();
+
+    let mut cmd = wrk.command("validate");
+    cmd.env("QSV_CACHE_DIR", wrk.path("qsv-cache"))
+        .arg("data.csv")
+        .arg("schema.json");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_dynenum_refresh_lookups_forces_refetch() {
+    let wrk = Workdir::new("validate_dynenum_refresh_lookups_forces_refetch").flexible(true);
+    setup_dynenum_cache(&wrk);
+
+    // the cache entry is fresh, but --refresh-lookups must still re-fetch
+    // from the unreachable URL and fail
+    let mut cmd = wrk.command("validate");
+    cmd.env("QSV_CACHE_DIR", wrk.path("qsv-cache"))
+        .arg("--refresh-lookups")
+        .arg("data.csv")
+        .arg("schema.json");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_schema_text() {
+    let wrk = Workdir::new("validate_schema_text").flexible(true);
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "fruit"],
+            svec!["1", "apple"],
+            svec!["2", "mango"], // invalid
+            svec!["3", "banana"],
+        ],
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").args([
+        "--schema-text",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "fruit": { "enum": ["apple", "banana"] }
+            }
+        }"#,
+    ]);
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    let invalid_records: Vec<Vec<String>> = wrk.read_csv("data.csv.invalid");
+    assert_eq!(invalid_records, vec![svec!["2", "mango"]]);
+}
+
+#[test]
+fn validate_schema_text_conflicts_with_schema_file() {
+    let wrk = Workdir::new("validate_schema_text_conflicts_with_schema_file");
+    wrk.create("data.csv", vec![svec!["id"], svec!["1"]]);
+    wrk.create_from_string("schema.json", r#"{"type": "object"}"#);
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv")
+        .arg("schema.json")
+        .args(["--schema-text", r#"{"type": "object"}"#]);
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_schema_from_stdin() {
+    use std::io::Write;
+
+    let wrk = Workdir::new("validate_schema_from_stdin").flexible(true);
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "fruit"],
+            svec!["1", "apple"],
+            svec!["2", "banana"],
+        ],
+    );
+
+    let schema = r#"{
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": {
+            "fruit": { "enum": ["apple", "banana"] }
+        }
+    }"#;
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("-");
+    cmd.stdin(std::process::Stdio::piped());
+
+    let mut child = cmd.spawn().unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    std::thread::spawn(move || {
+        stdin.write_all(schema.as_bytes()).unwrap();
+    });
+
+    let status = child.wait().unwrap();
+    assert!(status.success());
+}
+
+#[test]
+fn validate_schema_from_stdin_requires_file_input() {
+    // both the data and the schema cannot come from stdin
+    let wrk = Workdir::new("validate_schema_from_stdin_requires_file_input");
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("-").arg("-");
+
+    wrk.assert_err(&mut cmd);
+}
+
+#[test]
+fn validate_dynenum_multiple_columns() {
+    let wrk = Workdir::new("validate_dynenum_multiple_columns").flexible(true);
+
+    // the lookup table has both a code and an alias column
+    wrk.create(
+        "lookup.csv",
+        vec![
+            svec!["code", "alias"],
+            svec!["A1", "Apple"],
+            svec!["B2", "Banana"],
+            svec!["C3", "Carrot"],
+        ],
+    );
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "product"],
+            svec!["1", "A1"],     // matches code
+            svec!["2", "Banana"], // matches alias
+            svec!["3", "Orange"], // matches neither
+        ],
+    );
+
+    // a value is valid if it appears in EITHER the code or alias column
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "product": {
+                    "type": "string",
+                    "dynamicEnum": "lookup.csv|code,alias"
+                }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    let valid_records: Vec<Vec<String>> = wrk.read_csv("data.csv.valid");
+    assert_eq!(valid_records, vec![svec!["1", "A1"], svec!["2", "Banana"]]);
+
+    let invalid_records: Vec<Vec<String>> = wrk.read_csv("data.csv.invalid");
+    assert_eq!(invalid_records, vec![svec!["3", "Orange"]]);
+}
+
+#[test]
+fn validate_dynenum_multiple_columns_by_index() {
+    let wrk = Workdir::new("validate_dynenum_multiple_columns_by_index").flexible(true);
+
+    wrk.create(
+        "lookup.csv",
+        vec![
+            svec!["code", "category", "alias"],
+            svec!["A1", "fruit", "Apple"],
+            svec!["B2", "fruit", "Banana"],
+        ],
+    );
+
+    wrk.create(
+        "data.csv",
+        vec![
+            svec!["id", "product"],
+            svec!["1", "A1"],
+            svec!["2", "Apple"],
+            svec!["3", "fruit"], // category column is not selected
+        ],
+    );
+
+    // union of the first and third columns, by index
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "id": { "type": "string" },
+                "product": {
+                    "type": "string",
+                    "dynamicEnum": "lookup.csv|0,2"
+                }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+    wrk.output(&mut cmd);
+
+    wrk.assert_err(&mut cmd);
+
+    let invalid_records: Vec<Vec<String>> = wrk.read_csv("data.csv.invalid");
+    assert_eq!(invalid_records, vec![svec!["3", "fruit"]]);
+}
+
+#[test]
+fn validate_dynenum_multiple_columns_unknown_column() {
+    let wrk = Workdir::new("validate_dynenum_multiple_columns_unknown_column").flexible(true);
+
+    wrk.create(
+        "lookup.csv",
+        vec![svec!["code", "alias"], svec!["A1", "Apple"]],
+    );
+
+    wrk.create("data.csv", vec![svec!["id", "product"], svec!["1", "A1"]]);
+
+    wrk.create_from_string(
+        "schema.json",
+        r#"{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "product": {
+                    "type": "string",
+                    "dynamicEnum": "lookup.csv|code,nonexistent"
+                }
+            }
+        }"#,
+    );
+
+    let mut cmd = wrk.command("validate");
+    cmd.arg("data.csv").arg("schema.json");
+
+    wrk.assert_err(&mut cmd);
+}